@@ -0,0 +1,66 @@
+use aoc_common::{parse, read_input, Answer, ParseError, Solution};
+
+/// The total calories carried by each elf, of which there must be at least one
+fn parse_inventories(all_calories: &str) -> Result<Vec<u32>, ParseError> {
+    let inventories: Vec<u32> = parse::blocks(all_calories)
+        .map(|inventory| {
+            inventory
+                .lines()
+                .map(|calories| parse::token::<u32>(calories, "a number of calories"))
+                .sum()
+        })
+        .collect::<Result<_, _>>()?;
+
+    if inventories.is_empty() {
+        return Err(ParseError::end_of(all_calories, "an inventory"));
+    }
+
+    Ok(inventories)
+}
+
+fn part_one(inventories: &[u32]) -> u32 {
+    let max_calories = inventories
+        .iter()
+        .copied()
+        .max()
+        .expect("parsing checks that there is an inventory");
+
+    max_calories
+}
+
+fn part_two(inventories: &[u32]) -> u32 {
+    let mut max_calories = inventories.to_vec();
+
+    max_calories.sort();
+    max_calories.reverse();
+    max_calories.truncate(3);
+
+    max_calories.iter().sum()
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<u32>;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse_inventories(input).map_err(|error| error.locate(input, 2022, 1).into())
+    }
+
+    fn part1(&self, inventories: &Self::Input) -> Option<Answer> {
+        Some(part_one(inventories).into())
+    }
+
+    fn part2(&self, inventories: &Self::Input) -> Option<Answer> {
+        Some(part_two(inventories).into())
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let inventories = Day1.parse(&read_input(2022, 1)?)?;
+
+    println!("Part 1: {:?}", part_one(&inventories));
+    println!("Part 2: {:?}", part_two(&inventories));
+
+    Ok(())
+}
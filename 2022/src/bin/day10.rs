@@ -0,0 +1,198 @@
+use self::Instruction::{Addx, Noop};
+use self::Pixel::{Dark, Lit};
+use aoc_common::{ocr, parse, read_input, Answer, Grid, ParseError, Point2, Solution};
+use std::fmt;
+
+#[derive(Debug, Clone, Copy)]
+pub enum Instruction {
+    Addx(i32, u32),
+    Noop,
+}
+
+enum Output {
+    Instruction(Instruction),
+    Value(i32),
+}
+
+impl Instruction {
+    fn from_string(string: &str) -> Result<Self, ParseError> {
+        let mut instruction = string.split_whitespace();
+        match instruction.next() {
+            Some("noop") => Ok(Noop),
+            Some("addx") => {
+                let value = instruction
+                    .next()
+                    .ok_or_else(|| ParseError::end_of(string, "the value to add"))?;
+                Ok(Addx(parse::token(value, "a number")?, 2))
+            }
+            Some(other) => Err(ParseError::new(other, "noop or addx")),
+            None => Err(ParseError::new(string, "an instruction")),
+        }
+    }
+
+    /// How many cycles the instruction takes to finish
+    fn cycles(&self) -> u32 {
+        match self {
+            Addx(_, cycles) => *cycles,
+            Noop => 1,
+        }
+    }
+
+    fn process(&self, value_total: i32) -> Output {
+        match self {
+            Addx(value, 1) => Output::Value(value_total + value),
+            Addx(value, cycles) => Output::Instruction(Addx(*value, cycles - 1)),
+            Noop => Output::Value(value_total),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Pixel {
+    Lit,
+    Dark,
+}
+
+impl fmt::Display for Pixel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Lit => write!(f, "#"),
+            Dark => write!(f, "."),
+        }
+    }
+}
+
+const WIDTH: u32 = 40;
+const HEIGHT: u32 = 6;
+
+struct Screen(Grid<Pixel>);
+
+impl Screen {
+    fn new() -> Screen {
+        Screen(Grid::new(WIDTH as usize, HEIGHT as usize, Dark))
+    }
+
+    /// The letters that the lit pixels spell out
+    fn read(&self) -> anyhow::Result<String> {
+        ocr::read(&self.0.map(|&pixel| pixel == Lit))
+    }
+}
+
+impl fmt::Display for Screen {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+fn probe(cycles: u32) -> bool {
+    cycles >= 20 && (cycles - 20).is_multiple_of(40)
+}
+
+/// The instructions in `input`, which have to last for every cycle that the
+/// screen is drawn in
+fn parse_instructions(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    let instructions: Vec<_> = input
+        .lines()
+        .map(Instruction::from_string)
+        .collect::<Result<_, _>>()?;
+
+    let cycles: u32 = instructions.iter().map(Instruction::cycles).sum();
+    if cycles < WIDTH * HEIGHT {
+        let expected = format!(
+            "instructions for {} cycles rather than {cycles}",
+            WIDTH * HEIGHT
+        );
+        return Err(ParseError::end_of(input, expected));
+    }
+
+    Ok(instructions)
+}
+
+/// Runs the first `cycles` cycles, calling `during` with each cycle and the
+/// value of the register during it
+fn run(instructions: &[Instruction], cycles: u32, mut during: impl FnMut(u32, i32)) {
+    let mut instructions = instructions.iter().copied();
+
+    let mut value_total = 1;
+    let mut current_instruction: Option<Instruction> = None;
+
+    for cycle in 1..=cycles {
+        let instruction = if let Some(instruction) = current_instruction.take() {
+            instruction
+        } else {
+            instructions
+                .next()
+                .expect("parsing checks that there are instructions for every cycle")
+        };
+
+        during(cycle, value_total);
+
+        let output = instruction.process(value_total);
+        match output {
+            Output::Value(value) => value_total = value,
+            Output::Instruction(instruction) => current_instruction = Some(instruction),
+        }
+    }
+}
+
+fn part_one(instructions: &[Instruction]) -> i32 {
+    let mut signal_strengths: Vec<i32> = vec![];
+
+    run(instructions, 220, |cycle, value_total| {
+        if probe(cycle) {
+            signal_strengths.push(value_total * cycle as i32);
+        }
+    });
+
+    signal_strengths.iter().sum()
+}
+
+fn part_two(instructions: &[Instruction]) -> Screen {
+    let mut screen = Screen::new();
+
+    run(instructions, WIDTH * HEIGHT, |cycle, sprite| {
+        let (x, y) = ((cycle - 1) % WIDTH, (cycle - 1) / WIDTH);
+        if (sprite - x as i32).abs() <= 1 {
+            screen.0[Point2::new(x as i32, y as i32)] = Lit;
+        }
+    });
+
+    screen
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Instruction>;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse_instructions(input).map_err(|error| error.locate(input, 2022, 10).into())
+    }
+
+    fn part1(&self, instructions: &Self::Input) -> Option<Answer> {
+        Some(part_one(instructions).into())
+    }
+
+    // The screen is shown as it is if its letters can't be read
+    fn part2(&self, instructions: &Self::Input) -> Option<Answer> {
+        let screen = part_two(instructions);
+        Some(
+            screen
+                .read()
+                .map_or_else(|_| Answer::image(&screen), Answer::from),
+        )
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let instructions = Day10.parse(&read_input(2022, 10)?)?;
+
+    let sum = part_one(&instructions);
+    println!("Part 1: {:?}", sum);
+
+    let screen = part_two(&instructions);
+    println!("Part 2: {}", screen.read()?);
+    println!("{screen}");
+
+    Ok(())
+}
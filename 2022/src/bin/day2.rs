@@ -0,0 +1,167 @@
+use self::Action::{Paper, Rock, Scissors};
+use self::Outcome::{Draw, Loss, Win};
+use aoc_common::{parse, read_input, Answer, ParseError, Solution};
+
+#[derive(Clone, Copy)]
+pub enum Action {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+/// The second column of the strategy guide, which part 1 reads as my action
+/// and part 2 as the outcome
+#[derive(Clone, Copy)]
+pub enum Response {
+    X,
+    Y,
+    Z,
+}
+
+enum Outcome {
+    Win,
+    Loss,
+    Draw,
+}
+
+fn simulate_game(opponent: &Action, me: &Action) -> Outcome {
+    match (opponent, me) {
+        (Rock, Paper) => Win,
+        (Rock, Scissors) => Loss,
+        (Paper, Rock) => Loss,
+        (Paper, Scissors) => Win,
+        (Scissors, Rock) => Win,
+        (Scissors, Paper) => Loss,
+        (_, _) => Draw,
+    }
+}
+
+fn action_from_string(string: &str) -> Result<Action, ParseError> {
+    match string.to_ascii_lowercase().as_ref() {
+        "a" => Ok(Rock),
+        "b" => Ok(Paper),
+        "c" => Ok(Scissors),
+        _ => Err(ParseError::new(string, "A, B or C")),
+    }
+}
+
+fn response_from_string(string: &str) -> Result<Response, ParseError> {
+    match string.to_ascii_lowercase().as_ref() {
+        "x" => Ok(Response::X),
+        "y" => Ok(Response::Y),
+        "z" => Ok(Response::Z),
+        _ => Err(ParseError::new(string, "X, Y or Z")),
+    }
+}
+
+fn action_from_response(response: Response) -> Action {
+    match response {
+        Response::X => Rock,
+        Response::Y => Paper,
+        Response::Z => Scissors,
+    }
+}
+
+fn outcome_from_response(response: Response) -> Outcome {
+    match response {
+        Response::X => Loss,
+        Response::Y => Draw,
+        Response::Z => Win,
+    }
+}
+
+fn points_from_action(action: Action) -> u32 {
+    match action {
+        Rock => 1,
+        Paper => 2,
+        Scissors => 3,
+    }
+}
+
+fn points_from_outcome(outcome: &Outcome) -> u32 {
+    match outcome {
+        Win => 6,
+        Draw => 3,
+        Loss => 0,
+    }
+}
+
+fn action_from_outcome(opponent_action: Action, outcome: &Outcome) -> Action {
+    match (opponent_action, outcome) {
+        (Rock, Win) => Paper,
+        (Rock, Loss) => Scissors,
+        (Paper, Win) => Scissors,
+        (Paper, Loss) => Rock,
+        (Scissors, Win) => Rock,
+        (Scissors, Loss) => Paper,
+        (action, Draw) => action,
+    }
+}
+
+fn parse_strategies(strategies: &str) -> Result<Vec<(Action, Response)>, ParseError> {
+    strategies
+        .lines()
+        .map(|game| {
+            let (left, right) = parse::split_once(game, " ")?;
+            Ok((action_from_string(left)?, response_from_string(right)?))
+        })
+        .collect()
+}
+
+fn part_one(strategies: &[(Action, Response)]) -> u32 {
+    let score: u32 = strategies
+        .iter()
+        .map(|&(opponent_action, response)| {
+            let my_action = action_from_response(response);
+            let outcome = simulate_game(&opponent_action, &my_action);
+
+            points_from_action(my_action) + points_from_outcome(&outcome)
+        })
+        .sum();
+
+    score
+}
+
+fn part_two(strategies: &[(Action, Response)]) -> u32 {
+    let score: u32 = strategies
+        .iter()
+        .map(|&(opponent_action, response)| {
+            let outcome = outcome_from_response(response);
+            let action = action_from_outcome(opponent_action, &outcome);
+
+            points_from_action(action) + points_from_outcome(&outcome)
+        })
+        .sum();
+
+    score
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<(Action, Response)>;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse_strategies(input).map_err(|error| error.locate(input, 2022, 2).into())
+    }
+
+    fn part1(&self, strategies: &Self::Input) -> Option<Answer> {
+        Some(part_one(strategies).into())
+    }
+
+    fn part2(&self, strategies: &Self::Input) -> Option<Answer> {
+        Some(part_two(strategies).into())
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let strategies = Day2.parse(&read_input(2022, 2)?)?;
+
+    let score = part_one(&strategies);
+    println!("Part 1: {:?}", score);
+
+    let score = part_two(&strategies);
+    println!("Part 2: {:?}", score);
+
+    Ok(())
+}
@@ -0,0 +1,111 @@
+use aoc_common::{read_input, Answer, ParseError, Solution};
+
+fn priority_from_char(char: char) -> u32 {
+    if char.is_lowercase() {
+        char as u32 - 96
+    } else {
+        (char as u32 - 64) + 26
+    }
+}
+
+type Rucksack<'a> = &'a str;
+
+fn common_item(r1: Rucksack, r2: Rucksack, r3: Rucksack) -> Option<char> {
+    r1.chars()
+        .find(|item| r2.contains(*item) && r3.contains(*item))
+}
+
+/// The item that is in both of `rucksack`'s compartments
+fn misplaced_item(rucksack: Rucksack) -> Option<char> {
+    let half = rucksack.len() / 2;
+    let (left, right) = rucksack.split_at(half);
+
+    left.chars().find(|item| right.contains(*item))
+}
+
+/// The rucksacks in `rucksacks`, which have to come in groups of three and
+/// each have an item in both compartments and one in common with their group
+fn parse_rucksacks(rucksacks: &str) -> Result<Vec<String>, ParseError> {
+    let lines: Vec<&str> = rucksacks.lines().collect();
+
+    for rucksack in &lines {
+        if let Some((index, item)) = rucksack
+            .char_indices()
+            .find(|(_, item)| !item.is_ascii_alphabetic())
+        {
+            return Err(ParseError::new(
+                &rucksack[index..index + item.len_utf8()],
+                "an item letter",
+            ));
+        }
+        if misplaced_item(rucksack).is_none() {
+            return Err(ParseError::new(
+                rucksack,
+                "a rucksack with an item in both compartments",
+            ));
+        }
+    }
+
+    for group in lines.chunks(3) {
+        let [r1, r2, r3] = group else {
+            return Err(ParseError::end_of(rucksacks, "a group of three rucksacks"));
+        };
+        if common_item(r1, r2, r3).is_none() {
+            return Err(ParseError::new(
+                r1,
+                "a group of rucksacks with an item in common",
+            ));
+        }
+    }
+
+    Ok(lines.into_iter().map(str::to_string).collect())
+}
+
+fn part_one(rucksacks: &[String]) -> u32 {
+    rucksacks
+        .iter()
+        .map(|rucksack| {
+            let item = misplaced_item(rucksack)
+                .expect("parsing checks that every rucksack has a misplaced item");
+            priority_from_char(item)
+        })
+        .sum()
+}
+
+fn part_two(rucksacks: &[String]) -> u32 {
+    rucksacks
+        .chunks(3)
+        .map(|rucksack_group| {
+            let item = common_item(&rucksack_group[0], &rucksack_group[1], &rucksack_group[2])
+                .expect("parsing checks that every group has an item in common");
+            priority_from_char(item)
+        })
+        .sum()
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse_rucksacks(input).map_err(|error| error.locate(input, 2022, 3).into())
+    }
+
+    fn part1(&self, rucksacks: &Self::Input) -> Option<Answer> {
+        Some(part_one(rucksacks).into())
+    }
+
+    fn part2(&self, rucksacks: &Self::Input) -> Option<Answer> {
+        Some(part_two(rucksacks).into())
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let rucksacks = Day3.parse(&read_input(2022, 3)?)?;
+
+    println!("Part 1: {:?}", part_one(&rucksacks));
+    println!("Part 2: {:?}", part_two(&rucksacks));
+
+    Ok(())
+}
@@ -0,0 +1,82 @@
+use aoc_common::{parse, read_input, Answer, ParseError, Solution};
+
+pub struct Range {
+    left: u32,
+    right: u32,
+}
+
+impl Range {
+    pub fn from_string(range: &str) -> Result<Range, ParseError> {
+        let (left, right) = parse::split_once(range, "-")?;
+
+        Ok(Range {
+            left: parse::token(left, "a section number")?,
+            right: parse::token(right, "a section number")?,
+        })
+    }
+
+    fn contains(&self, range: &Range) -> bool {
+        self.left <= range.left && self.right >= range.right
+    }
+
+    fn overlaps(&self, range: &Range) -> bool {
+        // Overlap on either side of `self`
+        self.right >= range.left && self.left <= range.right
+    }
+}
+
+fn parse_pairs(pairs: &str) -> Result<Vec<(Range, Range)>, ParseError> {
+    pairs
+        .lines()
+        .map(|pair| {
+            let (left_range, right_range) = parse::split_once(pair, ",")?;
+            Ok((
+                Range::from_string(left_range)?,
+                Range::from_string(right_range)?,
+            ))
+        })
+        .collect()
+}
+
+fn part_one(pairs: &[(Range, Range)]) -> usize {
+    pairs
+        .iter()
+        .map(|(left, right)| right.contains(left) || left.contains(right))
+        .filter(|contains| *contains)
+        .count()
+}
+
+fn part_two(pairs: &[(Range, Range)]) -> usize {
+    pairs
+        .iter()
+        .map(|(left, right)| right.overlaps(left))
+        .filter(|overlap| *overlap)
+        .count()
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<(Range, Range)>;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse_pairs(input).map_err(|error| error.locate(input, 2022, 4).into())
+    }
+
+    fn part1(&self, pairs: &Self::Input) -> Option<Answer> {
+        Some(part_one(pairs).into())
+    }
+
+    fn part2(&self, pairs: &Self::Input) -> Option<Answer> {
+        Some(part_two(pairs).into())
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let pairs = Day4.parse(&read_input(2022, 4)?)?;
+
+    println!("Part 1: {:?}", part_one(&pairs));
+    println!("Part 2: {:?}", part_two(&pairs));
+
+    Ok(())
+}
@@ -0,0 +1,181 @@
+use aoc_common::{parse, read_input, Answer, ParseError, Solution};
+use std::fmt::{Debug, Display};
+
+#[derive(Clone, Copy)]
+struct Crate(char);
+
+impl Debug for Crate {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.0.to_string().as_ref())
+    }
+}
+
+impl Display for Crate {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.0.to_string().as_ref())
+    }
+}
+
+fn get_stack_nr(index: usize) -> usize {
+    index / 4 + 1
+}
+
+#[derive(Debug, Clone)]
+pub struct CrateStacks {
+    crate_stacks: Vec<Vec<Crate>>,
+}
+
+impl CrateStacks {
+    fn new() -> Self {
+        CrateStacks {
+            crate_stacks: vec![vec![]],
+        }
+    }
+
+    fn place_crate(&mut self, crt: Crate, stack_nr: usize) {
+        if let Some(stack) = self.crate_stacks.get_mut(stack_nr - 1) {
+            stack.push(crt);
+        } else {
+            self.crate_stacks.push(vec![crt]);
+        }
+    }
+
+    // Assumes that the vector at `stack_nr` already exists
+    fn place_crates(&mut self, crates: &mut Vec<Crate>, stack_nr: usize) {
+        if let Some(stack) = self.crate_stacks.get_mut(stack_nr - 1) {
+            stack.append(crates);
+        } else {
+            self.crate_stacks.push(crates.to_owned());
+        }
+    }
+
+    fn move_crate(&mut self, from_stack: usize, to_stack: usize) {
+        let stack = self.crate_stacks.get_mut(from_stack - 1).unwrap();
+        let crt = stack.pop().unwrap();
+        self.place_crate(crt, to_stack);
+    }
+
+    fn move_crates(&mut self, from_stack: usize, to_stack: usize, count: usize) {
+        let stack = self.crate_stacks.get_mut(from_stack - 1).unwrap();
+        let range = stack.len() - count..stack.len();
+        let mut crates: Vec<Crate> = stack.drain(range).collect();
+        self.place_crates(&mut crates, to_stack);
+    }
+
+    fn get_top_crates(&self) -> Vec<&Crate> {
+        self.crate_stacks
+            .iter()
+            .map(|stack| stack.last().unwrap())
+            .collect()
+    }
+
+    fn top_crates_to_string(&self) -> String {
+        self.get_top_crates()
+            .iter()
+            .map(|c| c.to_string())
+            .collect::<Vec<String>>()
+            .join("")
+    }
+}
+
+/// Moving `count` crates from the stack `from` to the stack `to`
+pub struct Move {
+    count: usize,
+    from: usize,
+    to: usize,
+}
+
+impl Move {
+    fn from_string(string: &str) -> Result<Move, ParseError> {
+        let action: Vec<&str> = string.split(' ').collect();
+        let [count, from, to] = [1, 3, 5].map(|i| {
+            let number = action
+                .get(i)
+                .ok_or_else(|| ParseError::new(string, "a move like 'move 1 from 2 to 3'"))?;
+            parse::token::<usize>(number, "a number")
+        });
+
+        Ok(Move {
+            count: count?,
+            from: from?,
+            to: to?,
+        })
+    }
+}
+
+fn initialize(data: &str) -> Result<(CrateStacks, Vec<Move>), ParseError> {
+    let (crates, moves) = data
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::end_of(data, "an empty line between the crates and moves"))?;
+
+    let mut crate_stacks = CrateStacks::new();
+
+    // Initialize crate stack configurations
+    for line in crates.lines().rev().skip(1) {
+        for (i, window) in line.chars().collect::<Vec<char>>().windows(3).enumerate() {
+            if let ('[', crt, ']') = (window[0], window[1], window[2]) {
+                crate_stacks.place_crate(Crate(crt), get_stack_nr(i));
+            }
+        }
+    }
+
+    let moves = moves
+        .lines()
+        .map(Move::from_string)
+        .collect::<Result<_, _>>()?;
+
+    Ok((crate_stacks, moves))
+}
+
+fn part_one((crate_stacks, moves): &(CrateStacks, Vec<Move>)) -> String {
+    let mut crate_stacks = crate_stacks.clone();
+
+    // Perform moves
+    for action in moves {
+        for _ in 0..action.count {
+            crate_stacks.move_crate(action.from, action.to);
+        }
+    }
+
+    // Get top crates
+    crate_stacks.top_crates_to_string()
+}
+
+fn part_two((crate_stacks, moves): &(CrateStacks, Vec<Move>)) -> String {
+    let mut crate_stacks = crate_stacks.clone();
+
+    // Perform moves
+    for action in moves {
+        crate_stacks.move_crates(action.from, action.to, action.count);
+    }
+
+    // Get top crates
+    crate_stacks.top_crates_to_string()
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = (CrateStacks, Vec<Move>);
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        initialize(input).map_err(|error| error.locate(input, 2022, 5).into())
+    }
+
+    fn part1(&self, procedure: &Self::Input) -> Option<Answer> {
+        Some(part_one(procedure).into())
+    }
+
+    fn part2(&self, procedure: &Self::Input) -> Option<Answer> {
+        Some(part_two(procedure).into())
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let procedure = Day5.parse(&read_input(2022, 5)?)?;
+
+    println!("Part 1: {:?}", part_one(&procedure));
+    println!("Part 2: {:?}", part_two(&procedure));
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use aoc_common::{read_input, Answer, Solution};
+
+fn has_duplicate(signal: &[char]) -> bool {
+    let mut signal = signal.to_vec();
+    signal.sort();
+    signal.windows(2).any(|pair| pair[0] == pair[1])
+}
+
+fn get_first_n_uniques_end_pos(signal: &[char], n: usize) -> anyhow::Result<usize> {
+    signal
+        .windows(n)
+        .enumerate()
+        .find_map(|(i, window)| {
+            if !has_duplicate(window) {
+                Some(i + window.len())
+            } else {
+                None
+            }
+        })
+        .ok_or_else(|| anyhow::anyhow!("the signal has no {n} different characters in a row"))
+}
+
+fn parse_signal(signal: &str) -> Vec<char> {
+    signal.trim_end().chars().collect()
+}
+
+fn part_one(signal: &[char]) -> anyhow::Result<usize> {
+    get_first_n_uniques_end_pos(signal, 4)
+}
+
+fn part_two(signal: &[char]) -> anyhow::Result<usize> {
+    get_first_n_uniques_end_pos(signal, 14)
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Vec<char>;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse_signal(input))
+    }
+
+    fn try_part1(&self, signal: &Self::Input) -> anyhow::Result<Option<Answer>> {
+        Ok(Some(part_one(signal)?.into()))
+    }
+
+    fn try_part2(&self, signal: &Self::Input) -> anyhow::Result<Option<Answer>> {
+        Ok(Some(part_two(signal)?.into()))
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let signal = parse_signal(&read_input(2022, 6)?);
+
+    println!("Part 1: {:?}", part_one(&signal)?);
+    println!("Part 2: {:?}", part_two(&signal)?);
+
+    Ok(())
+}
@@ -1,11 +1,15 @@
-use std::{io::{self, BufRead}, fs, rc::{Weak, Rc}, borrow::BorrowMut};
+use aoc_common::read_input;
+use std::{
+    borrow::BorrowMut,
+    rc::{Rc, Weak},
+};
 
 type Size = u32;
 
 #[derive(Debug)]
 enum Inode<'a> {
     File(File),
-    Directory(Directory<'a>)
+    Directory(Directory<'a>),
 }
 
 #[derive(Debug)]
@@ -17,43 +21,41 @@ struct Directory<'a> {
     children: Vec<Rc<Inode<'a>>>,
 }
 
-fn parse_line(line: &String) {
-    let mut tokens = line.split(" ");
+fn parse_line(line: &str) {
+    let mut tokens = line.split(' ');
     match tokens.next() {
         Some("$") => {}
         Some("dir") => {}
         Some(size) => {}
-        None => panic!("TODO")
+        None => panic!("TODO"),
     }
 }
 
-fn part_one() {
-    let file_path = "data.txt";
-    let file = fs::File::open(file_path).expect("File not found");
-    let _lines = io::BufReader::new(file).lines();
+fn part_one(lines: &str) {
+    let _lines = lines.lines();
 }
 
-fn main() {
-    part_one();
+fn main() -> anyhow::Result<()> {
+    let data = &read_input(2022, 7)?;
+
+    part_one(data);
     // part_two();
 
     let _d1 = Directory {
         name: "root",
         parent: &None,
-        children: vec![
-            Rc::new(Inode::File(File(3)))
-        ]
+        children: vec![Rc::new(Inode::File(File(3)))],
     };
 
     let mut _d2 = Directory {
         name: "subdir",
         parent: &Some(Weak::new()),
-        children: vec![
-            Rc::new(Inode::File(File(3)))
-        ]
+        children: vec![Rc::new(Inode::File(File(3)))],
     };
 
+    println!("_d1: {:?}", &_d1);
+
     *_d2.parent.borrow_mut() = &Some(Rc::downgrade(&Rc::new(_d1)));
 
-    println!("_d1: {:?}", &_d1);
+    Ok(())
 }
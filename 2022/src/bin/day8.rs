@@ -0,0 +1,139 @@
+use aoc_common::{read_input, Answer, Grid, ParseError, Solution};
+
+struct Coordinate {
+    y: usize,
+    x: usize,
+}
+
+type Tree = u32;
+type Forest = Vec<Vec<Tree>>;
+
+fn is_tallest(height: u32, treeline: Vec<Tree>) -> bool {
+    treeline.iter().all(|tree| tree < &height)
+}
+
+/// Splits `treeline` at tree at `position`, and excludes the tree at the split
+/// `position`
+fn split_treeline(treeline: &[Tree], position: usize) -> (Vec<Tree>, Vec<Tree>) {
+    let (left, right_with_tree) = treeline.split_at(position);
+    let (_, right) = right_with_tree.split_first().unwrap();
+    (left.to_vec(), right.to_vec())
+}
+
+fn get_vertical_treeline(forest: &Forest, x: usize) -> Vec<u32> {
+    forest.iter().map(|treeline| treeline[x]).collect()
+}
+
+fn is_horizontally_visible(tree: &Coordinate, forest: &Forest) -> bool {
+    let (left, right) = split_treeline(&forest[tree.y], tree.x);
+    let height = forest[tree.y][tree.x];
+
+    is_tallest(height, right.to_vec()) || is_tallest(height, left.to_vec())
+}
+
+fn is_vertically_visible(tree: &Coordinate, forest: &Forest) -> bool {
+    let treeline = get_vertical_treeline(forest, tree.x);
+    let (top, bottom) = split_treeline(&treeline, tree.y);
+    let height = forest[tree.y][tree.x];
+
+    is_tallest(height, top.to_vec()) || is_tallest(height, bottom.to_vec())
+}
+
+fn is_visible(tree: &Coordinate, forest: &Forest) -> bool {
+    is_horizontally_visible(tree, forest) || is_vertically_visible(tree, forest)
+}
+
+/// The rows of trees in `forest`, which has to be a rectangle with at least
+/// one tree in it
+fn parse_forest(forest: &str) -> Result<Forest, ParseError> {
+    let grid = Grid::try_parse(forest, "a tree height", |tree| tree.to_digit(10))?;
+    if grid.width() == 0 || grid.height() == 0 {
+        return Err(ParseError::end_of(forest, "a row of trees"));
+    }
+
+    Ok(grid.rows().map(<[Tree]>::to_vec).collect())
+}
+
+fn part_one(forest: &Forest) -> usize {
+    let visible_trees: Vec<bool> = forest
+        .iter()
+        .enumerate()
+        .flat_map(|(y, treeline)| {
+            treeline
+                .iter()
+                .enumerate()
+                .map(|(x, _)| is_visible(&Coordinate { x, y }, forest))
+                .collect::<Vec<bool>>()
+        })
+        .collect();
+
+    visible_trees.into_iter().filter(|tree| *tree).count()
+}
+
+fn count_visible_trees_to_right(treeline: &[Tree], height: u32) -> usize {
+    // This had to be imperative since I couldn't find a nice way to do this with iterators
+    let mut count = 0;
+    for tree in treeline {
+        count += 1;
+        if *tree >= height {
+            break;
+        }
+    }
+    count
+}
+
+fn scenic_score(forest: &Forest, tree: &Coordinate) -> usize {
+    let height = forest[tree.y][tree.x];
+    let (mut left, right) = split_treeline(&forest[tree.y], tree.x);
+    let vertical_treeline = get_vertical_treeline(forest, tree.x);
+    let (mut top, bottom) = split_treeline(&vertical_treeline, tree.y);
+    left.reverse();
+    top.reverse();
+
+    [left, right, top, bottom]
+        .iter()
+        .map(|treeline| count_visible_trees_to_right(treeline, height))
+        .product()
+}
+
+fn part_two(forest: &Forest) -> usize {
+    forest
+        .iter()
+        .enumerate()
+        .flat_map(|(y, treeline)| {
+            treeline
+                .iter()
+                .enumerate()
+                .map(|(x, _)| scenic_score(forest, &Coordinate { x, y }))
+                .collect::<Vec<usize>>()
+        })
+        .max()
+        .expect("parsing checks that there are trees")
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Forest;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse_forest(input).map_err(|error| error.locate(input, 2022, 8).into())
+    }
+
+    fn part1(&self, forest: &Self::Input) -> Option<Answer> {
+        Some(part_one(forest).into())
+    }
+
+    fn part2(&self, forest: &Self::Input) -> Option<Answer> {
+        Some(part_two(forest).into())
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let forest = Day8.parse(&read_input(2022, 8)?)?;
+
+    println!("Part 1: {:?}", part_one(&forest));
+    println!("Part 2: {:?}", part_two(&forest));
+
+    Ok(())
+}
@@ -1,7 +1,6 @@
-use self::Direction::{Down, Left, Right, Up};
+use aoc_common::Direction::{self, Down, Left, Right, Up};
+use aoc_common::{parse, read_input, Answer, ParseError, Simulate, Solution};
 use std::fmt;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
 
 #[derive(Debug, Clone, Copy)]
 struct Coordinate {
@@ -60,8 +59,8 @@ impl Matrix {
         let max = self.size() - 1;
         match move_direction {
             Up => coordinate.y >= max,
-            Down => coordinate.y <= 0,
-            Left => coordinate.x <= 0,
+            Down => coordinate.y == 0,
+            Left => coordinate.x == 0,
             Right => coordinate.x >= max,
         }
     }
@@ -95,26 +94,6 @@ impl Matrix {
     }
 }
 
-#[derive(Debug)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-
-impl Direction {
-    fn from_str(string: &str) -> Self {
-        match string {
-            "U" => Up,
-            "D" => Down,
-            "L" => Left,
-            "R" => Right,
-            _ => panic!("Direction not recognized"),
-        }
-    }
-}
-
 #[derive(Debug)]
 struct Roap(Vec<Coordinate>);
 
@@ -160,8 +139,8 @@ impl Simulation {
 
     fn halve_and_round_up(value: i32) -> i32 {
         let sign = if value < 0 { -1 } else { 1 };
-        let halved = value as f64 / 2 as f64;
-        return halved.abs().ceil() as i32 * sign;
+        let halved = value as f64 / 2_f64;
+        halved.abs().ceil() as i32 * sign
     }
 
     fn adjust_trailing_knot(lead: &Coordinate, trail: &mut Coordinate) {
@@ -187,12 +166,12 @@ impl Simulation {
     }
 
     fn move_roap(&mut self, direction: &Direction) {
-        if self.matrix.needs_expanding(&self.roap.head(), direction) {
+        if self.matrix.needs_expanding(self.roap.head(), direction) {
             self.expand();
         }
 
         // Move head
-        Self::move_roap_knot(&mut self.roap.head_mut(), &direction);
+        Self::move_roap_knot(self.roap.head_mut(), direction);
 
         // Adjust rest of roap
         for i in 1..self.roap.0.len() {
@@ -200,7 +179,7 @@ impl Simulation {
             Self::adjust_trailing_knot(lead, trail)
         }
 
-        self.matrix.set(&self.roap.tail(), true);
+        self.matrix.set(self.roap.tail(), true);
     }
 
     fn move_roap_count(&mut self, direction: Direction, count: u32) {
@@ -235,7 +214,7 @@ impl Simulation {
     }
 }
 
-impl fmt::Debug for Simulation {
+impl fmt::Display for Simulation {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let matrix = &self
             .matrix
@@ -260,35 +239,98 @@ impl fmt::Debug for Simulation {
             .rev()
             .collect::<Vec<String>>()
             .join("\n");
-        let mut matrix_with_newline = "\n".to_string();
-        matrix_with_newline.push_str(matrix);
-        write!(f, "{}", matrix_with_newline)
+        write!(f, "{}", matrix)
     }
 }
 
-fn simulate(roap_length: u32) {
-    let file_path = "data.txt";
-    let file = File::open(file_path).expect("File not found");
-    let movements = BufReader::new(file).lines();
+/// The rope making one step of its movements each tick
+struct Moving {
+    simulation: Simulation,
+    steps: std::vec::IntoIter<Direction>,
+}
 
-    let mut simulation = Simulation::new(roap_length);
+impl Moving {
+    fn new(movements: &[(Direction, u32)], roap_length: u32) -> Self {
+        let steps = movements
+            .iter()
+            .flat_map(|&(direction, count)| (0..count).map(move |_| direction));
+
+        Moving {
+            simulation: Simulation::new(roap_length),
+            steps: steps.collect::<Vec<_>>().into_iter(),
+        }
+    }
+}
+
+impl Simulate for Moving {
+    fn tick(&mut self) -> bool {
+        let Some(direction) = self.steps.next() else {
+            return false;
+        };
+        self.simulation.move_roap(&direction);
+        true
+    }
+}
+
+impl fmt::Display for Moving {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.simulation)
+    }
+}
+
+fn parse_movements(movements: &str) -> Result<Vec<(Direction, u32)>, ParseError> {
+    movements
+        .lines()
+        .map(|movement| {
+            let (direction, count) = parse::split_once(movement, " ")?;
+
+            Ok((
+                parse::token(direction, "U, D, L or R")?,
+                parse::token(count, "a number of steps")?,
+            ))
+        })
+        .collect()
+}
 
-    movements.for_each(|movement| {
-        let movement = movement.unwrap();
-        let (direction, count) = movement.split_once(' ').unwrap();
-        let direction = Direction::from_str(direction);
-        let count: u32 = count.parse().unwrap();
+fn simulate(movements: &[(Direction, u32)], roap_length: u32) -> usize {
+    let mut simulation = Simulation::new(roap_length);
 
+    movements.iter().for_each(|&(direction, count)| {
         simulation.move_roap_count(direction, count);
     });
 
-    // println!("simulation: {:?}", simulation);
+    // println!("simulation:\n{}", simulation);
 
-    let count = simulation.matrix.count_visited_coordinates();
-    println!("count: {:?}", count);
+    simulation.matrix.count_visited_coordinates()
 }
 
-fn main() {
-    simulate(2); // part 1
-    simulate(10); // part 2
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<(Direction, u32)>;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse_movements(input).map_err(|error| error.locate(input, 2022, 9).into())
+    }
+
+    fn part1(&self, movements: &Self::Input) -> Option<Answer> {
+        Some(simulate(movements, 2).into())
+    }
+
+    fn part2(&self, movements: &Self::Input) -> Option<Answer> {
+        Some(simulate(movements, 10).into())
+    }
+
+    fn simulation(&self, movements: &Self::Input) -> Option<Box<dyn Simulate>> {
+        Some(Box::new(Moving::new(movements, 10)))
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let movements = Day9.parse(&read_input(2022, 9)?)?;
+
+    println!("count: {:?}", simulate(&movements, 2)); // part 1
+    println!("count: {:?}", simulate(&movements, 10)); // part 2;
+
+    Ok(())
 }
@@ -1,3 +1,4 @@
+use aoc_common::{read_input, Answer, ParseError, Solution};
 use std::collections::HashMap;
 
 fn get_literal_to_digit_mapping() -> HashMap<&'static str, char> {
@@ -56,11 +57,45 @@ fn extract_number_from_line(string: &str) -> u32 {
     format!("{}{}", first, last).parse().unwrap()
 }
 
-fn main() {
-    let data = include_str!("../../data/day1");
-    let sum: u32 = data.lines().map(extract_number_from_line).sum();
+/// The lines of the calibration document, which each have at least one digit
+fn parse_lines(document: &str) -> Result<Vec<String>, ParseError> {
+    document
+        .lines()
+        .map(|line| match get_first_digit(line) {
+            Some(_) => Ok(line.to_string()),
+            None => Err(ParseError::new(line, "a line with a digit")),
+        })
+        .collect()
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse_lines(input).map_err(|error| error.locate(input, 2023, 1).into())
+    }
+
+    fn part2(&self, lines: &Self::Input) -> Option<Answer> {
+        let sum: u32 = lines
+            .iter()
+            .map(|line| extract_number_from_line(line))
+            .sum();
+        Some(sum.into())
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let lines = Day1.parse(&read_input(2023, 1)?)?;
+    let sum: u32 = lines
+        .iter()
+        .map(|line| extract_number_from_line(line))
+        .sum();
 
     println!("Day 1.2: {:#?}", sum);
+
+    Ok(())
 }
 
 #[test]
@@ -1,9 +1,17 @@
-use self::Direction::{East, North, South, West};
+use aoc_common::Direction::{self, Down as South, Left as West, Right as East, Up as North};
+use aoc_common::{read_input, Answer, ParseError, Solution};
 use std::{fmt::Display, ops::Add};
 
 #[derive(Debug, PartialEq, Clone, Copy)]
 struct Coordinate(i32, i32);
 
+impl From<Direction> for Coordinate {
+    fn from(direction: Direction) -> Self {
+        let offset = direction.offset();
+        Coordinate(offset.x, offset.y)
+    }
+}
+
 impl Add for &Coordinate {
     type Output = Coordinate;
 
@@ -12,7 +20,7 @@ impl Add for &Coordinate {
     }
 }
 
-struct Maze(Vec<Vec<Tile>>);
+pub struct Maze(Vec<Vec<Tile>>);
 
 impl Maze {
     fn get_tile(&self, Coordinate(x, y): &Coordinate) -> Option<&Tile> {
@@ -31,12 +39,12 @@ impl Maze {
         coordinate: &Coordinate,
         direction: &Direction,
     ) -> Option<Coordinate> {
-        let neighbour_coordinate = coordinate + &direction.get_delta();
+        let neighbour_coordinate = coordinate + &Coordinate::from(*direction);
 
         self.get_tile(&neighbour_coordinate)
             .and_then(move |neighbour_tile| match neighbour_tile {
                 Tile::Pipe(pipe) => {
-                    if pipe.has_direction(&direction.get_opposite()) {
+                    if pipe.has_direction(&direction.opposite()) {
                         Some(neighbour_coordinate)
                     } else {
                         None
@@ -59,7 +67,11 @@ impl Maze {
             .expect("Start tile should exist")
     }
 
-    fn follow_pipe<'a>(&'a self, coordinate: Coordinate, direction: &'a Direction) -> PipeIterator {
+    fn follow_pipe<'a>(
+        &'a self,
+        coordinate: Coordinate,
+        direction: &'a Direction,
+    ) -> PipeIterator<'a> {
         PipeIterator {
             maze: self,
             tile_coordinate: coordinate,
@@ -69,7 +81,7 @@ impl Maze {
     }
 
     fn get_loop_length(&self, start: Coordinate, direction: Direction) -> Option<usize> {
-        let iterator_start = &start + &direction.get_delta();
+        let iterator_start = &start + &Coordinate::from(direction);
         self.follow_pipe(iterator_start, &direction)
             .enumerate()
             .last()
@@ -126,17 +138,40 @@ impl<'a> Iterator for PipeIterator<'a> {
     }
 }
 
-impl From<&str> for Maze {
-    fn from(string: &str) -> Self {
+impl TryFrom<&str> for Maze {
+    type Error = ParseError;
+
+    fn try_from(string: &str) -> Result<Self, Self::Error> {
         let maze = string
             .lines()
-            .map(|line| line.chars().map(Tile::from).collect())
-            .collect();
+            .map(|line| {
+                line.char_indices()
+                    .map(|(index, c)| {
+                        Tile::from_char(c).ok_or_else(|| {
+                            ParseError::new(
+                                &line[index..index + c.len_utf8()],
+                                "a pipe, '.' or 'S'",
+                            )
+                        })
+                    })
+                    .collect()
+            })
+            .collect::<Result<_, _>>()?;
 
-        Maze(maze)
+        Ok(Maze(maze))
     }
 }
 
+/// A maze to solve, which unlike the mazes in the tests must have a start
+fn parse_maze(maze: &str) -> Result<Maze, ParseError> {
+    let parsed = Maze::try_from(maze)?;
+    if !parsed.0.iter().flatten().any(|tile| tile == &Tile::Start) {
+        return Err(ParseError::end_of(maze, "a start tile 'S'"));
+    }
+
+    Ok(parsed)
+}
+
 impl std::fmt::Debug for Maze {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let maze = &self
@@ -150,40 +185,12 @@ impl std::fmt::Debug for Maze {
     }
 }
 
-#[derive(Debug, PartialEq, Clone, Copy)]
-enum Direction {
-    North,
-    East,
-    South,
-    West,
-}
-
-impl Direction {
-    fn get_delta(&self) -> Coordinate {
-        match self {
-            North => Coordinate(0, -1),
-            East => Coordinate(1, 0),
-            South => Coordinate(0, 1),
-            West => Coordinate(-1, 0),
-        }
-    }
-
-    fn get_opposite(&self) -> Self {
-        match self {
-            North => South,
-            East => West,
-            South => North,
-            West => East,
-        }
-    }
-}
-
 #[derive(PartialEq, Debug)]
 struct Pipe(Direction, Direction);
 
 impl Pipe {
     fn get_end_direction(&self, direction: &Direction) -> &Direction {
-        if direction.get_opposite() == self.0 {
+        if direction.opposite() == self.0 {
             &self.1
         } else {
             &self.0
@@ -216,18 +223,18 @@ enum Tile {
     Start,
 }
 
-impl From<char> for Tile {
-    fn from(c: char) -> Self {
+impl Tile {
+    fn from_char(c: char) -> Option<Self> {
         match c {
-            '|' => Tile::Pipe(Pipe(North, South)),
-            '-' => Tile::Pipe(Pipe(East, West)),
-            'L' => Tile::Pipe(Pipe(North, East)),
-            'J' => Tile::Pipe(Pipe(North, West)),
-            '7' => Tile::Pipe(Pipe(South, West)),
-            'F' => Tile::Pipe(Pipe(South, East)),
-            '.' => Tile::Ground,
-            'S' => Tile::Start,
-            other => panic!("Unexpected character '{}' found", other),
+            '|' => Some(Tile::Pipe(Pipe(North, South))),
+            '-' => Some(Tile::Pipe(Pipe(East, West))),
+            'L' => Some(Tile::Pipe(Pipe(North, East))),
+            'J' => Some(Tile::Pipe(Pipe(North, West))),
+            '7' => Some(Tile::Pipe(Pipe(South, West))),
+            'F' => Some(Tile::Pipe(Pipe(South, East))),
+            '.' => Some(Tile::Ground),
+            'S' => Some(Tile::Start),
+            _ => None,
         }
     }
 }
@@ -242,23 +249,39 @@ impl Display for Tile {
     }
 }
 
-fn main() {
-    let data = include_str!("../../data/day10");
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Maze;
 
-    let maze: Maze = data.into();
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse_maze(input).map_err(|error| error.locate(input, 2023, 10).into())
+    }
+
+    fn part1(&self, maze: &Self::Input) -> Option<Answer> {
+        let loop_length = maze.find_loop()?;
+
+        Some(divide_rounding_up(loop_length as _, 2).into())
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let maze = Day10.parse(&read_input(2023, 10)?)?;
     let loop_length = maze.find_loop();
 
     let farthest_away_position = divide_rounding_up(loop_length.unwrap() as _, 2);
     println!("Part 1: {}", farthest_away_position);
+
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{Coordinate, Direction, Maze};
+    use super::{Coordinate, Maze};
+    use aoc_common::Direction::{Down as South, Left as West, Right as East, Up as North};
     use indoc::indoc;
     use itertools::Itertools;
     use pretty_assertions::assert_eq;
-    use Direction::{East, North, South, West};
 
     #[test]
     fn identifies_continuing_pipe() {
@@ -269,7 +292,8 @@ mod tests {
             .L-J.
             .....
         "}
-        .into();
+        .try_into()
+        .unwrap();
 
         let top_left = &Coordinate(1, 1);
 
@@ -305,7 +329,8 @@ mod tests {
             .L-J.
             .....
         "}
-        .into();
+        .try_into()
+        .unwrap();
 
         assert_eq!(maze.find_start(), Coordinate(1, 1));
 
@@ -316,7 +341,8 @@ mod tests {
             .LSJ.
             .....
         "}
-        .into();
+        .try_into()
+        .unwrap();
 
         assert_eq!(maze.find_start(), Coordinate(2, 3));
     }
@@ -329,7 +355,8 @@ mod tests {
             .L-J.
             .....
         "}
-        .into();
+        .try_into()
+        .unwrap();
 
         let result = maze.follow_pipe(Coordinate(1, 2), &South).collect_vec();
         let expected = [
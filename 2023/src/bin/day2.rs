@@ -1,7 +1,11 @@
 use self::Color::{Blue, Green, Red};
+use aoc_common::{
+    combinators::{comma_list, finish, lines, unsigned},
+    read_input, Answer, ParseError, Solution,
+};
 use nom::{
     bytes::complete::tag,
-    character::complete::{alpha1, digit1},
+    character::complete::{alpha1, space1},
     combinator::{map, map_res},
     multi::separated_list1,
     sequence::{delimited, separated_pair, tuple},
@@ -16,13 +20,15 @@ enum Color {
     Blue,
 }
 
-impl From<&str> for Color {
-    fn from(s: &str) -> Self {
+impl TryFrom<&str> for Color {
+    type Error = ParseError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
         match s {
-            "red" => Red,
-            "green" => Green,
-            "blue" => Blue,
-            other => panic!("Unexpected color '{}' found", other),
+            "red" => Ok(Red),
+            "green" => Ok(Green),
+            "blue" => Ok(Blue),
+            other => Err(ParseError::new(other, "red, green or blue")),
         }
     }
 }
@@ -32,7 +38,7 @@ type Draw = HashMap<Color, Count>;
 type Id = u16;
 
 #[derive(Debug, Default)]
-struct Game {
+pub struct Game {
     id: Id,
     draws: Vec<Draw>,
 }
@@ -72,15 +78,11 @@ impl Game {
 }
 
 fn parse_game_id(i: &str) -> IResult<&str, Id> {
-    delimited(tag("Game "), map_res(digit1, str::parse), tag(": "))(i)
+    delimited(tag("Game "), unsigned, tag(": "))(i)
 }
 
 fn parse_color(i: &str) -> IResult<&str, (Count, Color)> {
-    separated_pair(
-        map_res(digit1, str::parse),
-        tag(" "),
-        map(alpha1, Color::from),
-    )(i)
+    separated_pair(unsigned, space1, map_res(alpha1, Color::try_from))(i)
 }
 
 fn into_draw(colors: Vec<(Count, Color)>) -> Draw {
@@ -91,22 +93,32 @@ fn into_draw(colors: Vec<(Count, Color)>) -> Draw {
 }
 
 fn parse_draw(i: &str) -> IResult<&str, Draw> {
-    map(separated_list1(tag(", "), parse_color), into_draw)(i)
+    map(comma_list(parse_color), into_draw)(i)
 }
 
 fn parse_draws(i: &str) -> IResult<&str, Vec<Draw>> {
     separated_list1(tag("; "), parse_draw)(i)
 }
 
-fn parse_line(i: &str) -> Game {
-    let (_, game) = tuple((parse_game_id, parse_draws))(i).unwrap();
-    game.into()
+fn parse_game(i: &str) -> IResult<&str, Game> {
+    map(tuple((parse_game_id, parse_draws)), Game::from)(i)
+}
+
+fn parse_line(i: &str) -> Result<Game, ParseError> {
+    finish(parse_game, i, "a game like 'Game 1: 3 blue; 4 red'")
 }
 
-fn possible_games(lines: &str) -> Vec<Id> {
-    lines
-        .lines()
-        .map(parse_line)
+fn parse_games(games: &str) -> Result<Vec<Game>, ParseError> {
+    finish(
+        lines(parse_game),
+        games,
+        "a game like 'Game 1: 3 blue; 4 red'",
+    )
+}
+
+fn possible_games(games: &[Game]) -> Vec<Id> {
+    games
+        .iter()
         .filter(|game| {
             game.max_count(Red) <= 12 && game.max_count(Green) <= 13 && game.max_count(Blue) <= 14
         })
@@ -114,22 +126,41 @@ fn possible_games(lines: &str) -> Vec<Id> {
         .collect()
 }
 
-fn game_powers(lines: &str) -> Vec<u32> {
-    lines
-        .lines()
-        .map(parse_line)
+fn game_powers(games: &[Game]) -> Vec<u32> {
+    games
+        .iter()
         .map(|game| game.max_count(Red) * game.max_count(Green) * game.max_count(Blue))
         .collect()
 }
 
-fn main() {
-    let data = include_str!("../../data/day2");
-    let id_sum: u16 = possible_games(data).iter().sum();
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Game>;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse_games(input).map_err(|error| error.locate(input, 2023, 2).into())
+    }
+
+    fn part1(&self, games: &Self::Input) -> Option<Answer> {
+        Some(possible_games(games).iter().sum::<u16>().into())
+    }
+
+    fn part2(&self, games: &Self::Input) -> Option<Answer> {
+        Some(game_powers(games).into_iter().sum::<u32>().into())
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let games = Day2.parse(&read_input(2023, 2)?)?;
+    let id_sum: u16 = possible_games(&games).iter().sum();
     println!("Part 1: {}", id_sum);
 
-    let powers = game_powers(data);
+    let powers = game_powers(&games);
     let sum: u32 = powers.into_iter().sum();
     println!("Part 2: {}", sum);
+
+    Ok(())
 }
 
 #[test]
@@ -148,7 +179,7 @@ fn parses_game_id() {
 
 #[test]
 fn parses_line() {
-    let game = parse_line("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green");
+    let game = parse_line("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green").unwrap();
 
     assert_eq!(game.max_count(Red), 4);
     assert_eq!(game.max_count(Green), 2);
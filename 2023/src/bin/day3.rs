@@ -1,7 +1,9 @@
+use aoc_common::{read_input, Answer, ParseError, Solution};
+
 type Schematic = Vec<Vec<char>>;
 
 #[derive(Debug)]
-struct Engine {
+pub struct Engine {
     schematic: Schematic,
 }
 
@@ -13,6 +15,19 @@ impl From<&str> for Engine {
     }
 }
 
+/// The engine in `schematic`, whose lines must all be as wide as the first
+fn parse_engine(schematic: &str) -> Result<Engine, ParseError> {
+    let width = schematic.lines().next().map_or(0, str::len);
+    if let Some(line) = schematic.lines().find(|line| line.len() != width) {
+        return Err(ParseError::new(
+            line,
+            format!("a line of {width} characters"),
+        ));
+    }
+
+    Ok(Engine::from(schematic))
+}
+
 fn get_adjacent_positions() -> Vec<(i32, i32)> {
     (-1..=1)
         .flat_map(|y| (-1..=1).map(move |x| (x, y)))
@@ -89,9 +104,22 @@ impl Engine {
     }
 }
 
-fn main() {
-    let schematic = include_str!("../../data/day3");
-    let engine = Engine::from(schematic);
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Engine;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse_engine(input).map_err(|error| error.locate(input, 2023, 3).into())
+    }
+
+    fn part1(&self, engine: &Self::Input) -> Option<Answer> {
+        Some(engine.parse().iter().sum::<u32>().into())
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let engine = Day3.parse(&read_input(2023, 3)?)?;
     let result = engine.parse();
     let sum: u32 = result.iter().sum();
 
@@ -101,6 +129,8 @@ fn main() {
     let sum: u32 = result.iter().sum();
 
     println!("Part 1: {:#?}", sum);
+
+    Ok(())
 }
 
 #[test]
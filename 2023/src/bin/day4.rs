@@ -1,3 +1,4 @@
+use aoc_common::{parse, read_input, Answer, ParseError, Solution};
 use indexmap::IndexMap;
 use indoc::indoc;
 use std::cell::RefCell;
@@ -75,10 +76,10 @@ impl std::fmt::Debug for Pile {
     }
 }
 
-fn to_numbers(string: &str) -> Vec<Id> {
+fn to_numbers(string: &str) -> Result<Vec<Id>, ParseError> {
     string
         .split_whitespace()
-        .map(|number| number.parse().expect("Should be a number"))
+        .map(|number| parse::token(number, "a number"))
         .collect()
 }
 
@@ -89,19 +90,26 @@ fn find_winning_numbers(winning_numbers: Vec<Id>, your_numbers: Vec<Id>) -> Vec<
         .collect()
 }
 
-fn parse_winning_numbers(line: &str) -> Vec<Id> {
-    let (card, number_list) = line.split_once(':').expect(": always exists");
-    let (left_list, right_list) = number_list.split_once('|').expect("| always exists");
-    let card_nr = card.split_whitespace().nth(1).expect("Should exist");
+fn parse_winning_numbers(line: &str) -> Result<Vec<Id>, ParseError> {
+    let (card, number_list) = parse::split_once(line, ":")?;
+    let (left_list, right_list) = parse::split_once(number_list, "|")?;
+    let card_nr = card
+        .split_whitespace()
+        .nth(1)
+        .ok_or_else(|| ParseError::new(card, "a card like 'Card 1'"))?;
+
+    let left: Vec<_> = to_numbers(left_list)?;
+    let right: Vec<_> = to_numbers(right_list)?;
+    let _card_nr: Id = parse::token(card_nr, "a card number")?;
 
-    let left: Vec<_> = to_numbers(left_list);
-    let right: Vec<_> = to_numbers(right_list);
-    let _card_nr: Id = card_nr.parse().expect("Should be a number");
+    Ok(find_winning_numbers(left, right))
+}
 
-    find_winning_numbers(left, right)
+fn parse_cards(cards: &str) -> Result<Vec<Vec<Id>>, ParseError> {
+    cards.lines().map(parse_winning_numbers).collect()
 }
 
-fn card_value(numbers: &Vec<Id>) -> Id {
+fn card_value(numbers: &[Id]) -> Id {
     let count = numbers.len() as Id;
     if count == 0 {
         0
@@ -110,12 +118,32 @@ fn card_value(numbers: &Vec<Id>) -> Id {
     }
 }
 
-fn main() {
-    let cards = include_str!("../../data/day4");
-    let cards: Vec<_> = cards.lines().map(parse_winning_numbers).collect();
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<Vec<Id>>;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse_cards(input).map_err(|error| error.locate(input, 2023, 4).into())
+    }
+
+    fn part1(&self, cards: &Self::Input) -> Option<Answer> {
+        Some(cards.iter().map(|card| card_value(card)).sum::<Id>().into())
+    }
+
+    fn part2(&self, cards: &Self::Input) -> Option<Answer> {
+        let pile: Pile = cards.clone().into();
+        pile.generate_cards();
+
+        Some(pile.count_card_copies().into())
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let cards = Day4.parse(&read_input(2023, 4)?)?;
 
     // Day 1
-    let sum: Id = cards.iter().map(card_value).sum();
+    let sum: Id = cards.iter().map(|card| card_value(card)).sum();
     println!("Day 1: {}", sum);
 
     // Day 2
@@ -123,6 +151,8 @@ fn main() {
     pile.generate_cards();
     let sum = pile.count_card_copies();
     println!("Day 2: {}", sum);
+
+    Ok(())
 }
 
 const _CARDS: &str = indoc! {"
@@ -136,7 +166,7 @@ const _CARDS: &str = indoc! {"
 
 #[test]
 fn parses_winning_numbers() {
-    let winning_numbers: Vec<_> = _CARDS.lines().map(parse_winning_numbers).collect();
+    let winning_numbers = parse_cards(_CARDS).unwrap();
     assert_eq!(
         winning_numbers,
         vec![
@@ -152,7 +182,7 @@ fn parses_winning_numbers() {
 
 #[test]
 fn instantiates_card_pile() {
-    let cards: Vec<_> = _CARDS.lines().map(parse_winning_numbers).collect();
+    let cards = parse_cards(_CARDS).unwrap();
     let pile: Pile = cards.into();
 
     let has_all_card_ids =
@@ -162,7 +192,7 @@ fn instantiates_card_pile() {
 
 #[test]
 fn generates_card_pile() {
-    let cards: Vec<_> = _CARDS.lines().map(parse_winning_numbers).collect();
+    let cards = parse_cards(_CARDS).unwrap();
     let pile: Pile = cards.into();
     pile.generate_cards();
 
@@ -1,12 +1,14 @@
+use aoc_common::{
+    combinators::{finish, lines, sections, space_list, unsigned},
+    read_input, Answer, ParseError, Solution,
+};
 use itertools::Itertools;
 use nom::{
     bytes::complete::{is_not, tag},
-    character::complete::{digit1, newline, space1},
-    combinator::map_res,
+    combinator::verify,
     error::{make_error, ErrorKind},
-    multi::separated_list1,
     sequence::{preceded, separated_pair},
-    Finish, IResult,
+    IResult,
 };
 use std::collections::HashMap;
 
@@ -30,13 +32,13 @@ impl From<Triple> for Range {
 }
 
 #[derive(Debug)]
-struct Map<'a> {
-    _from: &'a str,
-    to: &'a str,
+pub struct Map {
+    _from: String,
+    to: String,
     mappings: Vec<Range>,
 }
 
-impl Map<'_> {
+impl Map {
     fn map_number(&self, value: Number) -> Number {
         let range = self
             .mappings
@@ -53,29 +55,29 @@ impl Map<'_> {
     }
 }
 
-impl<'a> From<(&'a str, &'a str, Vec<Triple>)> for Map<'a> {
-    fn from((from, to, triples): (&'a str, &'a str, Vec<Triple>)) -> Self {
+impl From<(&str, &str, Vec<Triple>)> for Map {
+    fn from((from, to, triples): (&str, &str, Vec<Triple>)) -> Self {
         let mappings: Vec<Range> = triples.into_iter().map(|triple| triple.into()).collect();
 
         Map {
-            _from: from,
-            to,
+            _from: from.to_string(),
+            to: to.to_string(),
             mappings,
         }
     }
 }
 
 #[derive(Debug)]
-struct Almanac<'a>(HashMap<&'a str, Map<'a>>);
+pub struct Almanac(HashMap<String, Map>);
 
-impl<'a> Almanac<'a> {
+impl Almanac {
     fn seed_to_location(&self, seed: Number) -> Number {
         let mut current_map = self.get("seed");
         let mut current_number = seed;
 
         while let Some(map) = current_map {
             current_number = map.map_number(current_number);
-            current_map = self.get(map.to);
+            current_map = self.get(&map.to);
         }
 
         current_number
@@ -88,13 +90,13 @@ impl<'a> Almanac<'a> {
     }
 }
 
-impl<'a> From<Vec<(&'a str, Vec<Triple>)>> for Almanac<'a> {
-    fn from(mappings: Vec<(&'a str, Vec<Triple>)>) -> Self {
+impl From<Vec<(&str, Vec<Triple>)>> for Almanac {
+    fn from(mappings: Vec<(&str, Vec<Triple>)>) -> Self {
         let almanac = mappings
             .into_iter()
             .map(|(name, triples)| {
                 let (from, to) = name.split_once("-to-").expect("Should exist in name");
-                (from, (from, to, triples).into())
+                (from.to_string(), (from, to, triples).into())
             })
             .collect();
 
@@ -102,24 +104,20 @@ impl<'a> From<Vec<(&'a str, Vec<Triple>)>> for Almanac<'a> {
     }
 }
 
-impl<'a> std::ops::Deref for Almanac<'a> {
-    type Target = HashMap<&'a str, Map<'a>>;
+impl std::ops::Deref for Almanac {
+    type Target = HashMap<String, Map>;
 
     fn deref(&self) -> &Self::Target {
         &self.0
     }
 }
 
-fn parse_to_number(i: &str) -> IResult<&str, Number> {
-    map_res(digit1, str::parse)(i)
-}
-
 fn parse_seeds(i: &str) -> IResult<&str, Vec<Number>> {
-    preceded(tag("seeds: "), separated_list1(tag(" "), parse_to_number))(i)
+    preceded(tag("seeds: "), space_list(unsigned))(i)
 }
 
 fn parse_map_line(i: &str) -> IResult<&str, (Number, Number, Number)> {
-    let (remaining, numbers) = separated_list1(space1, parse_to_number)(i)?;
+    let (remaining, numbers) = space_list(unsigned)(i)?;
 
     match numbers.as_slice() {
         [first, second, third] => Ok((remaining, (*first, *second, *third))),
@@ -131,26 +129,60 @@ type Triple = (Number, Number, Number);
 
 fn parse_map(i: &str) -> IResult<&str, (&str, Vec<Triple>)> {
     separated_pair(
-        is_not(" "),
+        verify(is_not(" "), |name: &str| name.contains("-to-")),
         tag(" map:\n"),
-        separated_list1(newline, parse_map_line),
+        lines(parse_map_line),
     )(i)
 }
 
 type RawAlmanac<'a> = (Vec<Number>, Vec<(&'a str, Vec<Triple>)>);
 
-fn parse_almanac(i: &str) -> IResult<&str, RawAlmanac> {
-    separated_pair(
-        parse_seeds,
-        tag("\n\n"),
-        separated_list1(tag("\n\n"), parse_map),
-    )(i)
+fn parse_almanac(i: &str) -> IResult<&str, RawAlmanac<'_>> {
+    separated_pair(parse_seeds, tag("\n\n"), sections(parse_map))(i)
+}
+
+pub struct Day5;
+
+/// The seeds and the almanac mapping them to locations
+fn parse_input(input: &str) -> Result<(Vec<Number>, Almanac), ParseError> {
+    let (seeds, almanac) = finish(
+        parse_almanac,
+        input,
+        "seeds followed by maps of three numbers per line",
+    )?;
+
+    Ok((seeds, almanac.into()))
+}
+
+impl Solution for Day5 {
+    type Input = (Vec<Number>, Almanac);
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input).map_err(|error| error.locate(input, 2023, 5).into())
+    }
+
+    fn part1(&self, (seeds, almanac): &Self::Input) -> Option<Answer> {
+        let lowest_location = seeds
+            .iter()
+            .map(|&seed| almanac.seed_to_location(seed))
+            .min()?;
+
+        Some(lowest_location.into())
+    }
+
+    fn part2(&self, (seeds, almanac): &Self::Input) -> Option<Answer> {
+        let lowest_location = seeds
+            .iter()
+            .tuples()
+            .flat_map(|(&seeds, &count)| almanac.seeds_to_locations(seeds, count))
+            .min()?;
+
+        Some(lowest_location.into())
+    }
 }
 
-fn main() {
-    let input = include_str!("../../data/day5");
-    let (seeds, almanac) = parse_almanac(input).finish().unwrap().1;
-    let almanac: Almanac = almanac.into();
+fn main() -> anyhow::Result<()> {
+    let (seeds, almanac) = Day5.parse(&read_input(2023, 5)?)?;
 
     let result = seeds
         .iter()
@@ -168,6 +200,8 @@ fn main() {
         .expect("Should exist");
 
     println!("Part 2: {}", lowest_location);
+
+    Ok(())
 }
 
 #[cfg(test)]
@@ -212,7 +246,7 @@ const DATA: &str = indoc! {"
 
 #[test]
 fn parses_almanac() {
-    let (_, almanac) = parse_almanac(DATA).finish().unwrap();
+    let (_, almanac) = parse_almanac(DATA).unwrap();
     let expected = (
         vec![79, 14, 55, 13],
         vec![
@@ -240,7 +274,7 @@ fn parses_almanac() {
 
 #[test]
 fn maps_range() {
-    let almanac: Almanac = parse_almanac(DATA).finish().unwrap().1 .1.into();
+    let almanac: Almanac = parse_almanac(DATA).unwrap().1 .1.into();
     let map = almanac.get("seed").unwrap();
 
     assert_eq!(map.map_number(0), 0);
@@ -254,7 +288,7 @@ fn maps_range() {
 
 #[test]
 fn maps_seeds() {
-    let almanac: Almanac = parse_almanac(DATA).finish().unwrap().1 .1.into();
+    let almanac: Almanac = parse_almanac(DATA).unwrap().1 .1.into();
     assert_eq!(almanac.seed_to_location(79), 82);
     assert_eq!(almanac.seed_to_location(14), 43);
     assert_eq!(almanac.seed_to_location(55), 86);
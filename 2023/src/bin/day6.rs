@@ -1,10 +1,11 @@
+use aoc_common::{parse, read_input, Answer, ParseError, Solution};
 use itertools::Itertools;
 
 type Time = u128;
 type Distance = u64;
 
 #[derive(Debug, PartialEq)]
-struct Race {
+pub struct Race {
     time: Time,
     record_distance: Distance,
 }
@@ -28,23 +29,31 @@ impl From<(u64, u64)> for Race {
     }
 }
 
-fn parse_line(line: &str) -> impl Iterator<Item = u64> + '_ {
-    let (_, numbers) = line.split_once(':').expect("Must exist");
+fn parse_line(line: &str) -> Result<Vec<u64>, ParseError> {
+    let (_, numbers) = parse::split_once(line, ":")?;
     numbers
         .split_ascii_whitespace()
-        .map(str::parse)
-        .map(Result::unwrap)
+        .map(|number| parse::token(number, "a number"))
+        .collect()
 }
 
-fn parse_races(string: &str) -> impl Iterator<Item = Race> + '_ {
+/// The numbers on the line of times and on the line of distances
+fn parse_lines(string: &str) -> Result<(Vec<u64>, Vec<u64>), ParseError> {
     let mut lines = string.lines();
-    let times = lines.next().expect("Must exist");
-    let distances = lines.next().expect("Must exist");
+    let times = lines
+        .next()
+        .ok_or_else(|| ParseError::end_of(string, "a line of times"))?;
+    let distances = lines
+        .next()
+        .ok_or_else(|| ParseError::end_of(string, "a line of distances"))?;
+
+    Ok((parse_line(times)?, parse_line(distances)?))
+}
 
-    let times = parse_line(times);
-    let distances = parse_line(distances);
+fn parse_races(string: &str) -> Result<Vec<Race>, ParseError> {
+    let (times, distances) = parse_lines(string)?;
 
-    times.into_iter().zip(distances).map(Race::from)
+    Ok(times.into_iter().zip(distances).map(Race::from).collect())
 }
 
 trait JoinNumbers {
@@ -60,33 +69,60 @@ impl<T: Iterator<Item = u64>> JoinNumbers for T {
     }
 }
 
-fn parse_to_single_race(string: &str) -> Race {
-    let mut lines = string.lines();
-    let times = lines.next().expect("Must exist");
-    let distances = lines.next().expect("Must exist");
+fn parse_to_single_race(string: &str) -> Result<Race, ParseError> {
+    let (times, distances) = parse_lines(string)?;
 
-    let time = parse_line(times).join_numbers();
-    let distance = parse_line(distances).join_numbers();
+    let time = times.into_iter().join_numbers();
+    let distance = distances.into_iter().join_numbers();
+
+    Ok((time, distance).into())
+}
 
-    (time, distance).into()
+fn parse_input(input: &str) -> Result<(Vec<Race>, Race), ParseError> {
+    Ok((parse_races(input)?, parse_to_single_race(input)?))
 }
 
-fn main() {
-    let data = include_str!("../../data/day6");
-    let product: usize = parse_races(data)
-        .map(Race::from)
+pub struct Day6;
+
+impl Solution for Day6 {
+    /// The races, and the single race that part 2 reads when ignoring spaces
+    type Input = (Vec<Race>, Race);
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input).map_err(|error| error.locate(input, 2023, 6).into())
+    }
+
+    fn part1(&self, (races, _): &Self::Input) -> Option<Answer> {
+        let product: usize = races
+            .iter()
+            .map(|race| race.get_best_charge_up_times().count())
+            .product();
+
+        Some(product.into())
+    }
+
+    fn part2(&self, (_, race): &Self::Input) -> Option<Answer> {
+        Some(race.get_best_charge_up_times().count().into())
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let (races, race2) = Day6.parse(&read_input(2023, 6)?)?;
+    let product: usize = races
+        .iter()
         .map(|race| race.get_best_charge_up_times().count())
         .product();
     println!("Part 1: {}", product);
 
-    let race2 = parse_to_single_race(data);
     let best_times_count = race2.get_best_charge_up_times().count();
     println!("Part 2: {}", best_times_count);
+
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{parse_races, Race};
+    use super::{parse_races, Race};
     use indoc::indoc;
 
     #[test]
@@ -95,7 +131,7 @@ mod tests {
             Time:      7  15   30
             Distance:  9  40  200
         "};
-        let result: Vec<_> = parse_races(data).collect();
+        let result = parse_races(data).unwrap();
 
         assert_eq!(
             result,
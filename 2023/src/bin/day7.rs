@@ -1,4 +1,5 @@
-use crate::Card::{Eight, Five, Four, Nine, Seven, Six, Three, Two, A, J, K, Q, T};
+use self::Card::{Eight, Five, Four, Nine, Seven, Six, Three, Two, A, J, K, Q, T};
+use aoc_common::{parse, read_input, Answer, ParseError, Solution};
 use itertools::Itertools;
 use std::{cmp::Ordering, collections::HashSet};
 
@@ -19,29 +20,29 @@ enum Card {
     A,
 }
 
-impl From<char> for Card {
-    fn from(c: char) -> Self {
+impl Card {
+    fn from_char(c: char) -> Option<Self> {
         match c {
-            '2' => Two,
-            '3' => Three,
-            '4' => Four,
-            '5' => Five,
-            '6' => Six,
-            '7' => Seven,
-            '8' => Eight,
-            '9' => Nine,
-            'T' => T,
-            'J' => J,
-            'Q' => Q,
-            'K' => K,
-            'A' => A,
-            other => panic!("Unrecognized card {} found", other),
+            '2' => Some(Two),
+            '3' => Some(Three),
+            '4' => Some(Four),
+            '5' => Some(Five),
+            '6' => Some(Six),
+            '7' => Some(Seven),
+            '8' => Some(Eight),
+            '9' => Some(Nine),
+            'T' => Some(T),
+            'J' => Some(J),
+            'Q' => Some(Q),
+            'K' => Some(K),
+            'A' => Some(A),
+            _ => None,
         }
     }
 }
 
 #[derive(Debug, Eq, PartialEq, Clone)]
-struct Hand([Card; 5]);
+pub struct Hand([Card; 5]);
 
 impl Hand {
     /// Finds all card types that there are exactly n of
@@ -104,11 +105,29 @@ impl Hand {
     }
 }
 
+impl Hand {
+    fn parse(string: &str) -> Result<Self, ParseError> {
+        let cards = string
+            .char_indices()
+            .map(|(index, c)| {
+                Card::from_char(c)
+                    .ok_or_else(|| ParseError::new(&string[index..index + c.len_utf8()], "a card"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let cards = cards
+            .try_into()
+            .map_err(|_| ParseError::new(string, "a hand of five cards"))?;
+
+        Ok(Hand(cards))
+    }
+}
+
+// Hands in the tests are written as literals
+#[cfg(test)]
 impl From<&str> for Hand {
     fn from(string: &str) -> Self {
-        let char_array: [char; 5] = string.chars().collect::<Vec<_>>().try_into().unwrap();
-
-        Hand(char_array.map(Card::from))
+        Hand::parse(string).unwrap()
     }
 }
 
@@ -167,29 +186,52 @@ fn get_rankings(
         .map(|(rank, pair)| (rank + 1, pair))
 }
 
-fn parse_line(line: &str) -> (Hand, u32) {
-    let (hand, bid) = line.split_once(' ').unwrap();
-    let hand: Hand = hand.into();
-    let bid: u32 = bid.parse().unwrap();
+fn parse_line(line: &str) -> Result<(Hand, u32), ParseError> {
+    let (hand, bid) = parse::split_once(line, " ")?;
+    let hand = Hand::parse(hand)?;
+    let bid: u32 = parse::token(bid, "a bid")?;
+
+    Ok((hand, bid))
+}
 
-    (hand, bid)
+fn parse_hands(hands: &str) -> Result<Vec<(Hand, u32)>, ParseError> {
+    hands.lines().map(parse_line).collect()
 }
 
-fn main() {
-    let data = include_str!("../../data/day7");
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<(Hand, u32)>;
 
-    let hands = data.lines().map(parse_line);
-    let total_winnings: u32 = get_rankings(hands)
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse_hands(input).map_err(|error| error.locate(input, 2023, 7).into())
+    }
+
+    fn part2(&self, hands: &Self::Input) -> Option<Answer> {
+        let total_winnings: u32 = get_rankings(hands.iter().cloned())
+            .map(|(ranking, (_, bid))| ranking as u32 * bid)
+            .sum();
+
+        Some(total_winnings.into())
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let hands = Day7.parse(&read_input(2023, 7)?)?;
+
+    let total_winnings: u32 = get_rankings(hands.into_iter())
         .map(|(ranking, (_, bid))| ranking as u32 * bid)
         .sum();
 
     println!("Part 2 = {}", total_winnings);
+
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{
-        parse_line,
+    use super::{
+        parse_hands,
         Card::{Eight, Five, Seven, Six, Three, Two, A, J, K, Q, T},
         Hand,
     };
@@ -242,7 +284,7 @@ mod tests {
 
     #[test]
     fn parses_lines() {
-        let result: Vec<_> = DATA.lines().map(parse_line).collect();
+        let result = parse_hands(DATA).unwrap();
         let expected = vec![
             (Hand([Three, Two, T, Three, K]), 765),
             (Hand([T, Five, Five, J, Five]), 684),
@@ -263,9 +305,9 @@ mod tests {
 
     #[test]
     fn sorts_lines() {
-        let result = DATA
-            .lines()
-            .map(parse_line)
+        let result = parse_hands(DATA)
+            .unwrap()
+            .into_iter()
             .sorted_by_key(|pair| pair.0.clone())
             .collect_vec();
 
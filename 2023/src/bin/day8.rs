@@ -1,20 +1,24 @@
+use aoc_common::{
+    combinators::{finish, key_value, lines},
+    math::lcm,
+    read_input, Answer, ParseError, Solution,
+};
 use itertools::FoldWhile::{Continue, Done};
 use itertools::Itertools;
 use nom::character::complete::alphanumeric1;
 use nom::{
-    bytes::complete::tag,
+    bytes::complete::{is_a, tag},
     character::complete::{char, newline},
-    multi::{count, separated_list1},
+    multi::count,
     sequence::{delimited, separated_pair},
-    Finish, IResult,
+    IResult,
 };
-use num::integer::lcm;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 #[derive(Debug)]
-struct Network<'a>(HashMap<&'a str, (&'a str, &'a str)>);
+pub struct Network(HashMap<String, (String, String)>);
 
-impl Network<'_> {
+impl Network {
     fn get_end_frequency(&self, instructions: &str, start_node_name: &str) -> usize {
         let init = (vec![start_node_name], None);
         let (visited_nodes, _) = instructions
@@ -24,8 +28,8 @@ impl Network<'_> {
                 let node_name = *visited_nodes.last().unwrap();
 
                 let next_node = match instruction {
-                    'L' => self.0.get(node_name).unwrap().0,
-                    'R' => self.0.get(node_name).unwrap().1,
+                    'L' => self.0.get(node_name).unwrap().0.as_str(),
+                    'R' => self.0.get(node_name).unwrap().1.as_str(),
                     other => panic!("Unexpected direction '{}' found", other),
                 };
 
@@ -49,7 +53,7 @@ impl Network<'_> {
         self.0
             .iter()
             .filter(|&(node_name, _)| node_name.ends_with('A'))
-            .map(|(&node_name, _)| node_name)
+            .map(|(node_name, _)| node_name.as_str())
             .collect()
     }
 
@@ -66,9 +70,13 @@ impl Network<'_> {
 
 type Line<'a> = (&'a str, (&'a str, &'a str));
 
-impl<'a> From<Vec<Line<'a>>> for Network<'a> {
-    fn from(lines: Vec<Line<'a>>) -> Self {
-        Network(lines.into_iter().collect())
+impl From<Vec<Line<'_>>> for Network {
+    fn from(lines: Vec<Line<'_>>) -> Self {
+        let nodes = lines
+            .into_iter()
+            .map(|(node, (left, right))| (node.to_string(), (left.to_string(), right.to_string())));
+
+        Network(nodes.collect())
     }
 }
 
@@ -80,38 +88,69 @@ fn parse_pair(i: &str) -> IResult<&str, (&str, &str)> {
     )(i)
 }
 
-fn parse_line(i: &str) -> IResult<&str, Line> {
-    separated_pair(alphanumeric1, tag(" = "), parse_pair)(i)
+fn parse_line(i: &str) -> IResult<&str, Line<'_>> {
+    key_value(alphanumeric1, "=", parse_pair)(i)
 }
 
-fn parse_network(i: &str) -> IResult<&str, Vec<Line>> {
-    separated_list1(newline, parse_line)(i)
+fn parse_network(i: &str) -> IResult<&str, Vec<Line<'_>>> {
+    lines(parse_line)(i)
 }
 
-fn parse(i: &str) -> (&str, Network) {
-    let (instructions, network) =
-        separated_pair(alphanumeric1, count(newline, 2), parse_network)(i)
-            .finish()
-            .unwrap()
-            .1;
-    (instructions, network.into())
+fn parse(i: &str) -> Result<(String, Network), ParseError> {
+    let (instructions, lines) = finish(
+        separated_pair(is_a("LR"), count(newline, 2), parse_network),
+        i,
+        "instructions followed by nodes like 'AAA = (BBB, CCC)'",
+    )?;
+
+    let names: HashSet<_> = lines.iter().map(|&(name, _)| name).collect();
+    let unknown = lines
+        .iter()
+        .flat_map(|&(_, (left, right))| [left, right])
+        .find(|name| !names.contains(name));
+    if let Some(name) = unknown {
+        return Err(ParseError::new(name, "a node in the network"));
+    }
+
+    Ok((instructions.to_string(), lines.into()))
 }
 
-fn main() {
-    let data = include_str!("../../data/day8");
+pub struct Day8;
+
+impl Solution for Day8 {
+    /// The instructions and the network they lead through
+    type Input = (String, Network);
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse(input).map_err(|error| error.locate(input, 2023, 8).into())
+    }
+
+    fn part2(&self, (instructions, network): &Self::Input) -> Option<Answer> {
+        let start_nodes = network.get_starting_nodes();
+
+        Some(
+            network
+                .get_steps_to_end_nodes(start_nodes, instructions)
+                .into(),
+        )
+    }
+}
 
-    let (instructions, network) = parse(data);
+fn main() -> anyhow::Result<()> {
+    let (instructions, network) = Day8.parse(&read_input(2023, 8)?)?;
     let start_nodes = network.get_starting_nodes();
-    let steps = network.get_steps_to_end_nodes(start_nodes, instructions);
+    let steps = network.get_steps_to_end_nodes(start_nodes, &instructions);
 
     println!("Part 2: {}", steps);
+
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use indoc::indoc;
 
-    use crate::parse;
+    use super::parse;
 
     #[test]
     fn gets_starting_nodes() {
@@ -127,7 +166,7 @@ mod tests {
             22Z = (22B, 22B)
             XXX = (XXX, XXX)
         "};
-        let (_, network) = parse(data);
+        let (_, network) = parse(data).unwrap();
         let result = network.get_starting_nodes();
 
         assert_eq!(result, vec!["22A", "11A",])
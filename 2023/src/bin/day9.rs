@@ -1,3 +1,4 @@
+use aoc_common::{parse, read_input, Answer, ParseError, Solution};
 use itertools::Itertools;
 use std::iter;
 
@@ -17,7 +18,8 @@ fn predict_previous_value(triangle: Vec<Vec<i32>>) -> i32 {
 
 fn build_triangle(numbers: Vec<i32>) -> Vec<Vec<i32>> {
     iter::successors(Some(numbers), |num| {
-        if num.iter().all(|&num| num == 0) {
+        // A single number is as far as differences can go
+        if num.len() <= 1 || num.iter().all(|&num| num == 0) {
             None
         } else {
             Some(get_differences(num))
@@ -34,16 +36,54 @@ fn get_differences(numbers: &[i32]) -> Vec<i32> {
         .collect()
 }
 
-fn parse_lines(lines: &str) -> Vec<Vec<i32>> {
-    lines
-        .lines()
-        .map(|line| line.split(' ').map(|num| num.parse().unwrap()).collect())
-        .collect()
+fn parse_lines(lines: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    lines.lines().map(parse_line).collect()
+}
+
+/// A history, which needs two values to have a difference between them
+fn parse_line(line: &str) -> Result<Vec<i32>, ParseError> {
+    let numbers = parse::numbers(line)?;
+    if numbers.len() < 2 {
+        return Err(ParseError::new(line, "at least two numbers"));
+    }
+
+    Ok(numbers)
 }
 
-fn main() {
-    let data = include_str!("../../data/day9");
-    let lines = parse_lines(data);
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse_lines(input).map_err(|error| error.locate(input, 2023, 9).into())
+    }
+
+    fn part1(&self, lines: &Self::Input) -> Option<Answer> {
+        let sum: i32 = lines
+            .iter()
+            .cloned()
+            .map(build_triangle)
+            .map(predict_next_value)
+            .sum();
+
+        Some(sum.into())
+    }
+
+    fn part2(&self, lines: &Self::Input) -> Option<Answer> {
+        let sum: i32 = lines
+            .iter()
+            .cloned()
+            .map(build_triangle)
+            .map(predict_previous_value)
+            .sum();
+
+        Some(sum.into())
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let lines = Day9.parse(&read_input(2023, 9)?)?;
 
     let sum: i32 = lines
         .clone()
@@ -61,4 +101,6 @@ fn main() {
         .sum();
 
     println!("Part 2: {}", sum);
+
+    Ok(())
 }
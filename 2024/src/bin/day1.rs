@@ -1,5 +1,9 @@
-pub fn sum_distances(input: &str) -> i32 {
-    let (mut lefts, mut rights) = parse_columns(input);
+use aoc_common::{parse, read_input, Answer, ParseError, Solution};
+
+type Columns = (Vec<i32>, Vec<i32>);
+
+pub fn sum_distances((lefts, rights): &Columns) -> i32 {
+    let (mut lefts, mut rights) = (lefts.clone(), rights.clone());
 
     lefts.sort();
     rights.sort();
@@ -11,37 +15,61 @@ pub fn sum_distances(input: &str) -> i32 {
         .sum()
 }
 
-pub fn sum_similarities(input: &str) -> i32 {
-    let (lefts, rights) = parse_columns(input);
-
+pub fn sum_similarities((lefts, rights): &Columns) -> i32 {
     lefts
-        .into_iter()
-        .map(|left| {
+        .iter()
+        .map(|&left| {
             let count = rights.iter().filter(|&right| left == *right).count();
             left * count as i32
         })
         .sum()
 }
 
-fn parse_columns(input: &str) -> (Vec<i32>, Vec<i32>) {
+fn parse_columns(input: &str) -> Result<Columns, ParseError> {
     let lines: Vec<_> = input.lines().collect();
     let pairs: Vec<(i32, i32)> = lines
         .iter()
         .map(|line| {
-            let pairs: Vec<_> = line.split_whitespace().take(2).collect();
+            let mut pairs = line.split_whitespace();
+            let mut next = || {
+                let location = pairs
+                    .next()
+                    .ok_or_else(|| ParseError::end_of(line, "two columns of location IDs"))?;
+                parse::token(location, "a location ID")
+            };
 
-            (pairs[0].parse().unwrap(), pairs[1].parse().unwrap())
+            Ok((next()?, next()?))
         })
-        .collect();
+        .collect::<Result<_, ParseError>>()?;
 
-    pairs.into_iter().unzip()
+    Ok(pairs.into_iter().unzip())
 }
 
-fn main() {
-    let data = include_str!("../../data/day1");
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Columns;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse_columns(input).map_err(|error| error.locate(input, 2024, 1).into())
+    }
+
+    fn part1(&self, columns: &Self::Input) -> Option<Answer> {
+        Some(sum_distances(columns).into())
+    }
+
+    fn part2(&self, columns: &Self::Input) -> Option<Answer> {
+        Some(sum_similarities(columns).into())
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let columns = Day1.parse(&read_input(2024, 1)?)?;
+
+    println!("Part 1: {}", sum_distances(&columns));
+    println!("Part 2: {}", sum_similarities(&columns));
 
-    println!("Part 1: {}", sum_distances(data));
-    println!("Part 2: {}", sum_similarities(data));
+    Ok(())
 }
 
 #[cfg(test)]
@@ -60,11 +88,11 @@ mod tests {
 
     #[test]
     fn sums_distances() {
-        assert_eq!(sum_distances(INPUT), 11);
+        assert_eq!(sum_distances(&parse_columns(INPUT).unwrap()), 11);
     }
 
     #[test]
     fn sums_similarities() {
-        assert_eq!(sum_similarities(INPUT), 31);
+        assert_eq!(sum_similarities(&parse_columns(INPUT).unwrap()), 31);
     }
 }
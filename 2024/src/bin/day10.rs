@@ -1,67 +1,57 @@
+use aoc_common::{read_input, Answer, Grid, ParseError, Point2, Solution};
 use itertools::Itertools;
 
-struct Map(String);
+pub struct Map(Grid<Option<usize>>);
 
 type Coordinate = (i32, i32);
 type Trailhead = Vec<Coordinate>;
 
 // Part 1
-pub fn sum_trailhead_scores(map: &str) -> usize {
-    let map = Map(map.to_string());
-    let height = map.0.len();
-    let width = map.0.lines().next().expect("Has lines").len();
-
-    (0..height)
-        .map(|y| {
-            (0..width)
-                .map(|x| {
-                    let (x, y) = (x.try_into().unwrap(), y.try_into().unwrap());
-                    let Some(current_tile) = map.get_tile((x, y)) else {
-                        return 0;
-                    };
-
-                    if current_tile != 0 {
-                        return 0;
-                    }
-
-                    map.get_unique_trailheads((x, y)).count()
-                })
-                .sum::<usize>()
+pub fn sum_trailhead_scores(map: &Map) -> usize {
+    map.0
+        .positions()
+        .map(|Point2 { x, y }| {
+            if map.get_tile((x, y)) != Some(0) {
+                return 0;
+            }
+
+            map.get_unique_trailheads((x, y)).count()
         })
         .sum()
 }
 
 // Part 2
-pub fn sum_trailhead_ratings(map: &str) -> usize {
-    let map = Map(map.to_string());
-    let height = map.0.len();
-    let width = map.0.lines().next().expect("Has lines").len();
-
-    (0..height)
-        .map(|y| {
-            (0..width)
-                .map(|x| {
-                    let (x, y) = (x.try_into().unwrap(), y.try_into().unwrap());
-                    let Some(current_tile) = map.get_tile((x, y)) else {
-                        return 0;
-                    };
-
-                    if current_tile != 0 {
-                        return 0;
-                    }
-
-                    // The only difference to Part 1 is that we don't filter here
-                    map.get_trailheads((x, y)).len()
-                })
-                .sum::<usize>()
+pub fn sum_trailhead_ratings(map: &Map) -> usize {
+    map.0
+        .positions()
+        .map(|Point2 { x, y }| {
+            if map.get_tile((x, y)) != Some(0) {
+                return 0;
+            }
+
+            // The only difference to Part 1 is that we don't filter here
+            map.get_trailheads((x, y)).len()
         })
         .sum()
 }
 
+impl TryFrom<&str> for Map {
+    type Error = ParseError;
+
+    /// Tiles of `.` are impassable
+    fn try_from(map: &str) -> Result<Self, Self::Error> {
+        let map = Grid::try_parse(map, "a height or '.'", |tile| match tile {
+            '.' => Some(None),
+            _ => tile.to_digit(10).map(|height| Some(height as usize)),
+        })?;
+
+        Ok(Map(map))
+    }
+}
+
 impl Map {
-    fn get_tile(&self, (x, y): Coordinate) -> Option<usize> {
-        let tile = self.0.lines().nth(y as usize)?.chars().nth(x as usize);
-        tile.and_then(|t| Some(t.to_digit(10)? as usize))
+    fn get_tile(&self, tile: Coordinate) -> Option<usize> {
+        self.0.get(Point2::from(tile)).copied().flatten()
     }
 
     fn get_neighbours(&self, (x, y): Coordinate) -> impl Iterator<Item = Coordinate> {
@@ -104,11 +94,31 @@ impl Map {
     }
 }
 
-fn main() {
-    let data = include_str!("../../data/day10");
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Map;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        Map::try_from(input).map_err(|error| error.locate(input, 2024, 10).into())
+    }
+
+    fn part1(&self, map: &Self::Input) -> Option<Answer> {
+        Some(sum_trailhead_scores(map).into())
+    }
+
+    fn part2(&self, map: &Self::Input) -> Option<Answer> {
+        Some(sum_trailhead_ratings(map).into())
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let map = Day10.parse(&read_input(2024, 10)?)?;
+
+    println!("Part 1: {}", sum_trailhead_scores(&map));
+    println!("Part 2: {}", sum_trailhead_ratings(&map));
 
-    println!("Part 1: {}", sum_trailhead_scores(data));
-    println!("Part 2: {}", sum_trailhead_ratings(data));
+    Ok(())
 }
 
 #[cfg(test)]
@@ -126,13 +136,13 @@ mod tests {
             9876
         "};
 
-        assert_eq!(Some(9), Map(map.to_string()).get_tile((0, 3)));
-        assert_eq!(Some(2), Map(map.to_string()).get_tile((1, 1)));
-        assert_eq!(Some(6), Map(map.to_string()).get_tile((2, 2)));
+        assert_eq!(Some(9), Map::try_from(map).unwrap().get_tile((0, 3)));
+        assert_eq!(Some(2), Map::try_from(map).unwrap().get_tile((1, 1)));
+        assert_eq!(Some(6), Map::try_from(map).unwrap().get_tile((2, 2)));
 
-        assert_eq!(None, Map(map.to_string()).get_tile((-1, -1)));
-        assert_eq!(None, Map(map.to_string()).get_tile((4, 0)));
-        assert_eq!(None, Map(map.to_string()).get_tile((0, 4)));
+        assert_eq!(None, Map::try_from(map).unwrap().get_tile((-1, -1)));
+        assert_eq!(None, Map::try_from(map).unwrap().get_tile((4, 0)));
+        assert_eq!(None, Map::try_from(map).unwrap().get_tile((0, 4)));
     }
 
     #[test]
@@ -174,7 +184,7 @@ mod tests {
             ],
         ];
 
-        assert_equal(expected, Map(map.to_string()).get_trailheads((3, 0)));
+        assert_equal(expected, Map::try_from(map).unwrap().get_trailheads((3, 0)));
     }
 
     #[test]
@@ -188,7 +198,7 @@ mod tests {
             ...9..2
             .....01
         "};
-        let map = Map(map.to_string());
+        let map = Map::try_from(map).unwrap();
 
         let expected = vec![vec![
             (1, 0),
@@ -245,7 +255,7 @@ mod tests {
             .....01
         "};
 
-        assert_eq!(3, sum_trailhead_scores(map));
+        assert_eq!(3, sum_trailhead_scores(&Map::try_from(map).unwrap()));
 
         let map = indoc! {"
             89010123
@@ -258,7 +268,7 @@ mod tests {
             10456732
         "};
 
-        assert_eq!(36, sum_trailhead_scores(map))
+        assert_eq!(36, sum_trailhead_scores(&Map::try_from(map).unwrap()))
     }
 
     #[test]
@@ -273,7 +283,7 @@ mod tests {
             ..9....
         "};
 
-        assert_eq!(3, sum_trailhead_ratings(map));
+        assert_eq!(3, sum_trailhead_ratings(&Map::try_from(map).unwrap()));
 
         let map = indoc! {"
             ..90..9
@@ -285,6 +295,6 @@ mod tests {
             987....
         "};
 
-        assert_eq!(13, sum_trailhead_ratings(map));
+        assert_eq!(13, sum_trailhead_ratings(&Map::try_from(map).unwrap()));
     }
 }
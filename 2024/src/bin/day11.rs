@@ -1,3 +1,4 @@
+use aoc_common::{parse, read_input, Answer, ParseError, Solution};
 use itertools::Itertools;
 use std::{collections::HashMap, iter};
 
@@ -5,7 +6,7 @@ type Stone = u64;
 type Count = usize;
 
 #[derive(Debug, Clone)]
-struct Stones {
+pub struct Stones {
     stones: HashMap<Stone, Count>,
     cache: HashMap<Stone, Vec<Stone>>,
 }
@@ -75,17 +76,17 @@ impl Stones {
     }
 }
 
-fn parse_stones(stones: &str) -> Stones {
+fn parse_stones(stones: &str) -> Result<Stones, ParseError> {
     let stones = stones
         .split(" ")
-        .map(|stone| stone.parse().unwrap())
-        .collect();
+        .map(|stone| parse::token(stone, "a stone"))
+        .collect::<Result<_, _>>()?;
 
-    Stones::new(stones)
+    Ok(Stones::new(stones))
 }
 
 fn has_even_digits(stone: Stone) -> bool {
-    stone.to_string().len() % 2 == 0
+    stone.to_string().len().is_multiple_of(2)
 }
 
 fn split_stone(stone: Stone) -> [Stone; 2] {
@@ -95,17 +96,31 @@ fn split_stone(stone: Stone) -> [Stone; 2] {
     [left.parse().unwrap(), right.parse().unwrap()]
 }
 
-fn main() {
-    let data = include_str!("../../data/day11");
-
-    println!(
-        "Part 1: {}",
-        parse_stones(data).blink_times(25).count_stones()
-    );
-    println!(
-        "Part 2: {}",
-        parse_stones(data).blink_times(75).count_stones()
-    );
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Stones;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse_stones(input).map_err(|error| error.locate(input, 2024, 11).into())
+    }
+
+    fn part1(&self, stones: &Self::Input) -> Option<Answer> {
+        Some(stones.clone().blink_times(25).count_stones().into())
+    }
+
+    fn part2(&self, stones: &Self::Input) -> Option<Answer> {
+        Some(stones.clone().blink_times(75).count_stones().into())
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let stones = Day11.parse(&read_input(2024, 11)?)?;
+
+    println!("Part 1: {}", stones.clone().blink_times(25).count_stones());
+    println!("Part 2: {}", stones.blink_times(75).count_stones());
+
+    Ok(())
 }
 
 #[cfg(test)]
@@ -130,28 +145,28 @@ mod tests {
 
     #[test]
     fn blinks_stones() {
-        let mut stones = parse_stones("125 17");
+        let mut stones = parse_stones("125 17").unwrap();
         stones.blink();
 
         assert_iterators_eq(&[253000, 1, 7], stones.get_stones());
 
-        let mut stones = parse_stones("253000 1 7");
+        let mut stones = parse_stones("253000 1 7").unwrap();
         stones.blink();
 
         assert_iterators_eq(&[253, 0, 2024, 14168], stones.get_stones());
 
-        let mut stones = parse_stones("253 0 2024 14168");
+        let mut stones = parse_stones("253 0 2024 14168").unwrap();
         stones.blink();
 
         assert_iterators_eq(&[512072, 1, 20, 24, 28676032], stones.get_stones());
 
-        let mut stones = parse_stones("512072 1 20 24 28676032");
+        let mut stones = parse_stones("512072 1 20 24 28676032").unwrap();
         stones.blink();
 
         let expected = &[512, 72, 2024, 2, 0, 2, 4, 2867, 6032];
         assert_iterators_eq(expected, stones.get_stones());
 
-        let mut stones = parse_stones("512 72 2024 2 0 2 4 2867 6032");
+        let mut stones = parse_stones("512 72 2024 2 0 2 4 2867 6032").unwrap();
         stones.blink();
 
         assert_iterators_eq(
@@ -159,7 +174,7 @@ mod tests {
             stones.get_stones(),
         );
 
-        let mut stones = parse_stones("1036288 7 2 20 24 4048 1 4048 8096 28 67 60 32");
+        let mut stones = parse_stones("1036288 7 2 20 24 4048 1 4048 8096 28 67 60 32").unwrap();
         stones.blink();
 
         let expected = &[
@@ -171,6 +186,12 @@ mod tests {
 
     #[test]
     fn blinks_times() {
-        assert_eq!(55312, parse_stones("125 17").blink_times(25).count_stones());
+        assert_eq!(
+            55312,
+            parse_stones("125 17")
+                .unwrap()
+                .blink_times(25)
+                .count_stones()
+        );
     }
 }
@@ -1,14 +1,19 @@
+use aoc_common::{read_input, Answer, Direction, Grid, ParseError, Point2, Solution};
 use itertools::Itertools;
 use std::{
     collections::{HashMap, HashSet},
+    fmt::Write,
     hash::Hash,
 };
 
 type Coordinate = (i32, i32);
 type Plot = char;
 
+/// Width and height of a plot in the SVG
+const SVG_PLOT: f64 = 20.0;
+
 #[derive(Clone)]
-struct Garden(String);
+pub struct Garden(Grid<Plot>);
 
 #[derive(Debug, PartialEq, Clone)]
 struct Region {
@@ -25,14 +30,6 @@ enum Perimeter {
     Left(Coordinate),
 }
 
-#[derive(Clone)]
-enum Direction {
-    Up,
-    Right,
-    Down,
-    Left,
-}
-
 #[derive(Clone, Debug)]
 struct Corner<'a>(&'a Perimeter, &'a Perimeter);
 
@@ -102,38 +99,69 @@ impl Perimeter {
             Perimeter::Left(c) => *c,
         }
     }
+
+    /// Where the fence starts and ends, counted in plots from the top left
+    /// corner of the garden. The perimeter's coordinate is the plot outside
+    /// of the region, so the fence is on its side facing the region.
+    fn fence(&self) -> [Coordinate; 2] {
+        match *self {
+            Perimeter::Top((x, y)) => [(x, y + 1), (x + 1, y + 1)],
+            Perimeter::Right((x, y)) => [(x, y), (x, y + 1)],
+            Perimeter::Bottom((x, y)) => [(x, y), (x + 1, y)],
+            Perimeter::Left((x, y)) => [(x + 1, y), (x + 1, y + 1)],
+        }
+    }
+}
+
+impl Corner<'_> {
+    /// Where the two fences meet, or halfway between them if they don't
+    fn point(&self) -> (f64, f64) {
+        let (first, second) = (self.0.fence(), self.1.fence());
+        if let Some(&(x, y)) = first.iter().find(|end| second.contains(end)) {
+            return (x as f64, y as f64);
+        }
+
+        let [(x1, y1), (x2, y2)] = first;
+        let [(x3, y3), (x4, y4)] = second;
+        (
+            (x1 + x2 + x3 + x4) as f64 / 4.0,
+            (y1 + y2 + y3 + y4) as f64 / 4.0,
+        )
+    }
 }
 
 // Part 1
-fn sum_region_costs(garden: &str) -> usize {
-    let garden = Garden(garden.to_string());
-    garden.get_regions().map(Region::get_cost).sum()
+fn sum_region_costs(garden: &Garden) -> usize {
+    garden.clone().get_regions().map(Region::get_cost).sum()
 }
 
 // Part 2
-fn sum_discounted_region_costs(garden: &str) -> usize {
-    let garden = Garden(garden.to_string());
-
-    let regions = garden.get_regions();
-    // for region in regions {
-    //     println!("{:?}", region);
-    // }
+fn sum_discounted_region_costs(garden: &Garden) -> usize {
+    let regions = garden.clone().get_regions();
 
     regions.map(|region| region.count_sides()).sum()
 }
 
+impl TryFrom<&str> for Garden {
+    type Error = ParseError;
+
+    fn try_from(garden: &str) -> Result<Self, Self::Error> {
+        let garden = Grid::try_parse(garden, "a plant", |plot| {
+            plot.is_ascii_graphic().then_some(plot)
+        })?;
+
+        Ok(Garden(garden))
+    }
+}
+
 impl Garden {
     fn get_regions(self) -> impl Iterator<Item = Region> {
-        let height = self.0.len();
-        let width = self.0.lines().nth(0).unwrap().len();
-
         let mut visited_regions: Vec<Region> = vec![];
 
-        let coordinates = (0..height).flat_map(move |y| (0..width).map(move |x| (x, y)));
+        let coordinates = self.0.positions().collect_vec();
         let regions: HashMap<_, Vec<Region>> = coordinates
-            .flat_map(move |(x, y)| {
-                let (x, y) = (x as i32, y as i32);
-
+            .into_iter()
+            .flat_map(move |Point2 { x, y }| {
                 if visited_regions.iter().any(|r| r.plots.contains(&(x, y))) {
                     return None;
                 }
@@ -151,7 +179,7 @@ impl Garden {
         regions.into_values().flatten()
     }
 
-    pub fn get_region(self, coordinate: Coordinate) -> Region {
+    fn get_region(self, coordinate: Coordinate) -> Region {
         let visited = HashSet::from([coordinate]);
         let perimeters = Vec::new();
         let plot = self.get_plot(coordinate).expect("Plot should exist");
@@ -211,13 +239,81 @@ impl Garden {
         (region, visited)
     }
 
-    fn get_plot(&self, (x, y): Coordinate) -> Option<Plot> {
-        if x.is_negative() || y.is_negative() {
-            return None;
+    /// Draws every region in a colour of its own with its plants' names, its
+    /// fences coloured by the side that they are grouped into and a dot on
+    /// each of its corners. Hovering over a region shows what was counted.
+    fn to_svg(&self) -> String {
+        let regions = self
+            .clone()
+            .get_regions()
+            .sorted_by_key(|region| region.plots.iter().min().copied());
+        let (width, height) = (self.0.width() as f64, self.0.height() as f64);
+        let mut svg = format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="-5 -5 {} {}" font-family="monospace" font-size="10" text-anchor="middle">"#,
+            width * SVG_PLOT + 10.0,
+            height * SVG_PLOT + 10.0
+        );
+        svg.push('\n');
+
+        let point = |(x, y): (f64, f64)| (x * SVG_PLOT, y * SVG_PLOT);
+
+        // Writing to a `String` can't fail
+        for (index, region) in regions.enumerate() {
+            // Turning by the golden angle keeps the hues of neighbours apart
+            let hue = index * 137 % 360;
+            let (horizontals, verticals) = region.get_sides();
+            let corners = region.get_corners().collect_vec();
+
+            writeln!(
+                svg,
+                "<g><title>{}: {} plots, {} fences, {} sides, {} corners</title>",
+                region.name,
+                region.plots.len(),
+                region.perimeters.len(),
+                horizontals.len() + verticals.len(),
+                corners.len()
+            )
+            .unwrap();
+
+            for &(x, y) in region.plots.iter().sorted() {
+                let (left, top) = point((x as f64, y as f64));
+                let (middle, baseline) = point((x as f64 + 0.5, y as f64 + 0.7));
+                writeln!(
+                    svg,
+                    r#"<rect x="{left}" y="{top}" width="{SVG_PLOT}" height="{SVG_PLOT}" fill="hsl({hue} 60% 80%)"/><text x="{middle}" y="{baseline}">{}</text>"#,
+                    region.name
+                )
+                .unwrap();
+            }
+
+            for (side, perimeters) in horizontals.iter().chain(&verticals).enumerate() {
+                let hue = (hue + 180 + side * 53) % 360;
+                for perimeter in perimeters {
+                    let [start, end] = perimeter.fence();
+                    let (x1, y1) = point((start.0 as f64, start.1 as f64));
+                    let (x2, y2) = point((end.0 as f64, end.1 as f64));
+                    writeln!(
+                        svg,
+                        r#"<line x1="{x1}" y1="{y1}" x2="{x2}" y2="{y2}" stroke="hsl({hue} 80% 35%)" stroke-width="3" stroke-linecap="round"/>"#
+                    )
+                    .unwrap();
+                }
+            }
+
+            for corner in &corners {
+                let (x, y) = point(corner.point());
+                writeln!(svg, r#"<circle cx="{x}" cy="{y}" r="3" fill="red"/>"#).unwrap();
+            }
+
+            svg.push_str("</g>\n");
         }
 
-        let (x, y) = (x as usize, y as usize);
-        self.0.lines().nth(y).and_then(|line| line.chars().nth(x))
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    fn get_plot(&self, coordinate: Coordinate) -> Option<Plot> {
+        self.0.get(Point2::from(coordinate)).copied()
     }
 
     fn get_plot_neighbours(&self, (x, y): Coordinate) -> Vec<(Coordinate, Direction)> {
@@ -286,8 +382,6 @@ impl Region {
 
     fn count_sides(&self) -> usize {
         let (horizontals, verticals) = self.get_sides();
-        dbg!(&verticals);
-        dbg!(&horizontals);
         horizontals.len() + verticals.len()
     }
 
@@ -345,10 +439,31 @@ impl Region {
     // }
 }
 
-fn main() {
-    let data = include_str!("../../data/day12");
+pub struct Day12;
 
-    println!("Part 1: {}", sum_region_costs(data));
+impl Solution for Day12 {
+    type Input = Garden;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        Garden::try_from(input).map_err(|error| error.locate(input, 2024, 12).into())
+    }
+
+    fn part1(&self, garden: &Self::Input) -> Option<Answer> {
+        Some(sum_region_costs(garden).into())
+    }
+
+    // Drawing the regions shows where sides and corners are miscounted
+    fn svg(&self, garden: &Self::Input) -> Option<String> {
+        Some(garden.to_svg())
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let garden = Day12.parse(&read_input(2024, 12)?)?;
+
+    println!("Part 1: {}", sum_region_costs(&garden));
+
+    Ok(())
 }
 
 #[cfg(test)]
@@ -374,7 +489,7 @@ mod tests {
             BBCC
             EEEC
         "};
-        let garden = Garden(garden.to_string());
+        let garden = Garden::try_from(garden).unwrap();
 
         // E
         let region = garden.clone().get_region((0, 3));
@@ -424,7 +539,7 @@ mod tests {
             OXOXO
             OOOOO
         "};
-        let garden = Garden(garden.to_string());
+        let garden = Garden::try_from(garden).unwrap();
         let regions = garden.get_regions().collect_vec().into_iter();
 
         let x_perimeters: Vec<_> = regions
@@ -510,7 +625,7 @@ mod tests {
             BBCC
             EEEC
         "};
-        let garden = Garden(garden.to_string());
+        let garden = Garden::try_from(garden).unwrap();
 
         let cost = garden.clone().get_region((0, 0)).get_cost();
         assert_eq!(40, cost);
@@ -537,7 +652,7 @@ mod tests {
             OXOXO
             OOOOO
         "};
-        let garden = Garden(garden.to_string());
+        let garden = Garden::try_from(garden).unwrap();
 
         let cost = garden.clone().get_region((0, 0)).get_cost();
         assert_eq!(756, cost);
@@ -555,7 +670,7 @@ mod tests {
             EEEC
         "};
 
-        let cost = sum_region_costs(garden);
+        let cost = sum_region_costs(&Garden::try_from(garden).unwrap());
         assert_eq!(140, cost);
     }
 
@@ -569,7 +684,7 @@ mod tests {
             OOOOO
         "};
 
-        let cost = sum_region_costs(garden);
+        let cost = sum_region_costs(&Garden::try_from(garden).unwrap());
         assert_eq!(772, cost);
     }
 
@@ -588,7 +703,7 @@ mod tests {
             MMMISSJEEE
         "};
 
-        let cost = sum_region_costs(garden);
+        let cost = sum_region_costs(&Garden::try_from(garden).unwrap());
         assert_eq!(1930, cost);
     }
 
@@ -601,7 +716,7 @@ mod tests {
             XXXX
         "};
 
-        let garden = Garden(garden.to_string());
+        let garden = Garden::try_from(garden).unwrap();
         let regions = garden.get_regions().collect_vec().into_iter();
         let x_region = regions.clone().find(|region| region.name == 'X').unwrap();
 
@@ -663,7 +778,10 @@ mod tests {
             XXXX
         "};
 
-        assert_eq!(12 * 14 + 4 + 4, sum_discounted_region_costs(garden));
+        assert_eq!(
+            12 * 14 + 4 + 4,
+            sum_discounted_region_costs(&Garden::try_from(garden).unwrap())
+        );
     }
 
     #[test]
@@ -677,7 +795,7 @@ mod tests {
             AAAAAA
         "};
 
-        let garden = Garden(garden.to_string());
+        let garden = Garden::try_from(garden).unwrap();
         // let a_region = garden.clone().get_region((0, 0));
         // assert_eq!(12, a_region.count_sides());
 
@@ -697,7 +815,7 @@ mod tests {
             AAAAAA
         "};
 
-        let garden = Garden(garden.to_string());
+        let garden = Garden::try_from(garden).unwrap();
         let a_region = garden.clone().get_region((0, 0));
         println!("perimeters: {:?}", a_region.perimeters);
         let corners = a_region.get_corners().collect_vec();
@@ -719,7 +837,7 @@ mod tests {
             AAAAAA
         "};
 
-        let cost = sum_discounted_region_costs(garden);
+        let cost = sum_discounted_region_costs(&Garden::try_from(garden).unwrap());
         // (4+8) * (6 * 6 - 8) + (4*4*2)
         assert_eq!(368, cost);
     }
@@ -732,7 +850,7 @@ mod tests {
             BBCC
             EEEC
         "};
-        let garden = Garden(garden.to_string());
+        let garden = Garden::try_from(garden).unwrap();
         let a_region = garden.clone().get_region((0, 0));
         let sides = a_region.sort_sides_horizontally();
 
@@ -769,7 +887,7 @@ mod tests {
     //         BBCC
     //         EEEC
     //     "};
-    //     let garden = Garden(garden.to_string());
+    //     let garden = Garden::try_from(garden).unwrap();
     //     let a_region = garden.clone().get_region((0, 0));
     //     let sides = a_region.sort_sides_vertically();
 
@@ -786,7 +904,7 @@ mod tests {
             BBCC
             EEEC
         "};
-        let garden = Garden(garden.to_string());
+        let garden = Garden::try_from(garden).unwrap();
 
         let perimeters = garden.clone().get_region((0, 0)).perimeters;
         assert_equal(
@@ -822,7 +940,7 @@ mod tests {
             BBCC
             EEEC
         "};
-        let garden = Garden(garden.to_string());
+        let garden = Garden::try_from(garden).unwrap();
 
         let a_region = garden.clone().get_region((0, 0));
 
@@ -852,4 +970,30 @@ mod tests {
         assert!(!Perimeter::Top((1, 2)).creates_corner(&Perimeter::Bottom((1, 2))));
         assert!(!Perimeter::Top((1, 2)).creates_corner(&Perimeter::Right((2, 2))));
     }
+
+    #[test]
+    fn finds_where_fences_meet() {
+        let inner = Corner(&Perimeter::Top((1, 2)), &Perimeter::Right((1, 2)));
+        let outer = Corner(&Perimeter::Right((4, 0)), &Perimeter::Top((3, -1)));
+
+        assert_eq!(inner.point(), (1.0, 3.0));
+        assert_eq!(outer.point(), (4.0, 0.0));
+    }
+
+    #[test]
+    fn draws_regions_as_svg() {
+        let garden = indoc! {"
+            AAAA
+            BBCD
+            BBCC
+            EEEC
+        "};
+        let svg = Garden::try_from(garden).unwrap().to_svg();
+
+        assert!(svg.starts_with("<svg "));
+        assert_eq!(svg.matches("<g>").count(), 5);
+        assert_eq!(svg.matches("<rect ").count(), 16);
+        assert!(svg.contains("<title>A: 4 plots, 10 fences, 4 sides"));
+        assert!(svg.contains("<title>D: 1 plots, 4 fences, 4 sides, 4 corners</title>"));
+    }
 }
@@ -1,25 +1,27 @@
+use aoc_common::{parse, read_input, Answer, ParseError, Point2, Solution};
 use itertools::Itertools;
 use std::{
     collections::{HashMap, HashSet},
     hash::Hash,
-    ops::Add,
 };
 
 // Part 1
-fn find_fewest_tokens(machines: &str) -> usize {
-    parse_machines(machines)
-        .flat_map(|machine| machine.fewest_prize_tokens())
+fn find_fewest_tokens(machines: &[Machine]) -> usize {
+    machines
+        .iter()
+        .flat_map(|machine| machine.clone().fewest_prize_tokens())
         .sum()
 }
 
 // Part 2 (linear algebra goes brrrrrrrrrrrrrrr)
-fn find_fewest_tokens_with_conversion_fixed(machines: &str) -> usize {
-    parse_machines(machines)
+fn find_fewest_tokens_with_conversion_fixed(machines: &[Machine]) -> usize {
+    machines
+        .iter()
         .map(|machine| {
-            let Vector(x, y) = machine.prize;
+            let Vector { x, y } = machine.prize;
 
             let conversion = 10000000000000;
-            let prize = Vector(x + conversion, y + conversion);
+            let prize = Vector::new(x + conversion, y + conversion);
             let machine = Machine::new(machine.button_a, machine.button_b, prize);
 
             machine.find_prize_with_conversion()
@@ -27,11 +29,10 @@ fn find_fewest_tokens_with_conversion_fixed(machines: &str) -> usize {
         .sum()
 }
 
-#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash)]
-struct Vector(i64, i64);
+type Vector = Point2<i64>;
 
 #[derive(PartialEq, Eq, Debug, Clone)]
-struct Machine {
+pub struct Machine {
     button_a: Vector,
     button_b: Vector,
     prize: Vector,
@@ -73,14 +74,6 @@ enum Button {
     B = 1,
 }
 
-impl Add for Vector {
-    type Output = Vector;
-
-    fn add(self, rhs: Self) -> Self::Output {
-        Self(self.0 + rhs.0, self.1 + rhs.1)
-    }
-}
-
 impl Machine {
     fn new(button_a: Vector, button_b: Vector, prize: Vector) -> Self {
         Self {
@@ -93,7 +86,7 @@ impl Machine {
 
     fn fewest_prize_tokens(mut self) -> Option<usize> {
         let tokens = self
-            .find_prizes(Vector(0, 0), Buttons(HashMap::new()))
+            .find_prizes(Vector::new(0, 0), Buttons(HashMap::new()))
             .into_iter()
             .unique()
             .map(|buttons| buttons.token_cost())
@@ -112,8 +105,8 @@ impl Machine {
 
         let done = pressed_as > 100
             || pressed_bs > 100
-            || coordinate.0 > self.prize.0
-            || coordinate.1 > self.prize.1;
+            || coordinate.x > self.prize.x
+            || coordinate.y > self.prize.y;
 
         if done {
             return HashSet::new();
@@ -143,7 +136,7 @@ impl Machine {
     }
 
     fn find_prize_with_conversion(&self) -> usize {
-        let Some(Vector(a, b)) = self.solve() else {
+        let Some(Vector { x: a, y: b }) = self.solve() else {
             return 0;
         };
 
@@ -153,12 +146,12 @@ impl Machine {
 
     fn solve(&self) -> Option<Vector> {
         // Cramer's rule
-        let a = self.button_a.0;
-        let b = self.button_b.0;
-        let c = self.button_a.1;
-        let d = self.button_b.1;
-        let e = self.prize.0;
-        let f = self.prize.1;
+        let a = self.button_a.x;
+        let b = self.button_b.x;
+        let c = self.button_a.y;
+        let d = self.button_b.y;
+        let e = self.prize.x;
+        let f = self.prize.y;
 
         let (x, x_remainder) = divide_with_remainder(e * d - b * f, a * d - b * c);
         let (y, y_remainder) = divide_with_remainder(a * f - e * c, a * d - b * c);
@@ -167,7 +160,7 @@ impl Machine {
             return None;
         }
 
-        Some(Vector(x, y))
+        Some(Vector::new(x, y))
     }
 }
 
@@ -175,46 +168,79 @@ fn divide_with_remainder(numerator: i64, denominator: i64) -> (i64, i64) {
     (numerator / denominator, numerator % denominator)
 }
 
-fn parse_machines(machines: &str) -> impl Iterator<Item = Machine> + use<'_> {
-    machines.split("\n\n").map(parse_machine)
+fn parse_machines(machines: &str) -> Result<Vec<Machine>, ParseError> {
+    machines.split("\n\n").map(parse_machine).collect()
 }
 
-fn parse_machine(machine: &str) -> Machine {
+fn parse_machine(machine: &str) -> Result<Machine, ParseError> {
     let mut lines = machine.lines();
-    let button_a = parse_button(lines.next().unwrap());
-    let button_b = parse_button(lines.next().unwrap());
+    let mut next_line = |expected| {
+        lines
+            .next()
+            .ok_or_else(|| ParseError::end_of(machine, expected))
+    };
+    let button_a = parse_button(next_line("button A")?)?;
+    let button_b = parse_button(next_line("button B")?)?;
+
+    let (_, prize_coordinate) = parse::split_once(next_line("a prize")?, "X=")?;
+    let (prize_x, prize_y) = parse::split_once(prize_coordinate, ", Y=")?;
+    let prize = Vector::new(
+        parse::token(prize_x, "a coordinate")?,
+        parse::token(prize_y, "a coordinate")?,
+    );
+
+    Ok(Machine::new(button_a, button_b, prize))
+}
 
-    let (_, prize_coordinate) = lines.next().unwrap().split_once("X=").unwrap();
-    let (prize_x, prize_y) = prize_coordinate.split_once(", Y=").unwrap();
-    let prize = Vector(prize_x.parse().unwrap(), prize_y.parse().unwrap());
+fn parse_button(button: &str) -> Result<Vector, ParseError> {
+    let (_, movement) = parse::split_once(button, "X+")?;
+    let (x, y) = parse::split_once(movement, ", Y+")?;
 
-    Machine::new(button_a, button_b, prize)
+    Ok(Vector::new(
+        parse::token(x, "a distance")?,
+        parse::token(y, "a distance")?,
+    ))
 }
 
-fn parse_button(button: &str) -> Vector {
-    let (_, movement) = button.split_once("X+").unwrap();
-    let (x, y) = movement.split_once(", Y+").unwrap();
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Machine>;
 
-    Vector(x.parse().unwrap(), y.parse().unwrap())
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse_machines(input).map_err(|error| error.locate(input, 2024, 13).into())
+    }
+
+    fn part1(&self, machines: &Self::Input) -> Option<Answer> {
+        Some(find_fewest_tokens(machines).into())
+    }
+
+    fn part2(&self, machines: &Self::Input) -> Option<Answer> {
+        Some(find_fewest_tokens_with_conversion_fixed(machines).into())
+    }
 }
 
-fn main() {
-    let data = include_str!("../../data/day13");
+fn main() -> anyhow::Result<()> {
+    let machines = Day13.parse(&read_input(2024, 13)?)?;
+
+    println!("Part 1: {}", find_fewest_tokens(&machines));
+    println!(
+        "Part 2: {}",
+        find_fewest_tokens_with_conversion_fixed(&machines)
+    );
 
-    println!("Part 1: {}", find_fewest_tokens(data));
-    println!("Part 2: {}", find_fewest_tokens_with_conversion_fixed(data));
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use indoc::indoc;
-    use itertools::Itertools;
 
     #[test]
     fn parses_button() {
         let button = "Button A: X+94, Y+34";
-        assert_eq!(Vector(94, 34), parse_button(button));
+        assert_eq!(Vector::new(94, 34), parse_button(button).unwrap());
     }
 
     #[test]
@@ -230,10 +256,18 @@ mod tests {
         "};
 
         let expected = vec![
-            Machine::new(Vector(94, 34), Vector(22, 67), Vector(8400, 5400)),
-            Machine::new(Vector(26, 66), Vector(67, 21), Vector(12748, 12176)),
+            Machine::new(
+                Vector::new(94, 34),
+                Vector::new(22, 67),
+                Vector::new(8400, 5400),
+            ),
+            Machine::new(
+                Vector::new(26, 66),
+                Vector::new(67, 21),
+                Vector::new(12748, 12176),
+            ),
         ];
-        assert_eq!(expected, parse_machines(machines).collect_vec());
+        assert_eq!(expected, parse_machines(machines).unwrap());
     }
 
     #[test]
@@ -252,7 +286,10 @@ mod tests {
             Prize: X=8400, Y=5400
         "};
 
-        assert_eq!(Some(280), parse_machine(machine).fewest_prize_tokens());
+        assert_eq!(
+            Some(280),
+            parse_machine(machine).unwrap().fewest_prize_tokens()
+        );
     }
 
     #[test]
@@ -262,9 +299,9 @@ mod tests {
             Button B: X+84, Y+37
             Prize: X=7870, Y=6450
         "};
-        let machine = parse_machine(machine);
+        let machine = parse_machine(machine).unwrap();
 
-        assert_eq!(Some(Vector(38, 86,)), machine.solve())
+        assert_eq!(Some(Vector::new(38, 86,)), machine.solve())
     }
 
     #[test]
@@ -275,7 +312,7 @@ mod tests {
             Prize: X=10000000012748, Y=10000000012176
         "};
 
-        let prize = parse_machine(machine).find_prize_with_conversion();
+        let prize = parse_machine(machine).unwrap().find_prize_with_conversion();
         assert_eq!(459236326669, prize);
     }
 }
@@ -1,16 +1,15 @@
-use itertools::Itertools;
-use nom::{
-    self,
-    bytes::complete::tag,
-    character::complete::digit1,
-    combinator::{map_res, opt},
-    sequence::{preceded, separated_pair, tuple},
+use anyhow::Context;
+use aoc_common::{
+    combinators::{finish, key_value, lines, signed},
+    read_input, Answer, ParseError, Simulate, Solution,
 };
+use itertools::Itertools;
+use nom::{bytes::complete::tag, character::complete::char, sequence::separated_pair};
 use std::fmt::Display;
 
 // Part 1
-fn safety_factor_after_100_seconds(map: &str) -> usize {
-    let mut map = Map::new(map, 101, 103);
+fn safety_factor_after_100_seconds(robots: &[Robot]) -> usize {
+    let mut map = Map::with_robots(robots.to_vec(), 101, 103);
 
     map.update(100);
 
@@ -18,19 +17,18 @@ fn safety_factor_after_100_seconds(map: &str) -> usize {
 }
 
 // Part 2
-fn find_christmas_tree(map: &str) -> usize {
-    let mut map = Map::new(map, 101, 103);
-    let mut count = 0;
-
-    loop {
-        map.update(1);
-        count += 1;
-
-        if map.has_christmas_tree() {
-            println!("{map}");
-            return count;
-        }
-    }
+fn find_christmas_tree(robots: &[Robot]) -> anyhow::Result<usize> {
+    let mut map = Map::with_robots(robots.to_vec(), 101, 103);
+    // Every robot is back where it started after `width * height` seconds, so
+    // a tree that hasn't shown up by then never will
+    let seconds = (map.width * map.height) as usize;
+
+    (1..=seconds)
+        .find(|_| {
+            map.update(1);
+            map.has_christmas_tree()
+        })
+        .with_context(|| format!("the robots don't form a christmas tree in {seconds} seconds"))
 }
 
 #[derive(Debug)]
@@ -42,8 +40,8 @@ struct Map {
 
 type Vector = (i32, i32);
 
-#[derive(Debug, PartialEq)]
-struct Robot {
+#[derive(Debug, Clone, PartialEq)]
+pub struct Robot {
     position: Vector,
     velocity: Vector,
 }
@@ -58,9 +56,9 @@ impl Robot {
 }
 
 impl Map {
-    fn new(robots: &str, width: i32, height: i32) -> Self {
+    fn with_robots(robots: Vec<Robot>, width: i32, height: i32) -> Self {
         Map {
-            robots: parse_robots(robots).collect(),
+            robots,
             width,
             height,
         }
@@ -129,6 +127,14 @@ impl Map {
     }
 }
 
+/// The robots moving for as long as it's played
+impl Simulate for Map {
+    fn tick(&mut self) -> bool {
+        self.update(1);
+        true
+    }
+}
+
 fn iter_quadrant(
     x_from: i32,
     x_to: i32,
@@ -156,43 +162,56 @@ fn positive_modulo(n: i32, modulus: i32) -> i32 {
     ((n % modulus) + modulus) % modulus
 }
 
-fn parse_robots(robots: &str) -> impl Iterator<Item = Robot> + use<'_> {
-    robots.lines().map(|robot| {
-        let (_, (position, velocity)) = parse_robot(robot).unwrap();
+fn parse_robots(robots: &str) -> Result<Vec<Robot>, ParseError> {
+    finish(lines(parse_robot), robots, "a robot like 'p=0,4 v=3,-3'")
+}
+
+fn parse_robot(i: &str) -> nom::IResult<&str, Robot> {
+    let vector = || separated_pair(signed, char(','), signed);
+    let (rest, ((_, position), (_, velocity))) = separated_pair(
+        key_value(tag("p"), "=", vector()),
+        char(' '),
+        key_value(tag("v"), "=", vector()),
+    )(i)?;
 
-        Robot { position, velocity }
-    })
+    Ok((rest, Robot { position, velocity }))
 }
 
-fn parse_robot(i: &str) -> nom::IResult<&str, (Vector, Vector)> {
-    fn parse_vector(i: &str) -> nom::IResult<&str, (i32, i32)> {
-        separated_pair(parse_signed_number, tag(","), parse_signed_number)(i)
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Vec<Robot>;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse_robots(input).map_err(|error| error.locate(input, 2024, 14).into())
     }
 
-    fn parse_signed_number(i: &str) -> nom::IResult<&str, i32> {
-        map_res(tuple((opt(tag("-")), digit1)), |(sign, n)| {
-            format!("{}{n}", sign.unwrap_or_default()).parse()
-        })(i)
+    fn part1(&self, robots: &Self::Input) -> Option<Answer> {
+        Some(safety_factor_after_100_seconds(robots).into())
     }
 
-    separated_pair(
-        preceded(tag("p="), parse_vector),
-        tag(" "),
-        preceded(tag("v="), parse_vector),
-    )(i)
+    fn try_part2(&self, robots: &Self::Input) -> anyhow::Result<Option<Answer>> {
+        Ok(Some(find_christmas_tree(robots)?.into()))
+    }
+
+    fn simulation(&self, robots: &Self::Input) -> Option<Box<dyn Simulate>> {
+        Some(Box::new(Map::with_robots(robots.clone(), 101, 103)))
+    }
 }
 
-fn main() {
-    let data = include_str!("../../data/day14");
-    println!("Part 1: {}", safety_factor_after_100_seconds(data));
-    println!("Part 2: {}", find_christmas_tree(data));
+fn main() -> anyhow::Result<()> {
+    let robots = Day14.parse(&read_input(2024, 14)?)?;
+    println!("Part 1: {}", safety_factor_after_100_seconds(&robots));
+    println!("Part 2: {}", find_christmas_tree(&robots)?);
+
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use indoc::indoc;
-    use itertools::{assert_equal, Itertools};
+    use itertools::assert_equal;
 
     #[test]
     fn parses_robots() {
@@ -211,7 +230,7 @@ mod tests {
                 velocity: (-1, -3),
             },
         ];
-        assert_equal(expected, parse_robots(robots).collect_vec());
+        assert_equal(expected, parse_robots(robots).unwrap());
     }
 
     #[test]
@@ -230,7 +249,7 @@ mod tests {
             p=2,4 v=2,-3
             p=9,5 v=-3,-3
         "};
-        let map = Map::new(robots, 11, 7);
+        let map = Map::with_robots(parse_robots(robots).unwrap(), 11, 7);
 
         let expected = indoc! {"
             1.12.......
@@ -246,7 +265,7 @@ mod tests {
 
     #[test]
     fn moves_robot() {
-        let mut map = Map::new("p=2,4 v=2,-3", 11, 7);
+        let mut map = Map::with_robots(parse_robots("p=2,4 v=2,-3").unwrap(), 11, 7);
 
         map.update(1);
 
@@ -261,7 +280,7 @@ mod tests {
 
     #[test]
     fn wraps_robot_around_edge() {
-        let mut map = Map::new("p=4,1 v=2,-3", 11, 7);
+        let mut map = Map::with_robots(parse_robots("p=4,1 v=2,-3").unwrap(), 11, 7);
 
         map.update(1);
 
@@ -295,7 +314,7 @@ mod tests {
             p=2,4 v=2,-3
             p=9,5 v=-3,-3
         "};
-        let mut map = Map::new(robots, 11, 7);
+        let mut map = Map::with_robots(parse_robots(robots).unwrap(), 11, 7);
 
         map.update(100);
 
@@ -327,7 +346,7 @@ mod tests {
             p=2,4 v=2,-3
             p=9,5 v=-3,-3
         "};
-        let mut map = Map::new(robots, 11, 7);
+        let mut map = Map::with_robots(parse_robots(robots).unwrap(), 11, 7);
 
         map.update(100);
 
@@ -1,3 +1,4 @@
+use aoc_common::{read_input, Answer, Direction, Grid, ParseError, Point2, Simulate, Solution};
 use itertools::Itertools;
 use std::{
     collections::HashSet,
@@ -6,10 +7,9 @@ use std::{
 
 type Coordinate = (i32, i32);
 
-struct Warehouse {
-    tiles: Vec<Tile>,
-    width: usize,
-    height: usize,
+#[derive(Clone)]
+pub struct Warehouse {
+    tiles: Grid<Option<TileType>>,
 }
 
 #[derive(/* Debug,  */ PartialEq)]
@@ -18,7 +18,7 @@ struct Tile {
     coordinate: Coordinate,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 enum TileType {
     BoxLeft,  // Left part of box
     BoxRight, // Right part of box
@@ -26,89 +26,38 @@ enum TileType {
     Robot,
 }
 
-#[derive(Debug, PartialEq)]
-enum Direction {
-    Up,
-    Right,
-    Down,
-    Left,
-}
-
 impl Debug for Tile {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{:?}: {:?}", self.tile_type, self.coordinate)
     }
 }
 
-impl Direction {
-    fn get_neighbour_pos(&self, (x, y): Coordinate) -> Coordinate {
-        match self {
-            Direction::Up => (x, y - 1),
-            Direction::Right => (x + 1, y),
-            Direction::Down => (x, y + 1),
-            Direction::Left => (x - 1, y),
-        }
-    }
-}
-
-impl std::ops::Add<&Direction> for Coordinate {
-    type Output = Coordinate;
-
-    fn add(self, direction: &Direction) -> Coordinate {
-        match direction {
-            Direction::Up => (self.0, self.1 - 1),
-            Direction::Down => (self.0, self.1 + 1),
-            Direction::Left => (self.0 - 1, self.1),
-            Direction::Right => (self.0 + 1, self.1),
-        }
-    }
-}
-
-impl From<char> for Direction {
-    fn from(direction: char) -> Self {
-        match direction {
-            '>' => Direction::Right,
-            'v' => Direction::Down,
-            '<' => Direction::Left,
-            '^' => Direction::Up,
-            other => panic!("Unrecognized character {other}"),
-        }
-    }
+fn get_neighbour_pos(coordinate: Coordinate, direction: &Direction) -> Coordinate {
+    (Point2::from(coordinate) + *direction).into()
 }
 
 impl Warehouse {
     fn sum_box_gps_coordinates(&self) -> i32 {
         self.tiles
             .iter()
-            .map(|tile| match tile.tile_type {
-                TileType::BoxLeft => tile.coordinate.1 * 100 + tile.coordinate.0,
+            .map(|(coordinate, tile)| match tile {
+                Some(TileType::BoxLeft) => coordinate.y * 100 + coordinate.x,
                 _ => 0,
             })
             .sum()
     }
 
-    fn get_tile(&self, coordinate: Coordinate) -> Option<&Tile> {
-        self.tiles.iter().find(|t| t.coordinate == coordinate)
-    }
-
-    fn get_tile_mut(&mut self, coordinate: Coordinate) -> Option<&mut Tile> {
-        self.tiles.iter_mut().find(|t| t.coordinate == coordinate)
+    fn get_tile(&self, coordinate: Coordinate) -> Option<Tile> {
+        let tile_type = (*self.tiles.get(Point2::from(coordinate))?)?;
+        Some(Tile::new(tile_type, coordinate))
     }
 
     fn get_robot(&self) -> Coordinate {
-        self.tiles
-            .iter()
-            .find(|t| matches!(t.tile_type, TileType::Robot))
-            .map(|t| t.coordinate)
-            .unwrap()
-    }
-
-    fn get_robot_mut(&mut self) -> &mut Coordinate {
-        self.tiles
-            .iter_mut()
-            .find(|t| matches!(t.tile_type, TileType::Robot))
-            .map(|t| &mut t.coordinate)
-            .unwrap()
+        let robot = self
+            .tiles
+            .find(|&tile| tile == Some(TileType::Robot))
+            .unwrap();
+        (robot.x, robot.y)
     }
 
     fn move_robot(&mut self, direction: &Direction) {
@@ -119,8 +68,7 @@ impl Warehouse {
             return;
         }
 
-        let neighbour = direction.get_neighbour_pos(robot);
-        *self.get_robot_mut() = neighbour;
+        self.move_tiles(&[robot], direction);
     }
 
     /// Returns true if tiles were pushable, otherwise false
@@ -148,7 +96,7 @@ impl Warehouse {
         direction: &Direction,
         mut boxes: HashSet<Coordinate>,
     ) -> Option<HashSet<Coordinate>> {
-        let neighbour_pos = direction.get_neighbour_pos(from);
+        let neighbour_pos = get_neighbour_pos(from, direction);
 
         let Some(neighbour) = self.get_tile(neighbour_pos) else {
             return Some(boxes);
@@ -173,48 +121,48 @@ impl Warehouse {
         }
     }
 
+    /// Moves all tiles at once, so that they can move into each other's places
     fn move_tiles(&mut self, coordinates: &[Coordinate], direction: &Direction) {
-        for coordinate in coordinates {
-            let tile = self.get_tile_mut(*coordinate).unwrap();
-            tile.coordinate = direction.get_neighbour_pos(tile.coordinate)
+        let tiles = coordinates
+            .iter()
+            .map(|&coordinate| (coordinate, self.tiles[Point2::from(coordinate)].take()))
+            .collect_vec();
+
+        for (coordinate, tile) in tiles {
+            self.tiles[Point2::from(get_neighbour_pos(coordinate, direction))] = tile;
         }
     }
 
     fn move_tile(&mut self, tile: Coordinate, direction: Direction) {
-        let new_position = direction.get_neighbour_pos(tile);
-        let tile = self.get_tile_mut(tile).unwrap();
-
-        tile.move_to(new_position);
+        self.move_tiles(&[tile], &direction);
     }
 }
 
-impl From<&str> for Warehouse {
-    fn from(warehouse: &str) -> Self {
-        let mut lines = warehouse.lines();
-        let height = lines.clone().count();
-
-        let tiles = parse_tiles(warehouse).collect();
-
-        Self {
-            tiles,
-            width: lines.nth(0).unwrap().len(),
-            height,
+impl Warehouse {
+    fn parse(warehouse: &str) -> Result<Self, ParseError> {
+        let height = warehouse.lines().count();
+        let width = warehouse.lines().next().map_or(0, str::len) * 2;
+
+        let mut tiles = Grid::new(width, height, None);
+        for tile in parse_tiles(warehouse)? {
+            *tiles
+                .get_mut(Point2::from(tile.coordinate))
+                .ok_or_else(|| ParseError::end_of(warehouse, "a rectangular warehouse"))? =
+                Some(tile.tile_type);
         }
+
+        Ok(Self { tiles })
     }
 }
 
 impl Display for Warehouse {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let warehouse: String = (0..self.height)
-            .map(|y| {
-                (0..self.width)
-                    .map(move |x| {
-                        if let Some(tile) = self.get_tile((x as i32, y as i32)) {
-                            tile.to_string()
-                        } else {
-                            ".".to_string()
-                        }
-                    })
+        let warehouse = self
+            .tiles
+            .rows()
+            .map(|row| {
+                row.iter()
+                    .map(|tile| tile.map_or(".".to_string(), |tile| tile.to_string()))
                     .join("")
             })
             .join("\n");
@@ -223,6 +171,28 @@ impl Display for Warehouse {
     }
 }
 
+/// The robot making one of its moves each tick
+struct Moving {
+    warehouse: Warehouse,
+    moves: std::vec::IntoIter<Direction>,
+}
+
+impl Simulate for Moving {
+    fn tick(&mut self) -> bool {
+        let Some(direction) = self.moves.next() else {
+            return false;
+        };
+        self.warehouse.move_robot(&direction);
+        true
+    }
+}
+
+impl Display for Moving {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.warehouse)
+    }
+}
+
 impl Tile {
     fn new(tile_type: TileType, coordinate: Coordinate) -> Self {
         Self {
@@ -231,10 +201,6 @@ impl Tile {
         }
     }
 
-    fn move_to(&mut self, coordinate: Coordinate) {
-        self.coordinate = coordinate;
-    }
-
     fn get_box_sibling_pos(&self) -> Coordinate {
         match self.tile_type {
             TileType::BoxLeft => (self.coordinate.0 + 1, self.coordinate.1),
@@ -246,7 +212,13 @@ impl Tile {
 
 impl Display for Tile {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let tile = match self.tile_type {
+        write!(f, "{}", self.tile_type)
+    }
+}
+
+impl Display for TileType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let tile = match self {
             TileType::BoxLeft => "[",
             TileType::BoxRight => "]",
             TileType::Wall => "#",
@@ -256,55 +228,108 @@ impl Display for Tile {
     }
 }
 
-fn parse_tile(tile: char, coordinate: Coordinate) -> (Option<Tile>, Option<Tile>) {
+fn parse_tile(tile: char, coordinate: Coordinate) -> Option<(Option<Tile>, Option<Tile>)> {
     let (left_tile, right_tile) = match tile {
         '#' => (Some(TileType::Wall), Some(TileType::Wall)),
         'O' => (Some(TileType::BoxLeft), Some(TileType::BoxRight)),
         '@' => (Some(TileType::Robot), None),
         '.' => (None, None),
-        other => panic!("Unrecognized character '{other}'"),
+        _ => return None,
     };
 
     let left_tile = left_tile.map(|tile| Tile::new(tile, coordinate));
     let right_tile = right_tile.map(|tile| Tile::new(tile, (coordinate.0 + 1, coordinate.1)));
 
-    (left_tile, right_tile)
+    Some((left_tile, right_tile))
 }
 
-fn parse_tiles(warehouse: &str) -> impl Iterator<Item = Tile> + use<'_> {
-    warehouse.lines().enumerate().flat_map(|(y, line)| {
-        line.chars().enumerate().flat_map(move |(x, tile)| {
-            let (left, right) = parse_tile(tile, (x as i32 * 2, y as i32));
-            [left, right].into_iter().flatten()
-        })
-    })
+fn parse_tiles(warehouse: &str) -> Result<Vec<Tile>, ParseError> {
+    let mut tiles = vec![];
+
+    for (y, line) in warehouse.lines().enumerate() {
+        for (x, (index, tile)) in line.char_indices().enumerate() {
+            let (left, right) = parse_tile(tile, (x as i32 * 2, y as i32)).ok_or_else(|| {
+                ParseError::new(
+                    &line[index..index + tile.len_utf8()],
+                    "'#', 'O', '@' or '.'",
+                )
+            })?;
+            tiles.extend([left, right].into_iter().flatten());
+        }
+    }
+
+    Ok(tiles)
 }
 
-fn parse_warehouse(input: &str) -> (Warehouse, impl Iterator<Item = Direction> + use<'_>) {
-    fn parse_moves(moves: &str) -> impl Iterator<Item = Direction> + use<'_> {
-        moves.chars().filter(|&m| m != '\n').map(|m| m.into())
+fn parse_warehouse(input: &str) -> Result<(Warehouse, Vec<Direction>), ParseError> {
+    fn parse_moves(moves: &str) -> Result<Vec<Direction>, ParseError> {
+        moves
+            .char_indices()
+            .filter(|&(_, m)| m != '\n')
+            .map(|(index, m)| {
+                m.try_into().map_err(|_| {
+                    ParseError::new(&moves[index..index + m.len_utf8()], "a move arrow")
+                })
+            })
+            .collect()
     }
 
-    let (warehouse, moves) = input.split_once("\n\n").unwrap();
-    let directions = parse_moves(moves);
+    let (warehouse, moves) = input
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::end_of(input, "a blank line before the moves"))?;
 
-    (warehouse.into(), directions)
+    Ok((Warehouse::parse(warehouse)?, parse_moves(moves)?))
 }
 
-fn perform_moves(input: &str) -> String {
-    let (mut warehouse, moves) = parse_warehouse(input);
-    moves.for_each(|m| warehouse.move_robot(&m));
+fn perform_moves(input: &str) -> Result<String, ParseError> {
+    let (warehouse, moves) = parse_warehouse(input)?;
+
+    Ok(moved_warehouse(warehouse, moves))
+}
+
+fn moved_warehouse(mut warehouse: Warehouse, moves: impl IntoIterator<Item = Direction>) -> String {
+    moves.into_iter().for_each(|m| warehouse.move_robot(&m));
 
     warehouse.to_string()
 }
 
-fn main() {
-    let data = include_str!("../../data/day15");
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = (Warehouse, Vec<Direction>);
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse_warehouse(input).map_err(|error| error.locate(input, 2024, 15).into())
+    }
+
+    fn part1(&self, (warehouse, moves): &Self::Input) -> Option<Answer> {
+        let warehouse = moved_warehouse(warehouse.clone(), moves.iter().copied());
+
+        Some(
+            Warehouse::parse(&warehouse)
+                .ok()?
+                .sum_box_gps_coordinates()
+                .into(),
+        )
+    }
+
+    fn simulation(&self, (warehouse, moves): &Self::Input) -> Option<Box<dyn Simulate>> {
+        Some(Box::new(Moving {
+            warehouse: warehouse.clone(),
+            moves: moves.clone().into_iter(),
+        }))
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let (warehouse, moves) = Day15.parse(&read_input(2024, 15)?)?;
 
-    let warehouse = perform_moves(data);
-    let gps_sum = Warehouse::from(warehouse.as_str()).sum_box_gps_coordinates();
+    let warehouse = moved_warehouse(warehouse, moves);
+    let gps_sum = Warehouse::parse(&warehouse)?.sum_box_gps_coordinates();
 
     println!("Part 1: {}", gps_sum);
+
+    Ok(())
 }
 
 #[cfg(test)]
@@ -313,34 +338,28 @@ mod tests {
     use indoc::indoc;
     use itertools::assert_equal;
 
+    // Warehouses in the tests are written as literals
+    impl From<&str> for Warehouse {
+        fn from(warehouse: &str) -> Self {
+            Warehouse::parse(warehouse).unwrap()
+        }
+    }
+
     impl Warehouse {
-        fn parse_parsed_tile(tile: char, coordinate: Coordinate) -> std::option::Option<Tile> {
-            let tile = match tile {
+        fn parse_parsed_tile(tile: char) -> Option<TileType> {
+            match tile {
                 '#' => Some(TileType::Wall),
                 '[' => Some(TileType::BoxLeft),
                 ']' => Some(TileType::BoxRight),
                 '@' => Some(TileType::Robot),
                 '.' => None,
                 other => panic!("Unrecognized character '{other}'"),
-            };
-
-            tile.map(|tile| Tile::new(tile, coordinate))
+            }
         }
 
         fn from_parsed(warehouse: &str) -> Self {
-            let tiles = warehouse.lines().enumerate().flat_map(|(y, line)| {
-                line.chars().enumerate().flat_map(move |(x, tile)| {
-                    Warehouse::parse_parsed_tile(tile, (x as i32, y as i32))
-                })
-            });
-
-            let lines = warehouse.lines();
-            let height = lines.clone().count();
-
             Self {
-                tiles: tiles.collect(),
-                width: warehouse.lines().nth(0).unwrap().len(),
-                height,
+                tiles: Grid::parse(warehouse, Warehouse::parse_parsed_tile),
             }
         }
     }
@@ -350,7 +369,7 @@ mod tests {
         let warehouse = indoc! {"
             .O.#
         "};
-        let warehouse = parse_tiles(warehouse).collect_vec();
+        let warehouse = parse_tiles(warehouse).unwrap();
 
         let expected = [
             Tile {
@@ -552,7 +571,7 @@ mod tests {
             ....
             .O..
         "};
-        let warehouse = Warehouse::from(warehouse);
+        let mut warehouse = Warehouse::from(warehouse);
         println!("{}", &warehouse);
 
         warehouse.move_robot(&Direction::Down);
@@ -596,7 +615,7 @@ mod tests {
             #...O..#
             ########"
         };
-        assert_eq!(expected, perform_moves(input));
+        assert_eq!(expected, perform_moves(input).unwrap());
     }
 
     #[test]
@@ -638,7 +657,7 @@ mod tests {
             #OO....OO#
             ##########"
         };
-        assert_eq!(expected, perform_moves(input));
+        assert_eq!(expected, perform_moves(input).unwrap());
     }
 
     #[test]
@@ -1,13 +1,13 @@
+use aoc_common::{parse, read_input, Answer, ParseError, Solution};
 use itertools::Itertools;
 
-pub fn count_safe_reports(reports: &str) -> u32 {
-    parse_reports(reports)
-        .filter(|report| is_safe(report))
-        .count() as u32
+pub fn count_safe_reports(reports: &[Vec<u32>]) -> u32 {
+    reports.iter().filter(|report| is_safe(report)).count() as u32
 }
 
-pub fn count_safe_reports_dampened(reports: &str) -> u32 {
-    parse_reports(reports)
+pub fn count_safe_reports_dampened(reports: &[Vec<u32>]) -> u32 {
+    reports
+        .iter()
         .filter(|report| {
             if is_safe(report) {
                 return true;
@@ -18,12 +18,8 @@ pub fn count_safe_reports_dampened(reports: &str) -> u32 {
         .count() as u32
 }
 
-fn parse_reports(reports: &str) -> impl Iterator<Item = Vec<u32>> + use<'_> {
-    reports.lines().map(|line| {
-        line.split_whitespace()
-            .map(|level| level.parse().expect("All levels are numeric"))
-            .collect()
-    })
+fn parse_reports(reports: &str) -> Result<Vec<Vec<u32>>, ParseError> {
+    reports.lines().map(parse::numbers).collect()
 }
 
 fn is_safe(report: &[u32]) -> bool {
@@ -55,11 +51,31 @@ fn is_sorted(report: &[u32]) -> bool {
     report.iter().is_sorted() || report.iter().rev().is_sorted()
 }
 
-pub fn main() {
-    let data = include_str!("../../data/day2");
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Vec<u32>>;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse_reports(input).map_err(|error| error.locate(input, 2024, 2).into())
+    }
+
+    fn part1(&self, reports: &Self::Input) -> Option<Answer> {
+        Some(count_safe_reports(reports).into())
+    }
+
+    fn part2(&self, reports: &Self::Input) -> Option<Answer> {
+        Some(count_safe_reports_dampened(reports).into())
+    }
+}
+
+pub fn main() -> anyhow::Result<()> {
+    let reports = Day2.parse(&read_input(2024, 2)?)?;
+
+    println!("Part 1: {}", count_safe_reports(&reports));
+    println!("Part 2: {}", count_safe_reports_dampened(&reports));
 
-    println!("Part 1: {}", count_safe_reports(data));
-    println!("Part 2: {}", count_safe_reports_dampened(data));
+    Ok(())
 }
 
 #[cfg(test)]
@@ -78,12 +94,15 @@ mod tests {
 
     #[test]
     fn parses_reports() {
-        assert_eq!(2, count_safe_reports(REPORTS));
+        assert_eq!(2, count_safe_reports(&parse_reports(REPORTS).unwrap()));
     }
 
     #[test]
     fn parses_reports_dampened() {
-        assert_eq!(4, count_safe_reports_dampened(REPORTS));
+        assert_eq!(
+            4,
+            count_safe_reports_dampened(&parse_reports(REPORTS).unwrap())
+        );
     }
 
     #[test]
@@ -91,4 +110,14 @@ mod tests {
         assert!(is_safe_dampened(vec![1, 3, 2, 4, 5].as_slice()));
         assert!(is_safe_dampened(vec![8, 6, 4, 4, 1].as_slice()));
     }
+
+    #[test]
+    fn rejects_levels_that_arent_numbers() {
+        let error = Day2.parse("7 6 4\n1 2 x 4\n").unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "2024 day 2, line 2, column 5: expected a number but found 'x'"
+        );
+    }
 }
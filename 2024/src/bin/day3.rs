@@ -1,3 +1,4 @@
+use aoc_common::{read_input, Answer, Solution};
 use nom::{
     branch::alt,
     bytes::complete::tag,
@@ -7,15 +8,23 @@ use nom::{
     IResult,
 };
 
-pub fn sum_multiplications(string: &str) -> u32 {
-    parse_muls(string)
+/// The uncorrupted parts of the memory
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Instruction {
+    Mul(u32, u32),
+    /// `do` or `don't`, which enable or disable the following `mul`s
+    Toggle(bool),
+}
+
+pub fn sum_multiplications(instructions: &[Instruction]) -> u32 {
+    muls(instructions)
         .into_iter()
         .map(|mul| mul.0 * mul.1)
         .sum()
 }
 
-pub fn sum_multiplications_with_toggles(string: &str) -> u32 {
-    parse_muls_with_toggles(string)
+pub fn sum_multiplications_with_toggles(instructions: &[Instruction]) -> u32 {
+    enabled_muls(instructions)
         .into_iter()
         .map(|mul| mul.0 * mul.1)
         .sum()
@@ -36,51 +45,81 @@ fn parse_mul(i: &str) -> IResult<&str, (u32, u32)> {
     )(i)
 }
 
-fn parse_muls(string: &str) -> Vec<(u32, u32)> {
-    (0..string.len())
-        .filter_map(|position| {
-            if let Ok((_, mul)) = parse_mul(&string[position..]) {
-                Some(mul)
-            } else {
-                None
-            }
-        })
-        .collect()
-}
-
 fn parse_toggle(i: &str) -> IResult<&str, bool> {
     map(alt((tag("don't"), tag("do"))), |toggle| toggle == "do")(i)
 }
 
-fn parse_muls_with_toggles(string: &str) -> Vec<(u32, u32)> {
-    let mut enabled = true;
-
+/// Every instruction that starts somewhere in the corrupted memory
+fn parse_instructions(string: &str) -> Vec<Instruction> {
     (0..string.len())
         .filter_map(|position| {
             let substring = &string[position..];
 
             if let Ok((_, toggle)) = parse_toggle(substring) {
-                enabled = toggle;
-                return None;
-            }
-
-            if !enabled {
-                return None;
+                return Some(Instruction::Toggle(toggle));
             }
 
             match parse_mul(substring) {
-                Ok((_, mul)) => Some(mul),
+                Ok((_, (left, right))) => Some(Instruction::Mul(left, right)),
                 Err(_) => None,
             }
         })
         .collect()
 }
 
-fn main() {
-    let data = include_str!("../../data/day3");
+fn muls(instructions: &[Instruction]) -> Vec<(u32, u32)> {
+    instructions
+        .iter()
+        .filter_map(|instruction| match *instruction {
+            Instruction::Mul(left, right) => Some((left, right)),
+            Instruction::Toggle(_) => None,
+        })
+        .collect()
+}
+
+fn enabled_muls(instructions: &[Instruction]) -> Vec<(u32, u32)> {
+    let mut enabled = true;
 
-    println!("Part 1: {}", sum_multiplications(data));
-    println!("Part 2: {}", sum_multiplications_with_toggles(data));
+    instructions
+        .iter()
+        .filter_map(|instruction| match *instruction {
+            Instruction::Toggle(toggle) => {
+                enabled = toggle;
+                None
+            }
+            Instruction::Mul(left, right) => enabled.then_some((left, right)),
+        })
+        .collect()
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<Instruction>;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse_instructions(input))
+    }
+
+    fn part1(&self, instructions: &Self::Input) -> Option<Answer> {
+        Some(sum_multiplications(instructions).into())
+    }
+
+    fn part2(&self, instructions: &Self::Input) -> Option<Answer> {
+        Some(sum_multiplications_with_toggles(instructions).into())
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let instructions = parse_instructions(&read_input(2024, 3)?);
+
+    println!("Part 1: {}", sum_multiplications(&instructions));
+    println!(
+        "Part 2: {}",
+        sum_multiplications_with_toggles(&instructions)
+    );
+
+    Ok(())
 }
 
 #[cfg(test)]
@@ -95,7 +134,7 @@ mod tests {
 
         assert_equal(
             vec![(2, 4), (5, 5), (11, 8), (8, 5)],
-            parse_muls_with_toggles(memory),
+            enabled_muls(&parse_instructions(memory)),
         );
     }
 
@@ -103,13 +142,16 @@ mod tests {
     fn sums() {
         let memory = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
 
-        assert_eq!(161, sum_multiplications(memory));
+        assert_eq!(161, sum_multiplications(&parse_instructions(memory)));
     }
 
     #[test]
     fn sums_with_toggles() {
         let memory = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
 
-        assert_eq!(48, sum_multiplications_with_toggles(memory));
+        assert_eq!(
+            48,
+            sum_multiplications_with_toggles(&parse_instructions(memory))
+        );
     }
 }
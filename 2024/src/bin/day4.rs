@@ -1,11 +1,12 @@
+use aoc_common::{read_input, Answer, Grid, ParseError, Solution};
 use itertools::Itertools;
 
-#[derive(Clone)]
-struct WordSearch(String);
+#[derive(Clone, Debug)]
+pub struct WordSearch(String);
 
 impl WordSearch {
     // Part 1
-    pub fn count_xmas(self) -> usize {
+    pub fn count_xmas(&self) -> usize {
         let main_diagonals = Box::new(self.diagonals(&Diagonal::Main));
         let anti_diagonals = Box::new(self.diagonals(&Diagonal::Anti));
         let columns = Box::new(self.columns());
@@ -205,12 +206,44 @@ impl Iterator for ColumnIterator<'_> {
     }
 }
 
-fn main() {
-    let data = include_str!("../../data/day4");
-    let word_search = WordSearch(data.to_string());
+/// The word search in `input`, which has to be a rectangle of letters with at
+/// least one in it
+fn parse_word_search(input: &str) -> Result<WordSearch, ParseError> {
+    let grid = Grid::try_parse(input, "a letter", |letter| {
+        letter.is_ascii_graphic().then_some(letter)
+    })?;
+    if grid.width() == 0 || grid.height() == 0 {
+        return Err(ParseError::end_of(input, "a line of letters"));
+    }
+
+    Ok(WordSearch(input.to_string()))
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = WordSearch;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse_word_search(input).map_err(|error| error.locate(input, 2024, 4).into())
+    }
+
+    fn part1(&self, word_search: &Self::Input) -> Option<Answer> {
+        Some(word_search.count_xmas().into())
+    }
+
+    fn part2(&self, word_search: &Self::Input) -> Option<Answer> {
+        Some(word_search.count_cross_mas().into())
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let word_search = Day4.parse(&read_input(2024, 4)?)?;
 
-    println!("Part 1: {}", word_search.clone().count_xmas());
+    println!("Part 1: {}", word_search.count_xmas());
     println!("Part 2: {}", word_search.count_cross_mas());
+
+    Ok(())
 }
 
 #[cfg(test)]
@@ -332,6 +365,21 @@ mod tests {
         assert_eq!(9, word_search.count_cross_mas())
     }
 
+    #[test]
+    fn rejects_empty_and_ragged_word_searches() {
+        let error = parse_word_search("").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "expected a line of letters but the input ended"
+        );
+
+        let error = parse_word_search("XMAS\nSAM\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "expected a line of 4 characters but found 'SAM'"
+        );
+    }
+
     #[test]
     fn converts_anti_diagonals_to_coordinates() {
         let word_search = WordSearch(SMALL_WORD_SEARCH.to_string());
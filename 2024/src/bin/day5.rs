@@ -1,145 +1,98 @@
-use std::{
-    cmp::{self},
-    fmt::Debug,
-    rc::Rc,
+use aoc_common::{
+    parse,
+    precedence::{Cycle, Precedence},
+    read_input, Answer, ParseError, Solution,
 };
 
 // Part 1
-pub fn sum_middle_page_numbers(input: &str) -> u32 {
-    get_valid_page_lines(input)
-        .map(|line| get_line_middle(&line).number)
+pub fn sum_middle_page_numbers(manual: &Manual) -> u32 {
+    get_valid_updates(manual)
+        .map(|update| get_middle(update))
         .sum()
 }
 
 // Part 2
-pub fn sum_middle_of_sorted_page_numbers(input: &str) -> u32 {
-    get_invalid_page_lines(input)
-        .map(|mut line| {
-            sort(&mut line);
-            get_line_middle(&line).number
-        })
+pub fn sum_middle_of_sorted_page_numbers(manual: &Manual) -> Result<u32, Cycle<u32>> {
+    get_invalid_updates(manual)
+        .map(|update| Ok(get_middle(&manual.rules.sort(update)?)))
         .sum()
 }
 
-#[derive(Clone)]
-struct Page {
-    number: u32,
-    rules: Rc<Vec<(u32, u32)>>,
+pub struct Manual {
+    rules: Precedence<u32>,
+    updates: Vec<Vec<u32>>,
 }
 
-impl PartialEq for Page {
-    fn eq(&self, other: &Self) -> bool {
-        self.number == other.number
-    }
+fn get_middle(update: &[u32]) -> u32 {
+    update[update.len() / 2]
 }
 
-impl Debug for Page {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("Page")
-            .field("number", &self.number)
-            .finish()
-    }
+fn get_valid_updates(manual: &Manual) -> impl Iterator<Item = &Vec<u32>> {
+    manual
+        .updates
+        .iter()
+        .filter(|update| manual.rules.is_ordered(update))
 }
 
-impl PartialOrd for Page {
-    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
-        let self_is_less = self
-            .rules
-            .iter()
-            .find(|(before, after)| *before == self.number && *after == other.number);
-
-        if self_is_less.is_some() {
-            return Some(cmp::Ordering::Less);
-        }
-
-        let other_is_less = self
-            .rules
-            .iter()
-            .find(|(before, after)| *before == other.number && *after == self.number);
-
-        if other_is_less.is_some() {
-            return Some(cmp::Ordering::Greater);
-        }
-
-        unreachable!("Every page should have an ordering tule");
-    }
+fn get_invalid_updates(manual: &Manual) -> impl Iterator<Item = &Vec<u32>> {
+    manual
+        .updates
+        .iter()
+        .filter(|update| !manual.rules.is_ordered(update))
 }
 
-fn get_line_middle<T: Clone>(line: &[T]) -> T {
-    let middle = line.len() / 2;
-    line[middle].clone()
-}
+fn parse(input: &str) -> Result<Manual, ParseError> {
+    let (ordering_rules, pages) = input.split_once("\n\n").ok_or_else(|| {
+        ParseError::end_of(input, "an empty line between the rules and the updates")
+    })?;
+    let rules = ordering_rules
+        .lines()
+        .map(parse_rule)
+        .collect::<Result<_, _>>()?;
+    let updates = pages.lines().map(parse_update).collect::<Result<_, _>>()?;
 
-fn sort(pages: &mut [Page]) {
-    pages.sort_by(|a, b| a.partial_cmp(b).expect("Input always be sortable"))
+    Ok(Manual { rules, updates })
 }
 
-fn get_valid_page_lines(input: &str) -> impl Iterator<Item = Vec<Page>> {
-    let valid_pages = parse(input);
-    valid_pages
-        .into_iter()
-        .filter(|pages_line| is_valid_page_line(pages_line))
+fn parse_rule(rule: &str) -> Result<(u32, u32), ParseError> {
+    let (before, after) = parse::split_once(rule, "|")?;
+    let before = parse::token(before, "a page number")?;
+    let after = parse::token(after, "a page number")?;
+
+    Ok((before, after))
 }
 
-fn get_invalid_page_lines(input: &str) -> impl Iterator<Item = Vec<Page>> {
-    let valid_pages = parse(input);
-    valid_pages
-        .into_iter()
-        .filter(|pages_line| !is_valid_page_line(pages_line))
+fn parse_update(line: &str) -> Result<Vec<u32>, ParseError> {
+    line.split(",")
+        .map(|page| parse::token(page, "a page number"))
+        .collect()
 }
 
-fn parse(input: &str) -> Vec<Vec<Page>> {
-    let (ordering_rules, pages) = input.split_once("\n\n").expect("Input has correct format");
-    let ordering_rules: Vec<_> = ordering_rules.lines().map(parse_rule).collect();
+pub struct Day5;
 
-    let ordering_rules = Rc::new(ordering_rules);
-    let valid_page_lines = pages
-        .lines()
-        .map(|line| parse_pages_line(line, ordering_rules.clone()))
-        .collect();
+impl Solution for Day5 {
+    type Input = Manual;
 
-    valid_page_lines
-}
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse(input).map_err(|error| error.locate(input, 2024, 5).into())
+    }
 
-fn parse_rule(rule: &str) -> (u32, u32) {
-    let (before, after) = rule.split_once("|").unwrap();
-    let before = before.parse().expect("Must be numeric");
-    let after = after.parse().expect("Must be numeric");
+    fn part1(&self, manual: &Self::Input) -> Option<Answer> {
+        Some(sum_middle_page_numbers(manual).into())
+    }
 
-    (before, after)
+    fn try_part2(&self, manual: &Self::Input) -> anyhow::Result<Option<Answer>> {
+        Ok(Some(sum_middle_of_sorted_page_numbers(manual)?.into()))
+    }
 }
 
-fn parse_pages_line(line: &str, ordering_rules: Rc<Vec<(u32, u32)>>) -> Vec<Page> {
-    line.split(",")
-        .map(|page| {
-            let number = page.parse().expect("Must be numeric");
-            let rules = Rc::clone(&ordering_rules);
-            Page { number, rules }
-        })
-        .collect()
-}
+fn main() -> anyhow::Result<()> {
+    let manual = Day5.parse(&read_input(2024, 5)?)?;
 
-fn is_valid_page_line(pages_line: &[Page]) -> bool {
-    pages_line
-        .iter()
-        .enumerate()
-        .map(|(page_index, page)| {
-            let mut relevant_page_rules =
-                page.rules.iter().filter(|(_, after)| *after == page.number);
-
-            relevant_page_rules.all(|(before, _)| {
-                let mut trailing_pages = pages_line.iter().skip(page_index + 1);
-                !trailing_pages.any(|page| page.number == *before)
-            })
-        })
-        .all(|valid_page| valid_page)
-}
+    println!("Part 1: {}", sum_middle_page_numbers(&manual));
+    println!("Part 2: {}", sum_middle_of_sorted_page_numbers(&manual)?);
 
-fn main() {
-    let data = include_str!("../../data/day5");
-
-    println!("Part 1: {}", sum_middle_page_numbers(data));
-    println!("Part 2: {}", sum_middle_of_sorted_page_numbers(data));
+    Ok(())
 }
 
 #[cfg(test)]
@@ -181,72 +134,69 @@ mod tests {
 
     #[test]
     fn invalidates_invalid_line() {
-        let page_lines = parse(INPUT);
-        let invalid_line = page_lines.into_iter().nth(3).unwrap();
+        let manual = parse(INPUT).unwrap();
 
-        assert!(!is_valid_page_line(&invalid_line))
+        assert!(!manual.rules.is_ordered(&manual.updates[3]))
     }
 
     #[test]
     fn gets_valid_page_lines() {
-        let valid_pages = get_valid_page_lines(INPUT);
+        let manual = parse(INPUT).unwrap();
+        let valid_updates = get_valid_updates(&manual);
 
         let expected = [
             vec![75, 47, 61, 53, 29],
             vec![97, 61, 53, 29, 13],
             vec![75, 29, 13],
-        ]
-        .map(|pages_line| {
-            pages_line
-                .into_iter()
-                .map(|number| {
-                    let rules = Rc::new(vec![]);
-                    Page { number, rules }
-                })
-                .collect::<Vec<_>>()
-        });
-
-        assert_equal(expected, valid_pages);
+        ];
+
+        assert_equal(&expected, valid_updates);
     }
 
     #[test]
     fn sums_middle_page_numbers() {
-        assert_eq!(143, sum_middle_page_numbers(INPUT));
+        assert_eq!(143, sum_middle_page_numbers(&parse(INPUT).unwrap()));
     }
 
     #[test]
-    fn compares_pages() {
-        let rules = parse(INPUT);
-        let rules = &rules.first().unwrap().first().unwrap().rules;
-
-        for (before, after) in rules.iter() {
-            let page1 = Page {
-                number: *before,
-                rules: rules.clone(),
-            };
-            let page2 = Page {
-                number: *after,
-                rules: rules.clone(),
-            };
-
-            assert!(page1 < page2);
-        }
+    fn finds_broken_rules() {
+        let manual = parse(INPUT).unwrap();
+
+        assert_eq!(manual.rules.violations(&manual.updates[3]), [(97, 75)]);
+        assert_eq!(manual.rules.violations(&manual.updates[4]), [(29, 13)]);
     }
 
     #[test]
     fn sorts_pages() {
-        let rules = parse(INPUT);
-        let rules = &rules.first().unwrap().first().unwrap().rules;
-
-        let mut pages = [61, 13, 29].map(|number| Page {
-            number,
-            rules: rules.clone(),
-        });
-        pages.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let manual = parse(INPUT).unwrap();
+
+        assert_eq!(manual.rules.sort(&[61, 13, 29]), Ok(vec![61, 29, 13]));
+        assert_eq!(
+            manual.rules.sort(&[97, 13, 75, 29, 47]),
+            Ok(vec![97, 75, 47, 29, 13])
+        );
     }
 
     #[test]
     fn sums_middle_of_sorted_page_numbers() {
-        assert_eq!(123, sum_middle_of_sorted_page_numbers(INPUT));
+        assert_eq!(
+            Ok(123),
+            sum_middle_of_sorted_page_numbers(&parse(INPUT).unwrap())
+        );
+    }
+
+    #[test]
+    fn rejects_contradicting_rules() {
+        let input = "1|2\n2|3\n3|1\n\n1,2\n3,2,1\n";
+
+        let manual = Day5.parse(input).unwrap();
+        let error = Day5.try_part2(&manual).unwrap_err();
+
+        // Part 1 doesn't sort anything, so it can still be solved
+        assert_eq!(Day5.part1(&manual), Some(Answer::from(2)));
+        assert_eq!(
+            error.to_string(),
+            "the ordering rules contradict each other: 1 -> 2 -> 3 -> 1"
+        );
     }
 }
@@ -1,9 +1,10 @@
+use aoc_common::{read_input, Answer, Direction, Grid, ParseError, Point2, Simulate, Solution};
 use itertools::Itertools;
 use std::{collections::HashSet, fmt::Display};
 
 // Part 1
-pub fn count_visited_tiles(map: &str) -> usize {
-    let mut lab = Lab::new(map);
+pub fn count_visited_tiles(lab: &Lab) -> usize {
+    let mut lab = lab.clone();
 
     while let State::Simulating = lab.move_guard() {
         lab.map.set_tile_visited(lab.guard.position);
@@ -17,9 +18,7 @@ pub fn count_visited_tiles(map: &str) -> usize {
 }
 
 // Part 2
-fn count_looping_obstacle_placements(map: &str) -> usize {
-    // I create an initial lab here just to determine the height/width
-    let lab = Lab::new(map);
+fn count_looping_obstacle_placements(lab: &Lab) -> usize {
     let width = lab.map.0.first().expect("Map has tiles").len() as u32;
     let height = lab.map.0.len() as u32;
 
@@ -29,7 +28,7 @@ fn count_looping_obstacle_placements(map: &str) -> usize {
                 // Exclude guard start position
                 .skip_while(move |&x| (x, y) == lab.guard_start_state.position)
                 .map(move |x| {
-                    let mut lab = Lab::new(map);
+                    let mut lab = lab.clone();
                     lab.map.place_obstacle_tile((x, y));
                     lab.simulate_guard_looping()
                 })
@@ -40,7 +39,8 @@ fn count_looping_obstacle_placements(map: &str) -> usize {
 
 type Coordinate = (u32, u32);
 
-struct Lab {
+#[derive(Clone)]
+pub struct Lab {
     map: Map,
     guard: Guard,
     guard_start_state: Guard,
@@ -55,11 +55,11 @@ struct Guard {
 
 impl Guard {
     fn turn_right(&mut self) {
-        self.direction = self.direction.turn_right();
+        self.direction = self.direction.clockwise();
     }
 
     fn has_looped(&self) -> bool {
-        let current_state = &(self.position, self.direction.clone());
+        let current_state = &(self.position, self.direction);
         self.visited_states.contains(current_state)
     }
 }
@@ -84,14 +84,14 @@ impl Lab {
                 return State::LoopFound;
             }
 
-            let guard_state = (self.guard.position, self.guard.direction.clone());
+            let guard_state = (self.guard.position, self.guard.direction);
             self.guard.visited_states.insert(guard_state);
             self.map.set_tile_visited(self.guard.position);
         }
     }
 
-    fn new(map: &str) -> Self {
-        let map = Map::from(map);
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let map = Map::try_from(input)?;
         let guard_coordinate = map
             .0
             .iter()
@@ -103,20 +103,20 @@ impl Lab {
                     .find(|&(_, tile)| *tile == Tile::Visited)
                     .map(|(x, _)| (x as u32, y as u32))
             })
-            .unwrap();
+            .ok_or_else(|| ParseError::end_of(input, "a guard '^'"))?;
 
         let guard_direction = Direction::Up;
         let guard = Guard {
             position: guard_coordinate,
-            direction: guard_direction.clone(),
+            direction: guard_direction,
             visited_states: HashSet::from([(guard_coordinate, guard_direction)]),
         };
 
-        Self {
+        Ok(Self {
             map,
             guard_start_state: guard.clone(),
             guard,
-        }
+        })
     }
 
     fn move_guard(&mut self) -> State {
@@ -138,14 +138,9 @@ impl Lab {
         let height = self.map.0.len();
 
         let (x, y) = self.guard.position;
-        let (x, y) = (x as i32, y as i32);
+        let position = Point2::new(x as i32, y as i32);
 
-        let (new_x, new_y) = match self.guard.direction {
-            Direction::Up => (x, y - 1),
-            Direction::Right => (x + 1, y),
-            Direction::Down => (x, y + 1),
-            Direction::Left => (x - 1, y),
-        };
+        let Point2 { x: new_x, y: new_y } = position + self.guard.direction;
 
         if new_x.is_negative() || new_y.is_negative() {
             return None;
@@ -159,6 +154,17 @@ impl Lab {
     }
 }
 
+/// The guard walking one step or turning each tick, until they leave the lab
+impl Simulate for Lab {
+    fn tick(&mut self) -> bool {
+        let State::Simulating = self.move_guard() else {
+            return false;
+        };
+        self.map.set_tile_visited(self.guard.position);
+        true
+    }
+}
+
 impl Display for Lab {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let lab = self
@@ -184,7 +190,7 @@ impl Display for Lab {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct Map(Vec<Vec<Tile>>);
 
 impl Map {
@@ -207,51 +213,20 @@ impl Map {
     }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash)]
-enum Direction {
-    Up,
-    Right,
-    Down,
-    Left,
-}
-
-impl Direction {
-    fn turn_right(&self) -> Self {
-        match self {
-            Direction::Up => Direction::Right,
-            Direction::Right => Direction::Down,
-            Direction::Down => Direction::Left,
-            Direction::Left => Direction::Up,
-        }
-    }
-}
-
-impl Display for Direction {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let direction = match self {
-            Direction::Up => "^",
-            Direction::Right => ">",
-            Direction::Down => "v",
-            Direction::Left => "<",
-        };
-        write!(f, "{direction}")
-    }
-}
-
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 enum Tile {
     Obstacle,
     Visited,
     Unvisited,
 }
 
-impl From<char> for Tile {
-    fn from(tile: char) -> Self {
+impl Tile {
+    fn from_char(tile: char) -> Option<Self> {
         match tile {
-            '.' => Tile::Unvisited,
-            '#' => Tile::Obstacle,
-            '^' => Tile::Visited,
-            other => panic!("Invalid input character '{other}'"),
+            '.' => Some(Tile::Unvisited),
+            '#' => Some(Tile::Obstacle),
+            '^' => Some(Tile::Visited),
+            _ => None,
         }
     }
 }
@@ -267,22 +242,45 @@ impl Display for Tile {
     }
 }
 
-impl From<&str> for Map {
-    fn from(input: &str) -> Self {
-        let map = input
-            .lines()
-            .map(|line| line.chars().map(Tile::from).collect())
-            .collect();
+impl TryFrom<&str> for Map {
+    type Error = ParseError;
 
-        Map(map)
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let grid = Grid::try_parse(input, "'.', '#' or '^'", Tile::from_char)?;
+
+        Ok(Map(grid.rows().map(<[Tile]>::to_vec).collect()))
     }
 }
 
-fn main() {
-    let data = include_str!("../../data/day6");
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Lab;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        Lab::parse(input).map_err(|error| error.locate(input, 2024, 6).into())
+    }
+
+    fn part1(&self, lab: &Self::Input) -> Option<Answer> {
+        Some(count_visited_tiles(lab).into())
+    }
+
+    fn part2(&self, lab: &Self::Input) -> Option<Answer> {
+        Some(count_looping_obstacle_placements(lab).into())
+    }
+
+    fn simulation(&self, lab: &Self::Input) -> Option<Box<dyn Simulate>> {
+        Some(Box::new(lab.clone()))
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let lab = Day6.parse(&read_input(2024, 6)?)?;
+
+    println!("Part 1: {}", count_visited_tiles(&lab));
+    println!("Part 2: {}", count_looping_obstacle_placements(&lab));
 
-    println!("Part 1: {}", count_visited_tiles(data));
-    println!("Part 2: {}", count_looping_obstacle_placements(data));
+    Ok(())
 }
 
 #[cfg(test)]
@@ -305,7 +303,7 @@ mod tests {
 
     #[test]
     fn gets_initial_guard_tile() {
-        let guard_position = Lab::new(MAP).guard.position;
+        let guard_position = Lab::parse(MAP).unwrap().guard.position;
 
         assert_eq!(guard_position, (4, 6));
     }
@@ -325,7 +323,7 @@ mod tests {
             ......#...
         "};
 
-        let mut lab = Lab::new(map);
+        let mut lab = Lab::parse(map).unwrap();
         assert_eq!((4, 1), lab.guard.position);
         assert_eq!(Direction::Up, lab.guard.direction);
 
@@ -342,14 +340,14 @@ mod tests {
 
     #[test]
     fn displays_lab() {
-        let lab = Lab::new(MAP).to_string();
+        let lab = Lab::parse(MAP).unwrap().to_string();
 
         assert_eq!(MAP, lab);
     }
 
     #[test]
     fn detects_guard_left() {
-        let mut lab = Lab::new(MAP);
+        let mut lab = Lab::parse(MAP).unwrap();
 
         let result = lab.simulate_guard_looping();
         assert_eq!(State::GuardLeft, result);
@@ -357,7 +355,7 @@ mod tests {
 
     #[test]
     fn detects_guard_loop() {
-        let mut lab = Lab::new(MAP);
+        let mut lab = Lab::parse(MAP).unwrap();
 
         lab.map.place_obstacle_tile((3, 6));
         let result = lab.simulate_guard_looping();
@@ -367,7 +365,7 @@ mod tests {
 
     #[test]
     fn detects_guard_loop_outside_starting_position() {
-        let mut lab = Lab::new(MAP);
+        let mut lab = Lab::parse(MAP).unwrap();
 
         lab.map.place_obstacle_tile((7, 7));
         let result = lab.simulate_guard_looping();
@@ -377,7 +375,7 @@ mod tests {
 
     #[test]
     fn counts_guard_loops() {
-        let count = count_looping_obstacle_placements(MAP);
+        let count = count_looping_obstacle_placements(&Lab::parse(MAP).unwrap());
 
         assert_eq!(6, count);
     }
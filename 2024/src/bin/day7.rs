@@ -1,23 +1,26 @@
+use aoc_common::{parse, read_input, Answer, ParseError, Solution};
 use itertools::Itertools;
 use std::iter;
 
 // Part 1
-pub fn sum_valid_equations(equations: &str) -> Number {
+pub fn sum_valid_equations(equations: &[Equation]) -> Number {
     let operators = [Operator::Addition, Operator::Multiplication];
-    parse_calibration_equations(equations)
+    equations
+        .iter()
         .filter(|(expected, numbers)| equation_is_valid(*expected, numbers, &operators))
         .map(|(result, _)| result)
         .sum()
 }
 
 // Part 2
-pub fn sum_valid_equations_with_concatenation(equations: &str) -> Number {
+pub fn sum_valid_equations_with_concatenation(equations: &[Equation]) -> Number {
     let operators = [
         Operator::Addition,
         Operator::Multiplication,
         Operator::Concatenation,
     ];
-    parse_calibration_equations(equations)
+    equations
+        .iter()
         .filter(|(expected, numbers)| equation_is_valid(*expected, numbers, &operators))
         .map(|(expected, _)| expected)
         .sum()
@@ -25,6 +28,9 @@ pub fn sum_valid_equations_with_concatenation(equations: &str) -> Number {
 
 type Number = u64;
 
+/// The expected result and the numbers to combine into it
+type Equation = (Number, Vec<Number>);
+
 #[derive(Clone, Debug, PartialEq)]
 enum Operator {
     Addition,
@@ -67,20 +73,21 @@ impl PartialEq<Number> for Expression {
     }
 }
 
-fn parse_calibration_equations(
-    equations: &str,
-) -> impl Iterator<Item = (Number, Vec<Number>)> + use<'_> {
-    equations.lines().map(|line| {
-        let (expected, numbers) = line.split_once(": ").expect("Is correctly formatted");
-
-        let expected = expected.parse().expect("Is numeric");
-        let numbers = numbers
-            .split(" ")
-            .map(|n| n.parse().expect("Is numeric"))
-            .collect();
-
-        (expected, numbers)
-    })
+fn parse_calibration_equations(equations: &str) -> Result<Vec<Equation>, ParseError> {
+    equations
+        .lines()
+        .map(|line| {
+            let (expected, numbers) = parse::split_once(line, ": ")?;
+
+            let expected = parse::token(expected, "a test value")?;
+            let numbers = numbers
+                .split(" ")
+                .map(|n| parse::token(n, "a number"))
+                .collect::<Result<_, _>>()?;
+
+            Ok((expected, numbers))
+        })
+        .collect()
 }
 
 fn equation_is_valid(expected: Number, numbers: &[Number], operators: &[Operator]) -> bool {
@@ -99,11 +106,34 @@ fn create_operator_permutations(
     iter::repeat_n(operators.iter().cloned(), length).multi_cartesian_product()
 }
 
-fn main() {
-    let data = include_str!("../../data/day7");
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<Equation>;
 
-    println!("Part 1: {}", sum_valid_equations(data));
-    println!("Part 2: {}", sum_valid_equations_with_concatenation(data));
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse_calibration_equations(input).map_err(|error| error.locate(input, 2024, 7).into())
+    }
+
+    fn part1(&self, equations: &Self::Input) -> Option<Answer> {
+        Some(sum_valid_equations(equations).into())
+    }
+
+    fn part2(&self, equations: &Self::Input) -> Option<Answer> {
+        Some(sum_valid_equations_with_concatenation(equations).into())
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let equations = Day7.parse(&read_input(2024, 7)?)?;
+
+    println!("Part 1: {}", sum_valid_equations(&equations));
+    println!(
+        "Part 2: {}",
+        sum_valid_equations_with_concatenation(&equations)
+    );
+
+    Ok(())
 }
 
 #[cfg(test)]
@@ -150,7 +180,8 @@ mod tests {
             292: 11 6 16 20
         "};
 
-        assert_eq!(3749, sum_valid_equations(equations));
+        let equations = parse_calibration_equations(equations).unwrap();
+        assert_eq!(3749, sum_valid_equations(&equations));
     }
 
     #[test]
@@ -167,6 +198,21 @@ mod tests {
             292: 11 6 16 20
         "};
 
-        assert_eq!(11387, sum_valid_equations_with_concatenation(equations));
+        let equations = parse_calibration_equations(equations).unwrap();
+        assert_eq!(11387, sum_valid_equations_with_concatenation(&equations));
+    }
+
+    #[test]
+    fn reports_where_equation_is_malformed() {
+        let equations = indoc! {"
+            190: 10 19
+            3267: 81 4o 27
+        "};
+
+        let error = Day7.parse(equations).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "2024 day 7, line 2, column 10: expected a number but found '4o'"
+        );
     }
 }
@@ -1,41 +1,16 @@
+use aoc_common::{read_input, Answer, Grid, ParseError, Point2, Solution};
 use itertools::Itertools;
 use std::{
     collections::{HashMap, HashSet},
     fmt::{Debug, Display},
-    ops::{Add, Mul, Sub},
 };
 
 type Antennas = HashMap<char, HashSet<Coordinate>>;
 
-#[derive(Eq, PartialEq, Hash, Clone, Copy, PartialOrd, Ord, Debug)]
-struct Coordinate(i32, i32);
-
-impl Sub for Coordinate {
-    type Output = Self;
-
-    fn sub(self, rhs: Self) -> Self::Output {
-        Self(self.0 - rhs.0, self.1 - rhs.1)
-    }
-}
-
-impl Add for Coordinate {
-    type Output = Self;
-
-    fn add(self, rhs: Self) -> Self::Output {
-        Self(self.0 + rhs.0, self.1 + rhs.1)
-    }
-}
-
-impl Mul<i32> for Coordinate {
-    type Output = Self;
-
-    fn mul(self, rhs: i32) -> Self::Output {
-        Coordinate(self.0 * rhs, self.1 * rhs)
-    }
-}
+type Coordinate = Point2<i32>;
 
 #[derive(Debug)]
-struct Map {
+pub struct Map {
     antennas: Antennas,
     height: usize,
     width: usize,
@@ -81,10 +56,10 @@ impl Map {
     }
 
     fn is_out_of_bounds(&self, coordinate: Coordinate) -> bool {
-        coordinate.0.is_negative()
-            || coordinate.1.is_negative()
-            || coordinate.0 as usize >= self.width
-            || coordinate.1 as usize >= self.height
+        coordinate.x.is_negative()
+            || coordinate.y.is_negative()
+            || coordinate.x as usize >= self.width
+            || coordinate.y as usize >= self.height
     }
 }
 
@@ -98,14 +73,14 @@ impl Display for Map {
                     .map(|x| {
                         let (x, y) = (x as i32, y as i32);
 
-                        if antinodes.contains(&Coordinate(x, y)) {
+                        if antinodes.contains(&Coordinate::new(x, y)) {
                             return '#';
                         }
 
                         let antenna = self
                             .antennas
                             .iter()
-                            .find(|(_, positions)| positions.contains(&Coordinate(x, y)));
+                            .find(|(_, positions)| positions.contains(&Coordinate::new(x, y)));
 
                         if let Some(antenna) = antenna {
                             *antenna.0
@@ -120,27 +95,25 @@ impl Display for Map {
     }
 }
 
-fn parse_map(map: &str) -> Map {
-    let antennas = map
-        .lines()
-        .enumerate()
-        .flat_map(|(y, line)| {
-            line.chars()
-                .enumerate()
-                .map(move |(x, c)| (c, Coordinate(x as i32, y as i32)))
-        })
-        .fold(HashMap::new(), |mut map: Antennas, (c, coordinate)| {
+fn parse_map(map: &str) -> Result<Map, ParseError> {
+    let grid = Grid::try_parse(map, "an antenna or '.'", |c| {
+        (c == '.' || c.is_ascii_alphanumeric()).then_some(c)
+    })?;
+
+    let antennas = grid
+        .iter()
+        .fold(HashMap::new(), |mut map: Antennas, (coordinate, &c)| {
             if c != '.' {
                 map.entry(c).or_default().insert(coordinate);
             }
             map
         });
 
-    Map {
+    Ok(Map {
         antennas,
-        height: map.lines().count(),
-        width: map.lines().next().expect("Has lines").len(),
-    }
+        height: grid.height(),
+        width: grid.width(),
+    })
 }
 
 fn get_first_antinodes(c1: Coordinate, c2: Coordinate) -> (Coordinate, Coordinate) {
@@ -149,11 +122,31 @@ fn get_first_antinodes(c1: Coordinate, c2: Coordinate) -> (Coordinate, Coordinat
     (antinode1, antinode2)
 }
 
-fn main() {
-    let data = include_str!("../../data/day8");
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Map;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse_map(input).map_err(|error| error.locate(input, 2024, 8).into())
+    }
+
+    fn part1(&self, map: &Self::Input) -> Option<Answer> {
+        Some(map.get_first_antinodes().count().into())
+    }
+
+    fn part2(&self, map: &Self::Input) -> Option<Answer> {
+        Some(map.get_antinodes().count().into())
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let map = Day8.parse(&read_input(2024, 8)?)?;
+
+    println!("Part 1: {}", map.get_first_antinodes().count());
+    println!("Part 2: {}", map.get_antinodes().count());
 
-    println!("Part 1: {}", parse_map(data).get_first_antinodes().count());
-    println!("Part 2: {}", parse_map(data).get_antinodes().count());
+    Ok(())
 }
 
 #[cfg(test)]
@@ -168,7 +161,7 @@ mod tests {
             .iter()
             .collect::<HashSet<_>>()
             .iter()
-            .map(|(x, y)| Coordinate(*x, *y))
+            .map(|(x, y)| Coordinate::new(*x, *y))
             .sorted()
             .collect()
     }
@@ -190,7 +183,7 @@ mod tests {
             ............
         "};
 
-        let map = parse_map(map);
+        let map = parse_map(map).unwrap();
 
         let zero_antennas = map.antennas.get(&'0').unwrap();
         let a_antennas = map.antennas.get(&'A').unwrap();
@@ -204,10 +197,10 @@ mod tests {
 
     #[test]
     fn gets_first_antinodes() {
-        let antenna1 = Coordinate(4, 3);
-        let antenna2 = Coordinate(5, 5);
+        let antenna1 = Coordinate::new(4, 3);
+        let antenna2 = Coordinate::new(5, 5);
 
-        let expected_antinodes = (Coordinate(3, 1), Coordinate(6, 7));
+        let expected_antinodes = (Coordinate::new(3, 1), Coordinate::new(6, 7));
         assert_eq!(expected_antinodes, get_first_antinodes(antenna1, antenna2))
     }
 
@@ -225,9 +218,9 @@ mod tests {
             ..........
             ..........
         "};
-        let map = parse_map(map);
+        let map = parse_map(map).unwrap();
 
-        let expected_antinodes: Vec<_> = [Coordinate(3, 1), Coordinate(6, 7)]
+        let expected_antinodes: Vec<_> = [Coordinate::new(3, 1), Coordinate::new(6, 7)]
             .into_iter()
             .sorted()
             .collect();
@@ -252,7 +245,7 @@ mod tests {
             ............
             ............
         "};
-        assert_eq!(14, parse_map(map).get_first_antinodes().count());
+        assert_eq!(14, parse_map(map).unwrap().get_first_antinodes().count());
     }
 
     #[test]
@@ -269,7 +262,7 @@ mod tests {
             ............
             ............
         "};
-        let map = parse_map(map);
+        let map = parse_map(map).unwrap();
 
         assert_eq!(9, map.get_antinodes().count());
     }
@@ -1,23 +1,24 @@
+use aoc_common::{read_input, Answer, ParseError, Solution};
 use itertools::Itertools;
 
 // Part 1
-pub fn calculate_checksum(blocks: &str) -> usize {
-    let mut disk_map = parse_disk_map(blocks);
+pub fn calculate_checksum(disk_map: &DiskMap) -> usize {
+    let mut disk_map = disk_map.clone();
     disk_map.move_file_blocks();
 
     disk_map.calculate_checksum()
 }
 
 // Part 2
-pub fn calculate_checksum2(blocks: &str) -> usize {
-    let mut disk_map = parse_disk_map(blocks);
+pub fn calculate_checksum2(disk_map: &DiskMap) -> usize {
+    let mut disk_map = disk_map.clone();
     disk_map.move_files();
 
     disk_map.calculate_checksum()
 }
 
-#[derive(Debug)]
-struct DiskMap(Vec<Block>);
+#[derive(Debug, Clone)]
+pub struct DiskMap(Vec<Block>);
 
 #[derive(Clone, Debug, PartialEq)]
 enum Block {
@@ -47,15 +48,21 @@ impl DiskMap {
                 .0
                 .iter()
                 .rev()
-                .position(|block| matches!(block, Block::Id(_)))
-                .unwrap();
-
-            let last_file_pos = self.0.len() - 1 - last_file_from_right_pos;
+                .position(|block| matches!(block, Block::Id(_)));
             let first_free_block_pos = self
                 .0
                 .iter()
-                .position(|block| matches!(block, Block::FreeSpace))
-                .unwrap();
+                .position(|block| matches!(block, Block::FreeSpace));
+
+            // Without both a file block and some free space there is nothing
+            // to move
+            let (Some(last_file_from_right_pos), Some(first_free_block_pos)) =
+                (last_file_from_right_pos, first_free_block_pos)
+            else {
+                return;
+            };
+
+            let last_file_pos = self.0.len() - 1 - last_file_from_right_pos;
 
             if last_file_pos <= first_free_block_pos {
                 return;
@@ -65,22 +72,17 @@ impl DiskMap {
         }
     }
 
-    fn get_last_file_id(&self) -> usize {
-        let Block::Id(last_id) = self
-            .0
-            .iter()
-            .rev()
-            .find(|block| matches!(block, Block::Id(_)))
-            .unwrap()
-        else {
-            unreachable!()
-        };
-
-        *last_id
+    fn get_last_file_id(&self) -> Option<usize> {
+        self.0.iter().rev().find_map(|block| match block {
+            Block::Id(id) => Some(*id),
+            Block::FreeSpace => None,
+        })
     }
 
     fn move_files(&mut self) {
-        let last_file_id = self.get_last_file_id();
+        let Some(last_file_id) = self.get_last_file_id() else {
+            return;
+        };
 
         for id in (0..=last_file_id).rev() {
             let Some((file_start, file_end)) = self.find_file_position(id) else {
@@ -131,21 +133,30 @@ impl DiskMap {
     }
 }
 
-fn parse_disk_map(disk_map: &str) -> DiskMap {
+fn parse_disk_map(disk_map: &str) -> Result<DiskMap, ParseError> {
+    let disk_map = disk_map.trim_end();
     // We need a Vec to be able to call `.chunks()`
-    let blocks: Vec<_> = disk_map.chars().collect();
-    let disk_map = blocks
+    let sizes = disk_map
+        .char_indices()
+        .map(|(index, size)| {
+            size.to_digit(10).ok_or_else(|| {
+                ParseError::new(&disk_map[index..index + size.len_utf8()], "a digit")
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if sizes.is_empty() {
+        return Err(ParseError::end_of(disk_map, "a disk map"));
+    }
+
+    let disk_map = sizes
         .chunks(2)
         .enumerate()
         .flat_map(|(id, chunk)| {
-            let (files, free_space) = (chunk[0], chunk.get(1));
+            let (files_size, free_space_size) = (chunk[0], chunk.get(1));
 
-            let expanded_free_space = free_space.map(|free_space| {
-                let free_space_size = free_space.to_digit(10).expect("Is numeric");
-                expand_blocks(Block::FreeSpace, free_space_size)
-            });
+            let expanded_free_space = free_space_size
+                .map(|&free_space_size| expand_blocks(Block::FreeSpace, free_space_size));
 
-            let files_size = files.to_digit(10).expect("Is numeric");
             let expanded_files = expand_blocks(Block::Id(id), files_size);
 
             let expanded_blocks: Vec<_> = [Some(expanded_files), expanded_free_space]
@@ -158,18 +169,38 @@ fn parse_disk_map(disk_map: &str) -> DiskMap {
         .flatten()
         .collect();
 
-    DiskMap(disk_map)
+    Ok(DiskMap(disk_map))
 }
 
 fn expand_blocks(block: Block, size: u32) -> Vec<Block> {
     std::iter::repeat_n(block, size as usize).collect()
 }
 
-fn main() {
-    let data = include_str!("../../data/day9");
+pub struct Day9;
 
-    println!("Part 1: {}", calculate_checksum(data));
-    println!("Part 2: {}", calculate_checksum2(data));
+impl Solution for Day9 {
+    type Input = DiskMap;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse_disk_map(input).map_err(|error| error.locate(input, 2024, 9).into())
+    }
+
+    fn part1(&self, disk_map: &Self::Input) -> Option<Answer> {
+        Some(calculate_checksum(disk_map).into())
+    }
+
+    fn part2(&self, disk_map: &Self::Input) -> Option<Answer> {
+        Some(calculate_checksum2(disk_map).into())
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let disk_map = Day9.parse(&read_input(2024, 9)?)?;
+
+    println!("Part 1: {}", calculate_checksum(&disk_map));
+    println!("Part 2: {}", calculate_checksum2(&disk_map));
+
+    Ok(())
 }
 
 #[cfg(test)]
@@ -216,11 +247,14 @@ mod tests {
 
     #[test]
     fn parses_blocks() {
-        assert_eq!(DiskMap::from("0..111....22222"), parse_disk_map("12345"));
+        assert_eq!(
+            DiskMap::from("0..111....22222"),
+            parse_disk_map("12345").unwrap()
+        );
 
         let blocks = "2333133121414131402";
         let expected = "00...111...2...333.44.5555.6666.777.888899";
-        assert_eq!(DiskMap::from(expected), parse_disk_map(blocks));
+        assert_eq!(DiskMap::from(expected), parse_disk_map(blocks).unwrap());
     }
 
     #[test]
@@ -234,12 +268,12 @@ mod tests {
 
     #[test]
     fn moves_file_blocks() {
-        let mut disk_map = parse_disk_map("12345");
+        let mut disk_map = parse_disk_map("12345").unwrap();
         disk_map.move_file_blocks();
 
         assert_eq!(DiskMap::from("022111222......"), disk_map);
 
-        let mut disk_map = parse_disk_map("2333133121414131402");
+        let mut disk_map = parse_disk_map("2333133121414131402").unwrap();
         disk_map.move_file_blocks();
 
         let expected = DiskMap::from("0099811188827773336446555566..............");
@@ -248,12 +282,15 @@ mod tests {
 
     #[test]
     fn calculates_checksum() {
-        assert_eq!(1928, calculate_checksum("2333133121414131402"));
+        assert_eq!(
+            1928,
+            calculate_checksum(&parse_disk_map("2333133121414131402").unwrap())
+        );
     }
 
     #[test]
     fn moves_file_blocks_with_zeros() {
-        let mut disk_map = parse_disk_map("101111");
+        let mut disk_map = parse_disk_map("101111").unwrap();
         disk_map.move_file_blocks();
 
         assert_eq!(DiskMap::from("012.."), disk_map);
@@ -261,7 +298,7 @@ mod tests {
 
     #[test]
     fn moves_file_blocks_with_tens() {
-        let mut disk_map = parse_disk_map("101010101010101010111");
+        let mut disk_map = parse_disk_map("101010101010101010111").unwrap();
         disk_map.move_file_blocks();
 
         let expected = DiskMap(vec![
@@ -284,7 +321,22 @@ mod tests {
 
     #[test]
     fn calculates_checksum_with_zeros() {
-        assert_eq!(5, calculate_checksum("101011"));
+        assert_eq!(5, calculate_checksum(&parse_disk_map("101011").unwrap()));
+    }
+
+    #[test]
+    fn calculates_checksum_without_free_space() {
+        let disk_map = parse_disk_map("1").unwrap();
+
+        assert_eq!(0, calculate_checksum(&disk_map));
+        assert_eq!(0, calculate_checksum2(&disk_map));
+    }
+
+    #[test]
+    fn rejects_empty_disk_map() {
+        let error = parse_disk_map("\n").unwrap_err();
+
+        assert_eq!(error.to_string(), "expected a disk map but the input ended");
     }
 
     #[test]
@@ -340,7 +392,7 @@ mod tests {
 
     #[test]
     fn moves_files2() {
-        let mut disk_map = parse_disk_map("2333133121414131402");
+        let mut disk_map = parse_disk_map("2333133121414131402").unwrap();
         println!("   input: {}", &disk_map);
 
         disk_map.move_files();
@@ -372,6 +424,9 @@ mod tests {
 
     #[test]
     fn calculates_checksum2() {
-        assert_eq!(2858, calculate_checksum2("2333133121414131402"));
+        assert_eq!(
+            2858,
+            calculate_checksum2(&parse_disk_map("2333133121414131402").unwrap())
+        );
     }
 }
@@ -1,37 +1,44 @@
-#![feature(assert_matches)]
+use aoc_common::{Answer, ParseError, Solution, parse, read_input};
 
 const DIAL_NUMBERS: i16 = 100;
 
 type Distance = u16;
+type Rotation = (Direction, Distance);
 
 #[derive(Debug, Clone, Copy)]
-enum Direction {
+pub enum Direction {
     Left = -1,
     Right = 1,
 }
 
-impl From<&str> for Direction {
-    fn from(direction: &str) -> Self {
+impl TryFrom<&str> for Direction {
+    type Error = ParseError;
+
+    fn try_from(direction: &str) -> Result<Self, Self::Error> {
         match direction {
-            "L" => Direction::Left,
-            "R" => Direction::Right,
-            _ => panic!("invalid direction '{direction}'"),
+            "L" => Ok(Direction::Left),
+            "R" => Ok(Direction::Right),
+            _ => Err(ParseError::new(direction, "'L' or 'R'")),
         }
     }
 }
 
-fn parse_rotation(rotation: &str) -> (Direction, Distance) {
-    let (direction, distance) = rotation.split_at(1);
-    let distance: Distance = distance
-        .parse()
-        .unwrap_or_else(|_| panic!("invalid distance '{distance}'"));
+fn parse_rotation(rotation: &str) -> Result<Rotation, ParseError> {
+    let split = rotation
+        .char_indices()
+        .nth(1)
+        .map_or(rotation.len(), |(index, _)| index);
+    let (direction, distance) = rotation.split_at(split);
+    let distance = parse::token(distance, "a distance")?;
 
-    (direction.into(), distance)
+    Ok((direction.try_into()?, distance))
 }
 
-fn count_zeroes(data: &str) -> u32 {
-    let rotations: Vec<_> = data.lines().map(parse_rotation).collect();
+fn parse_rotations(data: &str) -> Result<Vec<Rotation>, ParseError> {
+    data.lines().map(parse_rotation).collect()
+}
 
+fn count_zeroes(rotations: &[Rotation]) -> u32 {
     let (_, zeroes) = rotations
         .iter()
         .fold((50, 0), |(number, zeroes), &(direction, distance)| {
@@ -57,9 +64,7 @@ fn count_passed_zeroes(start_number: i16, clicks: i16) -> i16 {
         .count() as i16
 }
 
-fn count_any_zeroes(data: &str) -> u32 {
-    let rotations: Vec<_> = data.lines().map(parse_rotation).collect();
-
+fn count_any_zeroes(rotations: &[Rotation]) -> u32 {
     let (_, zeroes) = rotations
         .iter()
         .fold((50, 0), |(number, zeroes), &(direction, distance)| {
@@ -73,25 +78,45 @@ fn count_any_zeroes(data: &str) -> u32 {
     zeroes as u32
 }
 
-fn main() {
-    let input = include_str!("../../input/day1");
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<Rotation>;
 
-    let zeroes = count_zeroes(input);
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse_rotations(input).map_err(|error| error.locate(input, 2025, 1).into())
+    }
+
+    fn part1(&self, rotations: &Self::Input) -> Option<Answer> {
+        Some(count_zeroes(rotations).into())
+    }
+
+    fn part2(&self, rotations: &Self::Input) -> Option<Answer> {
+        Some(count_any_zeroes(rotations).into())
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let rotations = Day1.parse(&read_input(2025, 1)?)?;
+
+    let zeroes = count_zeroes(&rotations);
     println!("Part 1: {zeroes}");
 
-    let passed_zeroes = count_any_zeroes(input);
+    let passed_zeroes = count_any_zeroes(&rotations);
     println!("Part 2: {passed_zeroes}");
+
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::assert_matches::assert_matches;
+    use aoc_common::assert_matches;
 
     #[test]
     fn parses_rotation() {
         let rotation = "R29";
-        assert_matches!(parse_rotation(rotation), (Direction::Right, 29))
+        assert_matches!(parse_rotation(rotation), Ok((Direction::Right, 29)))
     }
 
     #[test]
@@ -107,7 +132,7 @@ L99
 R14
 L82";
 
-        assert_eq!(count_zeroes(rotations), 3);
+        assert_eq!(count_zeroes(&parse_rotations(rotations).unwrap()), 3);
     }
 
     #[test]
@@ -135,6 +160,6 @@ L99
 R14
 L82";
 
-        assert_eq!(count_any_zeroes(rotations), 6);
+        assert_eq!(count_any_zeroes(&parse_rotations(rotations).unwrap()), 6);
     }
 }
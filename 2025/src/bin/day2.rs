@@ -1,12 +1,19 @@
+use aoc_common::{
+    Answer, ParseError, Solution,
+    combinators::{comma_list, finish, unsigned},
+    read_input,
+};
 use itertools::Itertools;
+use nom::{IResult, character::complete::char, sequence::separated_pair};
 
 type Id = u64;
 
-fn parse_range(range: &str) -> (Id, Id) {
-    let (lower, upper) = range.split_once('-').expect("range has `-`");
-    let lower: Id = lower.parse().expect("is numeric");
-    let upper: Id = upper.parse().expect("is numeric");
-    (lower, upper)
+fn parse_range(range: &str) -> IResult<&str, (Id, Id)> {
+    separated_pair(unsigned, char('-'), unsigned)(range)
+}
+
+fn parse_ranges(str: &str) -> Result<Vec<(Id, Id)>, ParseError> {
+    finish(comma_list(parse_range), str, "ranges of IDs like '11-22'")
 }
 
 fn is_invalid_dual(id: &str) -> bool {
@@ -20,13 +27,10 @@ fn is_invalid_dual(id: &str) -> bool {
 }
 
 // Part 1
-fn count_invalid_ids(str: &str) -> u64 {
-    str.strip_suffix('\n')
-        .unwrap_or(str)
-        .split(',')
-        .map(|range| -> Id {
-            let (lower, upper) = parse_range(range);
-
+fn count_invalid_ids(ranges: &[(Id, Id)]) -> u64 {
+    ranges
+        .iter()
+        .map(|&(lower, upper)| -> Id {
             (lower..=upper)
                 .filter(|id| is_invalid_dual(&id.to_string()))
                 .sum()
@@ -47,13 +51,10 @@ fn is_invalid_any_amount(id: &str) -> bool {
 }
 
 // Part 2
-fn count_invalid_ids_any_amount(str: &str) -> u64 {
-    str.strip_suffix('\n')
-        .unwrap_or(str)
-        .split(',')
-        .map(|range| -> Id {
-            let (lower, upper) = parse_range(range);
-
+fn count_invalid_ids_any_amount(ranges: &[(Id, Id)]) -> u64 {
+    ranges
+        .iter()
+        .map(|&(lower, upper)| -> Id {
             (lower..=upper)
                 .filter(|id| is_invalid_any_amount(&id.to_string()))
                 .sum()
@@ -61,11 +62,31 @@ fn count_invalid_ids_any_amount(str: &str) -> u64 {
         .sum()
 }
 
-fn main() {
-    let input = include_str!("../../input/day2");
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<(Id, Id)>;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse_ranges(input).map_err(|error| error.locate(input, 2025, 2).into())
+    }
+
+    fn part1(&self, ranges: &Self::Input) -> Option<Answer> {
+        Some(count_invalid_ids(ranges).into())
+    }
+
+    fn part2(&self, ranges: &Self::Input) -> Option<Answer> {
+        Some(count_invalid_ids_any_amount(ranges).into())
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let ranges = Day2.parse(&read_input(2025, 2)?)?;
+
+    println!("Part 1: {}", count_invalid_ids(&ranges));
+    println!("Part 2: {}", count_invalid_ids_any_amount(&ranges));
 
-    println!("Part 1: {}", count_invalid_ids(input));
-    println!("Part 2: {}", count_invalid_ids_any_amount(input));
+    Ok(())
 }
 
 #[cfg(test)]
@@ -84,7 +105,10 @@ mod tests {
         1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,\
         2121212118-2121212124";
 
-        assert_eq!(count_invalid_ids(invalid_ids), 1227775554);
+        assert_eq!(
+            count_invalid_ids(&parse_ranges(invalid_ids).unwrap()),
+            1227775554
+        );
     }
 
     #[test]
@@ -108,6 +132,9 @@ mod tests {
         1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,\
         2121212118-2121212124";
 
-        assert_eq!(count_invalid_ids_any_amount(invalid_ids), 4174379265);
+        assert_eq!(
+            count_invalid_ids_any_amount(&parse_ranges(invalid_ids).unwrap()),
+            4174379265
+        );
     }
 }
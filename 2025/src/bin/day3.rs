@@ -1,3 +1,5 @@
+use aoc_common::{Answer, ParseError, Solution, read_input};
+
 fn maximum_joltage(battery_bank: &str) -> u32 {
     (1..battery_bank.len())
         .map(|pivot| {
@@ -18,14 +20,55 @@ fn maximum_joltage(battery_bank: &str) -> u32 {
         .expect("length of `battery_bank` > 1")
 }
 
-fn total_joltage(battery_banks: &str) -> u32 {
-    battery_banks.lines().map(maximum_joltage).sum()
+fn total_joltage(battery_banks: &[String]) -> u32 {
+    battery_banks
+        .iter()
+        .map(|battery_bank| maximum_joltage(battery_bank))
+        .sum()
 }
 
-fn main() {
-    let input = include_str!("../../input/day3");
+fn parse_battery_banks(battery_banks: &str) -> Result<Vec<String>, ParseError> {
+    battery_banks
+        .lines()
+        .map(|battery_bank| {
+            if let Some((index, c)) = battery_bank
+                .char_indices()
+                .find(|(_, c)| !c.is_ascii_digit())
+            {
+                return Err(ParseError::new(
+                    &battery_bank[index..index + c.len_utf8()],
+                    "a joltage rating",
+                ));
+            }
+            if battery_bank.len() < 2 {
+                return Err(ParseError::new(battery_bank, "at least two batteries"));
+            }
+
+            Ok(battery_bank.to_string())
+        })
+        .collect()
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse_battery_banks(input).map_err(|error| error.locate(input, 2025, 3).into())
+    }
+
+    fn part1(&self, battery_banks: &Self::Input) -> Option<Answer> {
+        Some(total_joltage(battery_banks).into())
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let battery_banks = Day3.parse(&read_input(2025, 3)?)?;
+
+    println!("Part 1: {}", total_joltage(&battery_banks));
 
-    println!("Part 1: {}", total_joltage(input))
+    Ok(())
 }
 
 #[cfg(test)]
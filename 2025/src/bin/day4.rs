@@ -1,44 +1,18 @@
+use aoc_common::{Answer, Grid, ParseError, Point2, Solution, read_input};
+
 type Coordinate = (i32, i32);
 
-struct Map {
-    map: String,
-    height: usize,
-    width: usize,
-}
+pub struct Map(Grid<char>);
 
 impl Map {
-    pub fn new(map: String) -> Self {
-        let height = map.lines().count();
-        let width = map
-            .chars()
-            .position(|position| position == '\n')
-            .expect("has multiple lines");
-
-        Self { map, height, width }
-    }
-
-    fn get(&self, (x, y): Coordinate) -> Option<char> {
-        let (x, y) = (x.try_into().ok()?, y.try_into().ok()?);
-        let line = self.map.lines().nth(y)?;
+    pub fn parse(map: &str) -> Result<Self, ParseError> {
+        let grid = Grid::try_parse(map, "'@' or '.'", |c| matches!(c, '@' | '.').then_some(c))?;
 
-        line.chars().nth(x)
+        Ok(Self(grid))
     }
 
-    fn is_occupied(&self, position: Coordinate) -> Option<bool> {
-        Some(self.get(position).is_some_and(|x| x != '.'))
-    }
-
-    fn get_neighbour_positions(&self, (x, y): Coordinate) -> [Coordinate; 8] {
-        [
-            (x + 1, y),
-            (x + 1, y + 1),
-            (x, y + 1),
-            (x - 1, y + 1),
-            (x - 1, y),
-            (x - 1, y - 1),
-            (x, y - 1),
-            (x + 1, y - 1),
-        ]
+    fn is_roll(&self, position: Point2<i32>) -> bool {
+        self.0.get(position) == Some(&'@')
     }
 
     // ..@@.@@@@.
@@ -63,42 +37,44 @@ impl Map {
     // .@@@@@@@@.
     // x.x.@@@.x.
 
-    fn is_accessible(&self, position: Coordinate) -> bool {
-        let neighbours = self.get_neighbour_positions(position);
-        let occupied_neighbours = neighbours.iter().filter(|&&neighbour| {
-            self.is_occupied(neighbour)
-                .is_some_and(|is_occupied| is_occupied)
-        });
+    fn is_accessible(&self, (x, y): Coordinate) -> bool {
+        let occupied_neighbours = self
+            .0
+            .neighbours8(Point2::new(x, y))
+            .filter(|&neighbour| self.0[neighbour] != '.');
 
         occupied_neighbours.count() < 4
     }
 
     fn count_accessible_rolls(&self) -> usize {
-        let accessible_rolls = (0..self.height).flat_map(|y| {
-            (0..self.width).map(move |x| {
-                // Converting i32 coordinates to usize
-                let (x, y) = match (x.try_into(), y.try_into()) {
-                    (Ok(x), Ok(y)) => (x, y),
-                    _ => return false,
-                };
-
-                if self.get((x, y)).is_some_and(|position| position != '@') {
-                    // The position is not a roll
-                    return false;
-                }
-
-                self.is_accessible((x, y))
-            })
-        });
-        accessible_rolls.filter(|&position| position).count()
+        self.0
+            .positions()
+            .filter(|&position| self.is_roll(position))
+            .filter(|position| self.is_accessible((position.x, position.y)))
+            .count()
+    }
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Map;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        Map::parse(input).map_err(|error| error.locate(input, 2025, 4).into())
+    }
+
+    fn part1(&self, map: &Self::Input) -> Option<Answer> {
+        Some(map.count_accessible_rolls().into())
     }
 }
 
-fn main() {
-    let input = include_str!("../../input/day4");
-    let map = Map::new(input.to_string());
+fn main() -> anyhow::Result<()> {
+    let map = Day4.parse(&read_input(2025, 4)?)?;
 
     println!("Part 1: {}", map.count_accessible_rolls());
+
+    Ok(())
 }
 
 #[cfg(test)]
@@ -118,14 +94,14 @@ mod tests {
 
     #[test]
     fn initializes_map() {
-        let map = Map::new(MAP.to_string());
-        assert_eq!(map.height, 10);
-        assert_eq!(map.width, 10);
+        let map = Map::parse(MAP).unwrap();
+        assert_eq!(map.0.height(), 10);
+        assert_eq!(map.0.width(), 10);
     }
 
     #[test]
     fn validates_accessible_position() {
-        let map = Map::new(MAP.to_string());
+        let map = Map::parse(MAP).unwrap();
 
         let accessible_positions = [
             (2, 0),
@@ -151,7 +127,7 @@ mod tests {
 
     #[test]
     fn counts_accessible_rolls() {
-        let map = Map::new(MAP.to_string());
+        let map = Map::parse(MAP).unwrap();
 
         assert_eq!(map.count_accessible_rolls(), 13);
     }
@@ -1,51 +1,50 @@
+use aoc_common::{
+    Answer, ParseError, Solution,
+    combinators::{blank_line, finish, lines, unsigned},
+    read_input,
+};
+use nom::{IResult, character::complete::char, combinator::map, sequence::separated_pair};
 use std::ops;
 
 type Id = u64;
+type Inventory = (Vec<ops::RangeInclusive<Id>>, Vec<Id>);
 
-fn parse_available_ids(available_ids: &str) -> impl Iterator<Item = Id> {
-    available_ids
-        .lines()
-        .map(|id| id.parse().expect("is numeric"))
+fn parse_available_ids(available_ids: &str) -> IResult<&str, Vec<Id>> {
+    lines(unsigned)(available_ids)
 }
 
-fn parse_fresh_id_ranges(fresh_id_ranges: &str) -> impl Iterator<Item = ops::RangeInclusive<u64>> {
-    fresh_id_ranges.lines().map(|line| {
-        let (lower, upper) = line.split_once('-').expect("range separator exists");
-        let lower: Id = lower.parse().expect("is numeric");
-        let upper: Id = upper.parse().expect("is numeric");
-
-        lower..=upper
-    })
+fn parse_fresh_id_ranges(fresh_id_ranges: &str) -> IResult<&str, Vec<ops::RangeInclusive<u64>>> {
+    let range = separated_pair(unsigned, char('-'), unsigned);
+    lines(map(range, |(lower, upper)| lower..=upper))(fresh_id_ranges)
 }
 
-fn parse_input(
-    str: &str,
-) -> (
-    impl Iterator<Item = ops::RangeInclusive<Id>>,
-    impl Iterator<Item = Id>,
-) {
-    let (ranges, ids) = str.split_once("\n\n").expect("separator dexists");
-    (parse_fresh_id_ranges(ranges), parse_available_ids(ids))
+fn parse_input(str: &str) -> Result<Inventory, ParseError> {
+    finish(
+        separated_pair(parse_fresh_id_ranges, blank_line, parse_available_ids),
+        str,
+        "ranges of fresh IDs, a blank line and then available IDs",
+    )
 }
 
 mod part1 {
-    pub fn count_available_ids(str: &str) -> usize {
-        let (fresh_id_ranges, available_ids) = crate::parse_input(str);
-        // We collect here because we need to iterate multiple times over `fresh_id_ranges`
-        let fresh_id_ranges: Vec<_> = fresh_id_ranges.collect();
+    use super::Inventory;
+
+    pub fn count_available_ids((fresh_id_ranges, available_ids): &Inventory) -> usize {
         available_ids
+            .iter()
             .filter(|id| fresh_id_ranges.iter().any(|range| range.contains(id)))
             .count()
     }
 }
 
 mod part2 {
-    use crate::Id;
+    use super::{Id, Inventory};
     use std::collections::HashSet;
 
-    pub fn count_fresh_ids(str: &str) -> usize {
-        let (fresh_id_ranges, _) = crate::parse_input(str);
+    pub fn count_fresh_ids((fresh_id_ranges, _): &Inventory) -> usize {
         fresh_id_ranges
+            .iter()
+            .cloned()
             .fold(HashSet::<Id>::new(), |acc, range| {
                 // TODO: try using itertools' unique() instead to avoid allocating
                 range.collect::<HashSet<_>>().union(&acc).cloned().collect()
@@ -54,11 +53,31 @@ mod part2 {
     }
 }
 
-fn main() {
-    let input = include_str!("../../input/day5");
-    println!("Part 1: {}", part1::count_available_ids(input));
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Inventory;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input).map_err(|error| error.locate(input, 2025, 5).into())
+    }
+
+    fn part1(&self, inventory: &Self::Input) -> Option<Answer> {
+        Some(part1::count_available_ids(inventory).into())
+    }
+
+    fn part2(&self, inventory: &Self::Input) -> Option<Answer> {
+        Some(part2::count_fresh_ids(inventory).into())
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let inventory = Day5.parse(&read_input(2025, 5)?)?;
+    println!("Part 1: {}", part1::count_available_ids(&inventory));
+
+    println!("Part 2: {}", part2::count_fresh_ids(&inventory));
 
-    println!("Part 2: {}", part2::count_fresh_ids(input));
+    Ok(())
 }
 
 #[cfg(test)]
@@ -80,7 +99,12 @@ mod tests {
 
         #[test]
         fn counts_available_ids() {
-            assert_eq!(crate::part1::count_available_ids(INPUT), 3);
+            assert_eq!(
+                super::super::part1::count_available_ids(
+                    &super::super::parse_input(INPUT).unwrap()
+                ),
+                3
+            );
         }
     }
 
@@ -89,7 +113,10 @@ mod tests {
 
         #[test]
         fn counts_available_ids() {
-            assert_eq!(crate::part2::count_fresh_ids(INPUT), 14);
+            assert_eq!(
+                super::super::part2::count_fresh_ids(&super::super::parse_input(INPUT).unwrap()),
+                14
+            );
         }
     }
 }
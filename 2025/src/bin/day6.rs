@@ -1,3 +1,5 @@
+use aoc_common::{Answer, ParseError, Solution, read_input};
+
 mod part1 {
     pub fn solve_worksheet(worksheet: &str) -> u64 {
         let worksheet = worksheet.lines().map(|line| line.split_whitespace());
@@ -26,9 +28,63 @@ mod part1 {
     }
 }
 
-fn main() {
-    let input = include_str!("../../input/day6");
+/// Checks that the worksheet has rows of numbers above a row of operators,
+/// with the same number of columns in each row
+fn check_worksheet(worksheet: &str) -> Result<(), ParseError> {
+    let mut lines = worksheet.lines();
+    let operators = lines
+        .next_back()
+        .ok_or_else(|| ParseError::end_of(worksheet, "a row of operators"))?;
+    let width = operators.split_whitespace().count();
+
+    for line in lines {
+        check_row(line, width, "a number", |c| c.is_ascii_digit())?;
+    }
+    check_row(operators, width, "'+' or '*'", |c| matches!(c, '+' | '*'))
+}
+
+fn check_row(
+    line: &str,
+    width: usize,
+    expected: &str,
+    is_valid: impl Fn(char) -> bool,
+) -> Result<(), ParseError> {
+    if let Some((index, c)) = line.char_indices().find(|&(_, c)| c != ' ' && !is_valid(c)) {
+        return Err(ParseError::new(
+            &line[index..index + c.len_utf8()],
+            expected,
+        ));
+    }
+    if line.split_whitespace().count() != width {
+        return Err(ParseError::new(line, format!("a row of {width} columns")));
+    }
+
+    Ok(())
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    /// The worksheet's problems are laid out in columns of text, so it is
+    /// kept as is
+    type Input = String;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        check_worksheet(input).map_err(|error| error.locate(input, 2025, 6))?;
+
+        Ok(input.into())
+    }
+
+    fn part1(&self, worksheet: &Self::Input) -> Option<Answer> {
+        Some(part1::solve_worksheet(worksheet).into())
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let input = &Day6.parse(&read_input(2025, 6)?)?;
     println!("Part 1: {}", part1::solve_worksheet(input));
+
+    Ok(())
 }
 
 #[cfg(test)]
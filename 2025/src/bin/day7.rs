@@ -1,8 +1,10 @@
+use aoc_common::{Answer, ParseError, Solution, read_input};
+
 mod part1 {
     use std::collections::HashSet;
 
-    pub fn count_splits(str: &str) -> u32 {
-        let mut lines = str.lines();
+    pub fn count_splits(manifold: &[String]) -> u32 {
+        let mut lines = manifold.iter();
         let first_line = lines.next().expect("has lines");
         let start_index = first_line
             .chars()
@@ -32,35 +34,70 @@ mod part1 {
     }
 }
 
-fn main() {
-    let input = include_str!("../../input/day7");
+pub struct Day7;
+
+fn parse_manifold(manifold: &str) -> Result<Vec<String>, ParseError> {
+    let mut lines = manifold.lines();
+    let first_line = lines
+        .next()
+        .ok_or_else(|| ParseError::end_of(manifold, "a manifold"))?;
+    if !first_line.contains('S') {
+        return Err(ParseError::new(first_line, "a starting point 'S'"));
+    }
+
+    for line in manifold.lines() {
+        if let Some((index, c)) = line
+            .char_indices()
+            .find(|&(_, c)| !matches!(c, '.' | 'S' | '^'))
+        {
+            return Err(ParseError::new(
+                &line[index..index + c.len_utf8()],
+                "'.', 'S' or '^'",
+            ));
+        }
+        if line.len() != first_line.len() {
+            return Err(ParseError::new(
+                line,
+                format!("a line of {} characters", first_line.len()),
+            ));
+        }
+    }
+
+    Ok(manifold.lines().map(String::from).collect())
+}
+
+impl Solution for Day7 {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse_manifold(input).map_err(|error| error.locate(input, 2025, 7).into())
+    }
+
+    fn part1(&self, manifold: &Self::Input) -> Option<Answer> {
+        Some(part1::count_splits(manifold).into())
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let manifold = Day7.parse(&read_input(2025, 7)?)?;
 
-    println!("Part 1: {}", part1::count_splits(input));
+    println!("Part 1: {}", part1::count_splits(&manifold));
+
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_common::{example, example_answer};
 
     #[test]
     fn test() {
-        let input = ".......S.......
-...............
-.......^.......
-...............
-......^.^......
-...............
-.....^.^.^.....
-...............
-....^.^...^....
-...............
-...^.^...^.^...
-...............
-..^...^.....^..
-...............
-.^.^.^.^.^...^.
-...............";
-
-        assert_eq!(part1::count_splits(input), 21);
+        let input = example(2025, 7, 1);
+
+        assert_eq!(
+            part1::count_splits(&parse_manifold(&input).unwrap()).to_string(),
+            example_answer(2025, 7, 1)
+        );
     }
 }
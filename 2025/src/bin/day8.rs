@@ -1,162 +1,57 @@
-#![feature(core_float_math)]
-
-use core::f32::math::{powi, sqrt};
+use anyhow::Context;
+use aoc_common::{Answer, ParseError, Point3, Solution, read_input};
 use itertools::Itertools;
-use std::{collections::BTreeSet, hash::Hash, ops::Sub};
-
-#[derive(Clone, PartialEq, Eq, Hash)]
-// We use a `BTreeSet` here to get Hash and Eq for free. Apparently, `HashSet`
-// doesn't implement `Hash`.
-struct Pair(BTreeSet<Coordinate>);
-
-impl Pair {
-    fn connects_with(&self, pair: &Pair) -> bool {
-        self.0.intersection(&pair.0).count() > 0
-    }
-}
-
-impl From<(Coordinate, Coordinate)> for Pair {
-    fn from(pair: (Coordinate, Coordinate)) -> Self {
-        Pair(BTreeSet::from([pair.0, pair.1]))
-    }
-}
-
-impl<'a> From<&'a Pair> for (&'a Coordinate, &'a Coordinate) {
-    fn from(pair: &'a Pair) -> Self {
-        pair.0.iter().collect_tuple().expect("has two elements")
-    }
-}
-
-impl std::fmt::Debug for Pair {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let (c1, c2) = self.into();
-        write!(f, "{c1:?} - {c2:?}")
-    }
-}
-
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Hash, Ord)]
-struct Coordinate(u32, u32, u32);
-
-impl Coordinate {
-    fn distance_to(&self, coordinate: Coordinate) -> f32 {
-        fn square(n: f32) -> f32 {
-            powi(n, 2)
-        }
-
-        sqrt(
-            square(coordinate.0 as f32 - self.0 as f32)
-                + square(coordinate.1 as f32 - self.1 as f32)
-                + square(coordinate.2 as f32 - self.2 as f32),
-        )
-    }
-}
-
-impl std::fmt::Debug for Coordinate {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "({}, {}, {})", self.0, self.1, self.2)
-    }
-}
 
-impl Sub for Coordinate {
-    type Output = Coordinate;
+type Coordinate = Point3<u64>;
 
-    fn sub(self, Coordinate(x, y, z): Self) -> Self::Output {
-        Coordinate(self.0 - x, self.1 - y, self.2 - z)
-    }
-}
+/// How many of the closest pairs part 1 connects
+const CONNECTIONS: usize = 1000;
 
-impl From<&str> for Coordinate {
-    fn from(value: &str) -> Self {
-        let (x, y, z) = value
-            .split(',')
-            .map(|n| n.parse().expect("is numeric"))
-            .collect_tuple()
-            .expect("has three numbers");
-        Coordinate(x, y, z)
-    }
+/// Every pair of coordinates as their indices in `coordinates`, sorted closest
+/// to furthest
+fn sorted_coordinate_pairs(coordinates: &[Coordinate]) -> Vec<(usize, usize)> {
+    (0..coordinates.len())
+        .tuple_combinations()
+        .sorted_by_key(|&(c1, c2)| coordinates[c1].squared_distance(coordinates[c2]))
+        .collect()
 }
 
-fn flatten_circuits(circuits: Vec<Vec<Pair>>) -> impl Iterator<Item = BTreeSet<Coordinate>> {
-    circuits.into_iter().map(|circuit| {
-        circuit.iter().fold(BTreeSet::new(), |acc, pair| {
-            let (&c1, &c2) = pair.into();
-            acc.into_iter().chain([c1, c2]).collect()
+fn parse(str: &str) -> Result<Vec<Coordinate>, ParseError> {
+    str.lines()
+        .map(|line| {
+            line.parse()
+                .map_err(|_| ParseError::new(line, "three comma separated numbers"))
         })
-    })
-}
-
-fn parse(str: &str) -> impl Iterator<Item = Coordinate> + Clone {
-    str.lines().map(Into::into)
+        .collect()
 }
 
 mod part1 {
-    use crate::{Coordinate, Pair};
+    use super::Coordinate;
+    use aoc_common::union_find::UnionFind;
     use itertools::Itertools;
-    use std::collections::BTreeSet;
-
-    /// Sorted closest to furthest coordinate pairs
-    pub(crate) fn sorted_coordinate_pairs(
-        coordinates: impl Iterator<Item = Coordinate>,
-    ) -> impl Iterator<Item = Pair> {
-        coordinates
-            .permutations(2)
-            .map(|permutation| {
-                let (c1, c2) = permutation
-                    .into_iter()
-                    .collect_tuple()
-                    .expect("contains two coordinates");
-                let distance = c1.distance_to(c2);
-                (c1, c2, distance)
-            })
-            .sorted_by(|(_, _, d1), (_, _, d2)| f32::total_cmp(d1, d2))
-            .map(|(c1, c2, _)| Pair::from((c1, c2)))
-            .unique() // Remove pair inversions
-    }
 
-    fn connect_n_closest_coordinates(
-        coordinates: impl Iterator<Item = Coordinate>,
-        n: usize,
-    ) -> impl Iterator<Item = BTreeSet<Coordinate>> {
-        let mut pairs = sorted_coordinate_pairs(coordinates);
-        let mut circuits: Vec<Vec<Pair>> = vec![];
-
-        for pair in pairs.by_ref().take(n) {
-            let mut matching_circuits = circuits
-                .clone()
-                .into_iter()
-                .enumerate()
-                .filter(|(_, circuit)| circuit.iter().any(|p| pair.connects_with(p)));
-
-            let Some(connecting_circuit1) = matching_circuits.next() else {
-                // Doesn't connect with any of the circuits
-                circuits.push(vec![pair]);
-                continue;
-            };
-
-            match matching_circuits.next() {
-                None => {
-                    // Connects with one of the circuits
-                    circuits[connecting_circuit1.0].push(pair);
-                    continue;
-                }
-                Some(connecting_circuit2) => {
-                    // Connects two different circuits
-                    let connecting_circuit2 = circuits.swap_remove(connecting_circuit2.0);
-                    let new_circuit = connecting_circuit1.1.into_iter().chain(connecting_circuit2);
-
-                    circuits[connecting_circuit1.0] = new_circuit.collect();
-                }
-            };
+    /// The circuits that the `n` closest pairs of coordinates connect
+    pub(crate) fn connect_n_closest_coordinates(coordinates: &[Coordinate], n: usize) -> UnionFind {
+        let mut circuits = UnionFind::new(coordinates.len());
+        for (c1, c2) in super::sorted_coordinate_pairs(coordinates)
+            .into_iter()
+            .take(n)
+        {
+            circuits.union(c1, c2);
         }
 
-        crate::flatten_circuits(circuits)
+        circuits
     }
 
-    pub fn multiply_three_largest_circuits(input: &str) -> usize {
-        let coordinates = crate::parse(input);
-        let circuits = connect_n_closest_coordinates(coordinates, 1000);
+    pub fn multiply_three_largest_circuits(
+        coordinates: &[Coordinate],
+        connections: usize,
+    ) -> usize {
+        let mut circuits = connect_n_closest_coordinates(coordinates, connections);
 
         circuits
+            .groups()
+            .iter()
             .map(|circuit| circuit.len())
             .sorted_by(|len1, len2| Ord::cmp(len2, len1)) // descending order
             .take(3)
@@ -165,83 +60,61 @@ mod part1 {
 }
 
 mod part2 {
-    use crate::{Coordinate, Pair};
+    use super::Coordinate;
+    use aoc_common::union_find::UnionFind;
 
+    /// The pair of coordinates that joins everything into a single circuit,
+    /// as their indices in `coordinates`
     fn get_last_pair_to_connect_all_coordinates(
-        coordinates: impl Iterator<Item = Coordinate> + Clone,
-    ) -> Pair {
-        let pairs = crate::part1::sorted_coordinate_pairs(coordinates.clone());
-        let mut circuits: Vec<Vec<Pair>> = vec![];
-        let mut latest_pair = None;
-
-        for pair in pairs {
-            // TODO: this might not actually be the last pair
-            if has_connected_all_coordinates(coordinates.clone(), &circuits) {
-                return latest_pair.expect("`pairs` is non-empty");
-            }
-
-            let mut matching_circuits = circuits
-                .clone()
-                .into_iter()
-                .enumerate()
-                .filter(|(_, circuit)| circuit.iter().any(|p| pair.connects_with(p)));
-
-            let Some(connecting_circuit1) = matching_circuits.next() else {
-                // Doesn't connect with any of the circuits
-                circuits.push(vec![pair.clone()]);
-                latest_pair = Some(pair);
-                continue;
-            };
-
-            match matching_circuits.next() {
-                None => {
-                    // Connects with one of the circuits
-                    circuits[connecting_circuit1.0].push(pair.clone());
-                    latest_pair = Some(pair);
-                    continue;
-                }
-                Some(connecting_circuit2) => {
-                    // Connects two different circuits
-                    let connecting_circuit2 = circuits.swap_remove(connecting_circuit2.0);
-                    let new_circuit = connecting_circuit1.1.into_iter().chain(connecting_circuit2);
-
-                    circuits[connecting_circuit1.0] = new_circuit.collect();
-                    latest_pair = Some(pair);
-                }
-            };
-        }
+        coordinates: &[Coordinate],
+    ) -> Option<(usize, usize)> {
+        let mut circuits = UnionFind::new(coordinates.len());
+
+        super::sorted_coordinate_pairs(coordinates)
+            .into_iter()
+            .find(|&(c1, c2)| circuits.union(c1, c2) && circuits.components() == 1)
+    }
 
-        unreachable!()
+    /// `None` if there are fewer than two coordinates to connect
+    pub fn last_pair_x_coordinate_product(coordinates: &[Coordinate]) -> Option<u128> {
+        let (c1, c2) = get_last_pair_to_connect_all_coordinates(coordinates)?;
+
+        Some(coordinates[c1].x as u128 * coordinates[c2].x as u128)
     }
+}
 
-    fn has_connected_all_coordinates(
-        coordinates: impl Iterator<Item = Coordinate> + Clone,
-        circuits: &[Vec<Pair>],
-    ) -> bool {
-        let Some(circuits) = crate::flatten_circuits(circuits.to_vec()).next() else {
-            return false;
-        };
+pub struct Day8;
 
-        let length = circuits.len();
-        println!("Connected circuits: {length}");
+impl Solution for Day8 {
+    type Input = Vec<Coordinate>;
 
-        coordinates.count() == length
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse(input).map_err(|error| error.locate(input, 2025, 8).into())
     }
 
-    pub fn last_pair_x_coordinate_product(input: &str) -> u128 {
-        let coordinates = crate::parse(input);
-        let pair = get_last_pair_to_connect_all_coordinates(coordinates);
-        let (c1, c2) = (&pair).into();
+    fn part1(&self, coordinates: &Self::Input) -> Option<Answer> {
+        Some(part1::multiply_three_largest_circuits(coordinates, CONNECTIONS).into())
+    }
 
-        c1.0 as u128 * c2.0 as u128
+    fn part2(&self, coordinates: &Self::Input) -> Option<Answer> {
+        part2::last_pair_x_coordinate_product(coordinates).map(Into::into)
     }
 }
 
-fn main() {
-    let input = include_str!("../../input/day8");
+fn main() -> anyhow::Result<()> {
+    let coordinates = Day8.parse(&read_input(2025, 8)?)?;
 
-    println!("Part 1: {}", part1::multiply_three_largest_circuits(input));
-    println!("Part 2: {}", part2::last_pair_x_coordinate_product(input));
+    println!(
+        "Part 1: {}",
+        part1::multiply_three_largest_circuits(&coordinates, CONNECTIONS)
+    );
+    println!(
+        "Part 2: {}",
+        part2::last_pair_x_coordinate_product(&coordinates)
+            .context("there have to be at least two junction boxes to connect")?
+    );
+
+    Ok(())
 }
 
 #[cfg(test)]
@@ -271,21 +144,11 @@ mod tests {
 
     #[test]
     fn gets_distance_between_coordinates() {
-        let c1: Coordinate = "162,817,812".into();
-        let c2: Coordinate = "425,690,689".into();
-
-        assert_eq!(c1.distance_to(c2), 316.9022);
-    }
+        let c1: Coordinate = "162,817,812".parse().unwrap();
+        let c2: Coordinate = "425,690,689".parse().unwrap();
 
-    #[test]
-    fn pair_equality() {
-        let c1: Coordinate = "162,817,812".into();
-        let c2: Coordinate = "425,690,689".into();
-        let p1 = Pair::from((c1, c2));
-        let p2 = Pair::from((c2, c1));
-
-        assert_eq!(p1, p2);
-        assert!([p1].contains(&p2));
+        // 316.9022 squared
+        assert_eq!(c1.squared_distance(c2), 100_427);
     }
 
     #[test]
@@ -296,22 +159,47 @@ mod tests {
             ("906,360,560", "805,96,715"),
             ("431,825,988", "425,690,689"),
         ]
-        .map(|(c1, c2)| Pair::from((c1.into(), c2.into())));
+        .map(|(c1, c2)| (c1.parse().unwrap(), c2.parse().unwrap()));
 
-        let coordinates = crate::parse(INPUT);
-        let pairs = part1::sorted_coordinate_pairs(coordinates).take(4);
+        let coordinates = super::parse(INPUT).unwrap();
+        let pairs = sorted_coordinate_pairs(&coordinates)
+            .into_iter()
+            .take(4)
+            .map(|(c1, c2)| (coordinates[c1], coordinates[c2]));
 
         assert!(itertools::equal(pairs, expected_pairs));
     }
 
     #[test]
-    fn connects_with() {
-        let p1 = Pair::from(("162,817,812".into(), "425,690,689".into()));
-        let p2 = Pair::from(("162,817,812".into(), "431,825,988".into()));
-        let p3 = Pair::from(("906,360,560".into(), "805,96,715".into()));
-
-        assert!(p1.connects_with(&p2));
-        assert!(!p1.connects_with(&p3));
-        assert!(!p2.connects_with(&p3));
+    fn connects_closest_coordinates_into_circuits() {
+        let coordinates = super::parse(INPUT).unwrap();
+        let mut circuits = part1::connect_n_closest_coordinates(&coordinates, 10);
+
+        assert_eq!(circuits.components(), 11);
+        assert_eq!(
+            circuits.groups().iter().map(|circuit| circuit.len()).max(),
+            Some(5)
+        );
+    }
+
+    #[test]
+    fn multiplies_three_largest_circuits() {
+        let coordinates = super::parse(INPUT).unwrap();
+
+        assert_eq!(part1::multiply_three_largest_circuits(&coordinates, 10), 40);
+    }
+
+    #[test]
+    fn multiplies_x_coordinates_of_last_pair() {
+        let coordinates = super::parse(INPUT).unwrap();
+
+        assert_eq!(
+            part2::last_pair_x_coordinate_product(&coordinates),
+            Some(25_272)
+        );
+        assert_eq!(
+            part2::last_pair_x_coordinate_product(&coordinates[..1]),
+            None
+        );
     }
 }
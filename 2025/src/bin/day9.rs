@@ -1,16 +1,17 @@
+use aoc_common::{
+    Answer, ParseError, Solution,
+    combinators::{finish, lines, unsigned},
+    read_input,
+};
 use itertools::Itertools;
+use nom::{character::complete::char, sequence::separated_pair};
 
 type Coordinate = (u32, u32);
 type Area = u64;
 
-fn parse(input: &str) -> impl Iterator<Item = Coordinate> {
-    input.lines().map(|line| {
-        let (x, y) = line.split_once(',').expect("has comma");
-        let x = x.parse().expect("is numeric");
-        let y = y.parse().expect("is numeric");
-
-        (x, y)
-    })
+fn parse(input: &str) -> Result<Vec<Coordinate>, ParseError> {
+    let coordinate = separated_pair(unsigned, char(','), unsigned);
+    finish(lines(coordinate), input, "a coordinate like '7,1'")
 }
 
 fn get_rectangle_area(corner1: &Coordinate, corner2: &Coordinate) -> Area {
@@ -20,19 +21,35 @@ fn get_rectangle_area(corner1: &Coordinate, corner2: &Coordinate) -> Area {
     (x_distance as Area + 1) * (y_distance as Area + 1)
 }
 
-fn get_largest_rectangle_area(input: &str) -> Area {
-    let rectangle_areas = parse(input).permutations(2).map(|pair| {
-        let (c1, c2) = pair.iter().collect_tuple().expect("is pair");
+fn get_largest_rectangle_area(corners: &[Coordinate]) -> Area {
+    let rectangle_areas = corners.iter().permutations(2).map(|pair| {
+        let (c1, c2) = pair.into_iter().collect_tuple().expect("is pair");
         get_rectangle_area(c1, c2)
     });
 
     rectangle_areas.max().expect("iterator is non-empty")
 }
 
-pub fn main() {
-    let input = include_str!("../../input/day9");
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<Coordinate>;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse(input).map_err(|error| error.locate(input, 2025, 9).into())
+    }
+
+    fn part1(&self, corners: &Self::Input) -> Option<Answer> {
+        Some(get_largest_rectangle_area(corners).into())
+    }
+}
+
+pub fn main() -> anyhow::Result<()> {
+    let corners = Day9.parse(&read_input(2025, 9)?)?;
 
-    println!("Part 1: {}", get_largest_rectangle_area(input));
+    println!("Part 1: {}", get_largest_rectangle_area(&corners));
+
+    Ok(())
 }
 
 #[cfg(test)]
@@ -49,6 +66,8 @@ mod tests {
 2,5
 2,3
 7,3";
-        assert_eq!(get_largest_rectangle_area(input), 50);
+        let corners = parse(input).unwrap();
+
+        assert_eq!(get_largest_rectangle_area(&corners), 50);
     }
 }
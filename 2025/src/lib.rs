@@ -0,0 +1,38 @@
+//! Every solved 2025 day, registered for the `aoc` runner. Each day also
+//! builds as its own binary from `src/bin`.
+
+use aoc_common::{Day, Year};
+
+#[path = "bin/day1.rs"]
+pub mod day1;
+#[path = "bin/day2.rs"]
+pub mod day2;
+#[path = "bin/day3.rs"]
+pub mod day3;
+#[path = "bin/day4.rs"]
+pub mod day4;
+#[path = "bin/day5.rs"]
+pub mod day5;
+#[path = "bin/day6.rs"]
+pub mod day6;
+#[path = "bin/day7.rs"]
+pub mod day7;
+#[path = "bin/day8.rs"]
+pub mod day8;
+#[path = "bin/day9.rs"]
+pub mod day9;
+
+pub const YEAR: Year = Year {
+    year: 2025,
+    days: &[
+        Day::new(1, &day1::Day1),
+        Day::new(2, &day2::Day2),
+        Day::new(3, &day3::Day3),
+        Day::new(4, &day4::Day4),
+        Day::new(5, &day5::Day5),
+        Day::new(6, &day6::Day6),
+        Day::new(7, &day7::Day7),
+        Day::new(8, &day8::Day8),
+        Day::new(9, &day9::Day9),
+    ],
+};
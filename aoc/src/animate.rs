@@ -0,0 +1,234 @@
+use aoc_common::Frames;
+use crossterm::{
+    cursor::{Hide, MoveTo, MoveToNextLine, Show},
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    execute, queue,
+    style::Print,
+    terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use std::{
+    io::{self, Write},
+    time::Duration,
+};
+
+/// How many ticks `[` and `]` seek by
+const SEEK: usize = 100;
+const MAX_FPS: u32 = 1000;
+
+/// Which tick of a simulation is shown and how it is being played
+pub struct Player {
+    frames: Frames,
+    tick: usize,
+    playing: bool,
+    fps: u32,
+}
+
+impl Player {
+    pub fn new(frames: Frames, fps: u32) -> Self {
+        Self {
+            frames,
+            tick: 0,
+            playing: true,
+            fps: fps.clamp(1, MAX_FPS),
+        }
+    }
+
+    fn seek(&mut self, tick: usize) {
+        let (reached, _) = self.frames.get(tick);
+        self.tick = reached;
+    }
+
+    /// Shows the next tick while playing, or pauses if there are no more
+    fn advance(&mut self) {
+        let next = self.tick + 1;
+        self.seek(next);
+        if self.tick < next {
+            self.playing = false;
+        }
+    }
+
+    /// Acts on `key`, or returns `false` if it quits
+    fn handle(&mut self, key: KeyEvent) -> bool {
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return false,
+            KeyCode::Char(' ') => self.playing = !self.playing,
+            KeyCode::Right | KeyCode::Char('l') => {
+                self.playing = false;
+                self.seek(self.tick + 1);
+            }
+            KeyCode::Left | KeyCode::Char('h') => {
+                self.playing = false;
+                self.seek(self.tick.saturating_sub(1));
+            }
+            KeyCode::Char(']') => self.seek(self.tick + SEEK),
+            KeyCode::Char('[') => self.seek(self.tick.saturating_sub(SEEK)),
+            KeyCode::Home => self.seek(0),
+            KeyCode::Char('+' | '=') => self.fps = (self.fps * 2).min(MAX_FPS),
+            KeyCode::Char('-') => self.fps = (self.fps / 2).max(1),
+            _ => {}
+        }
+        true
+    }
+
+    fn status(&self) -> String {
+        let state = match (self.playing, self.frames.last()) {
+            (_, Some(last)) if last == self.tick => "finished",
+            (true, _) => "playing",
+            (false, _) => "paused",
+        };
+
+        format!(
+            "tick {}, {state} at {} fps | space: play/pause, ←/→: step, [/]: seek {SEEK}, +/-: speed, q: quit",
+            self.tick, self.fps
+        )
+    }
+
+    /// Draws the current frame, cropped to `width` by `height` characters,
+    /// with the status on the line below it
+    fn draw(&mut self, out: &mut impl Write, (width, height): (u16, u16)) -> io::Result<()> {
+        let status = self.status();
+        let (_, frame) = self.frames.get(self.tick);
+
+        queue!(out, MoveTo(0, 0), Clear(ClearType::All))?;
+        for line in frame.lines().take(usize::from(height.saturating_sub(1))) {
+            let line: String = line.chars().take(usize::from(width)).collect();
+            queue!(out, Print(line), MoveToNextLine(1))?;
+        }
+        let status: String = status.chars().take(usize::from(width)).collect();
+        queue!(out, MoveTo(0, height.saturating_sub(1)), Print(status))?;
+
+        out.flush()
+    }
+}
+
+/// The terminal in raw mode on its alternate screen until dropped, so that
+/// frames are redrawn in place and keys are read as they're pressed
+struct Screen;
+
+impl Screen {
+    fn enter() -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        execute!(io::stdout(), EnterAlternateScreen, Hide)?;
+        Ok(Self)
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        // Nothing can be done about failing to restore the terminal
+        let _ = execute!(io::stdout(), LeaveAlternateScreen, Show);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+/// Plays `player` in the terminal until it's quit
+pub fn play(mut player: Player) -> anyhow::Result<()> {
+    let _screen = Screen::enter()?;
+    let mut out = io::stdout();
+
+    loop {
+        player.draw(&mut out, terminal::size()?)?;
+
+        let frame = Duration::from_secs(1) / player.fps;
+        if player.playing && !event::poll(frame)? {
+            player.advance();
+            continue;
+        }
+
+        if let Event::Key(key) = event::read()? {
+            if key.kind == KeyEventKind::Press && !player.handle(key) {
+                return Ok(());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Simulate;
+    use std::fmt;
+
+    struct Counter(usize);
+
+    impl fmt::Display for Counter {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "count {}\nline 2", self.0)
+        }
+    }
+
+    impl Simulate for Counter {
+        fn tick(&mut self) -> bool {
+            if self.0 == 150 {
+                return false;
+            }
+            self.0 += 1;
+            true
+        }
+    }
+
+    fn player() -> Player {
+        Player::new(Frames::new(Box::new(Counter(0))), 10)
+    }
+
+    fn press(player: &mut Player, code: KeyCode) -> bool {
+        player.handle(KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    #[test]
+    fn steps_and_seeks() {
+        let mut player = player();
+
+        press(&mut player, KeyCode::Right);
+        assert_eq!((player.tick, player.playing), (1, false));
+        press(&mut player, KeyCode::Char(']'));
+        assert_eq!(player.tick, 101);
+        press(&mut player, KeyCode::Char(']'));
+        assert_eq!(player.tick, 150);
+        press(&mut player, KeyCode::Left);
+        assert_eq!(player.tick, 149);
+        press(&mut player, KeyCode::Home);
+        assert_eq!(player.tick, 0);
+        assert!(!press(&mut player, KeyCode::Char('q')));
+    }
+
+    #[test]
+    fn pauses_on_the_last_tick() {
+        let mut player = player();
+        player.seek(149);
+
+        player.advance();
+        assert_eq!((player.tick, player.playing), (150, true));
+        player.advance();
+
+        assert_eq!((player.tick, player.playing), (150, false));
+        assert!(player.status().starts_with("tick 150, finished at 10 fps"));
+    }
+
+    #[test]
+    fn changes_speed() {
+        let mut player = player();
+
+        press(&mut player, KeyCode::Char('+'));
+        assert_eq!(player.fps, 20);
+        for _ in 0..10 {
+            press(&mut player, KeyCode::Char('-'));
+        }
+        assert_eq!(player.fps, 1);
+    }
+
+    #[test]
+    fn crops_frames_to_the_terminal() {
+        let mut player = player();
+        let mut out = vec![];
+
+        player.draw(&mut out, (5, 2)).unwrap();
+
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("count"));
+        assert!(!out.contains("count 0"));
+        assert!(!out.contains("line 2"));
+        assert!(out.contains("tick "));
+    }
+}
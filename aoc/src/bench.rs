@@ -0,0 +1,406 @@
+use anyhow::Context;
+use aoc_common::Day;
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt, fs,
+    hint::black_box,
+    io::Write,
+    path::Path,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+/// How many times each phase runs
+#[derive(Debug, Clone, Copy)]
+pub struct Options {
+    /// Untimed runs before the timed ones, to warm up caches
+    pub warmup: u32,
+    pub runs: u32,
+}
+
+/// Summary of one phase's timed runs, in nanoseconds
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+    pub runs: u32,
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        let nanos = |duration: Duration| duration.as_nanos() as u64;
+        let total: u64 = samples.iter().copied().map(nanos).sum();
+
+        Self {
+            min_ns: nanos(samples[0]),
+            median_ns: nanos(samples[samples.len() / 2]),
+            mean_ns: total / samples.len() as u64,
+            runs: samples.len() as u32,
+        }
+    }
+
+    pub fn median(&self) -> Duration {
+        Duration::from_nanos(self.median_ns)
+    }
+}
+
+/// Runs `phase` as `options` says and times it, or returns `None` if the phase
+/// is unsolved
+pub fn time<T>(options: Options, mut phase: impl FnMut() -> Option<T>) -> Option<Stats> {
+    for _ in 0..options.warmup {
+        black_box(phase()?);
+    }
+
+    let mut samples = Vec::with_capacity(options.runs as usize);
+    for _ in 0..options.runs.max(1) {
+        let start = Instant::now();
+        let result = phase();
+        samples.push(start.elapsed());
+        black_box(result?);
+    }
+
+    Some(Stats::from_samples(samples))
+}
+
+/// Timings of one day, where unsolved phases are `None`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DayTimings {
+    pub year: u16,
+    pub day: u8,
+    pub parse: Option<Stats>,
+    pub part1: Option<Stats>,
+    pub part2: Option<Stats>,
+}
+
+impl DayTimings {
+    fn phases(&self) -> [(&'static str, Option<&Stats>); 3] {
+        [
+            ("parse", self.parse.as_ref()),
+            ("part 1", self.part1.as_ref()),
+            ("part 2", self.part2.as_ref()),
+        ]
+    }
+}
+
+/// Times parsing `input` and solving both parts of the parsed input, or fails
+/// if `input` can't be parsed
+pub fn bench_day(
+    year: u16,
+    day: &Day,
+    input: &str,
+    options: Options,
+) -> anyhow::Result<DayTimings> {
+    let solution = day.solution;
+    let parsed = solution.parse(input)?;
+
+    Ok(DayTimings {
+        year,
+        day: day.day,
+        parse: time(options, || solution.parse(input).ok()),
+        // A part that fails isn't timed, like one that is unsolved
+        part1: time(options, || solution.part1(&parsed).ok().flatten()),
+        part2: time(options, || solution.part2(&parsed).ok().flatten()),
+    })
+}
+
+/// Timings of every benchmarked day, as written to and read from JSON
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Report {
+    /// Seconds since the Unix epoch when the timings were taken
+    pub timestamp: u64,
+    pub days: Vec<DayTimings>,
+}
+
+impl Report {
+    /// An empty report timestamped with the current time
+    pub fn now() -> Self {
+        let since_epoch = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        Self {
+            timestamp: since_epoch.as_secs(),
+            days: vec![],
+        }
+    }
+
+    /// Every report in the history at `path`, one per line, oldest first
+    pub fn history(path: &Path) -> anyhow::Result<Vec<Self>> {
+        let history = fs::read_to_string(path)
+            .with_context(|| format!("failed to read '{}'", path.display()))?;
+
+        history
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(index, line)| {
+                serde_json::from_str(line).with_context(|| {
+                    format!("failed to parse line {} of '{}'", index + 1, path.display())
+                })
+            })
+            .collect()
+    }
+
+    /// Adds the report to the end of the history at `path` as one line of
+    /// JSON, creating the file if needed
+    pub fn append(&self, path: &Path) -> anyhow::Result<()> {
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .with_context(|| format!("failed to open '{}'", path.display()))?;
+        writeln!(file, "{}", serde_json::to_string(self)?)
+            .with_context(|| format!("failed to write '{}'", path.display()))
+    }
+
+    /// The `entry`th report in the history at `path`, counting from 1 for
+    /// the oldest, or the latest one
+    pub fn from_history(path: &Path, entry: Option<usize>) -> anyhow::Result<Self> {
+        let mut history = Self::history(path)?;
+        let count = history.len();
+        let index = match entry {
+            Some(entry) => entry.checked_sub(1).filter(|&index| index < count),
+            None => count.checked_sub(1),
+        };
+
+        let index = index.with_context(|| {
+            format!(
+                "'{}' has {count} entries, so there's no entry {}",
+                path.display(),
+                entry.unwrap_or(count)
+            )
+        })?;
+        Ok(history.swap_remove(index))
+    }
+
+    /// Phases whose median got more than `threshold` percent slower than in
+    /// `baseline`. Phases missing from either report are not compared.
+    pub fn regressions(&self, baseline: &Report, threshold: f64) -> Vec<Regression> {
+        self.days
+            .iter()
+            .filter_map(|timings| {
+                let baseline = baseline
+                    .days
+                    .iter()
+                    .find(|base| base.year == timings.year && base.day == timings.day)?;
+                Some((timings, baseline))
+            })
+            .flat_map(|(timings, baseline)| {
+                timings
+                    .phases()
+                    .into_iter()
+                    .zip(baseline.phases())
+                    .filter_map(|((phase, current), (_, base))| {
+                        let (current, base) = (current?.median(), base?.median());
+                        let limit = base.mul_f64(1.0 + threshold / 100.0);
+
+                        (current > limit).then_some(Regression {
+                            year: timings.year,
+                            day: timings.day,
+                            phase,
+                            baseline: base,
+                            current,
+                        })
+                    })
+            })
+            .collect()
+    }
+}
+
+fn format_stats(stats: Option<&Stats>) -> String {
+    stats.map_or("-".to_string(), |stats| format!("{:.2?}", stats.median()))
+}
+
+/// A table of the median time of each phase
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:>4} {:>3}  {:>10}  {:>10}  {:>10}",
+            "year", "day", "parse", "part 1", "part 2"
+        )?;
+
+        for timings in &self.days {
+            let [parse, part1, part2] = timings.phases().map(|(_, stats)| format_stats(stats));
+            writeln!(
+                f,
+                "{:>4} {:>3}  {parse:>10}  {part1:>10}  {part2:>10}",
+                timings.year, timings.day
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// A phase that got slower than in the baseline
+#[derive(Debug, PartialEq)]
+pub struct Regression {
+    pub year: u16,
+    pub day: u8,
+    pub phase: &'static str,
+    pub baseline: Duration,
+    pub current: Duration,
+}
+
+impl fmt::Display for Regression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let slowdown = self.current.as_secs_f64() / self.baseline.as_secs_f64();
+        write!(
+            f,
+            "{} day {} {}: {:.2?} -> {:.2?} ({slowdown:.2}x)",
+            self.year, self.day, self.phase, self.baseline, self.current
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    fn stats(median_ns: u64) -> Stats {
+        Stats {
+            min_ns: median_ns,
+            median_ns,
+            mean_ns: median_ns,
+            runs: 1,
+        }
+    }
+
+    fn timings(day: u8, part1: Option<u64>, part2: Option<u64>) -> DayTimings {
+        DayTimings {
+            year: 2024,
+            day,
+            parse: None,
+            part1: part1.map(stats),
+            part2: part2.map(stats),
+        }
+    }
+
+    #[test]
+    fn summarizes_samples() {
+        let samples = [40, 10, 30, 20].map(Duration::from_nanos).to_vec();
+
+        assert_eq!(
+            Stats::from_samples(samples),
+            Stats {
+                min_ns: 10,
+                median_ns: 30,
+                mean_ns: 25,
+                runs: 4
+            }
+        );
+    }
+
+    #[test]
+    fn runs_warmup_and_timed_runs() {
+        let mut calls = 0;
+        let stats = time(Options { warmup: 2, runs: 3 }, || {
+            calls += 1;
+            Some(calls)
+        });
+
+        assert_eq!(calls, 5);
+        assert_eq!(stats.unwrap().runs, 3);
+    }
+
+    #[test]
+    fn skips_unsolved_phases() {
+        let options = Options { warmup: 0, runs: 3 };
+
+        assert_eq!(time(options, || None::<String>), None);
+    }
+
+    #[test]
+    fn flags_regressions_beyond_threshold() {
+        let baseline = Report {
+            timestamp: 1,
+            days: vec![
+                timings(6, Some(100), Some(1000)),
+                timings(11, Some(50), None),
+            ],
+        };
+        let report = Report {
+            timestamp: 2,
+            days: vec![
+                timings(6, Some(105), Some(2000)),
+                timings(11, Some(500), Some(10)),
+                timings(12, Some(1), None),
+            ],
+        };
+
+        let regressions = report.regressions(&baseline, 10.0);
+
+        assert_eq!(
+            regressions,
+            vec![
+                Regression {
+                    year: 2024,
+                    day: 6,
+                    phase: "part 2",
+                    baseline: Duration::from_nanos(1000),
+                    current: Duration::from_nanos(2000),
+                },
+                Regression {
+                    year: 2024,
+                    day: 11,
+                    phase: "part 1",
+                    baseline: Duration::from_nanos(50),
+                    current: Duration::from_nanos(500),
+                },
+            ]
+        );
+        assert_eq!(
+            regressions[0].to_string(),
+            "2024 day 6 part 2: 1.00µs -> 2.00µs (2.00x)"
+        );
+    }
+
+    #[test]
+    fn keeps_a_history_of_reports() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("bench.jsonl");
+        let reports = [1, 2, 3].map(|timestamp| Report {
+            timestamp,
+            days: vec![timings(6, Some(timestamp * 100), None)],
+        });
+
+        for report in &reports {
+            report.append(&path).unwrap();
+        }
+
+        assert_eq!(fs::read_to_string(&path).unwrap().lines().count(), 3);
+        assert_eq!(Report::history(&path).unwrap(), reports);
+        assert_eq!(Report::from_history(&path, None).unwrap(), reports[2]);
+        assert_eq!(Report::from_history(&path, Some(1)).unwrap(), reports[0]);
+        assert_eq!(
+            Report::from_history(&path, Some(4))
+                .unwrap_err()
+                .to_string(),
+            format!("'{}' has 3 entries, so there's no entry 4", path.display())
+        );
+        assert!(Report::from_history(&path, Some(0)).is_err());
+
+        fs::write(&path, "{\"days\": []}\n").unwrap();
+        assert_eq!(
+            Report::history(&path).unwrap_err().to_string(),
+            format!("failed to parse line 1 of '{}'", path.display())
+        );
+    }
+
+    #[test]
+    fn prints_table_of_medians() {
+        let report = Report {
+            timestamp: 0,
+            days: vec![timings(6, Some(1_500), Some(2_000_000_000))],
+        };
+
+        assert_eq!(
+            report.to_string(),
+            indoc! {"
+                year day       parse      part 1      part 2
+                2024   6           -      1.50µs       2.00s
+            "}
+        );
+    }
+}
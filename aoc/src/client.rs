@@ -0,0 +1,268 @@
+use anyhow::{bail, Context};
+use std::{
+    cell::Cell,
+    fs::{self, File},
+    io::{Read, Seek, Write},
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use ureq::{http::StatusCode, Agent};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Advent of Code asks automated tools to identify themselves
+const USER_AGENT: &str = concat!(
+    "aoc/",
+    env!("CARGO_PKG_VERSION"),
+    " (personal Advent of Code runner)"
+);
+
+/// Shortest time between two requests, to stay well within the site's rate limits
+const MIN_REQUEST_INTERVAL: Duration = Duration::from_secs(3);
+
+/// Talks to the Advent of Code website, or anything that serves the same paths
+pub struct Client {
+    agent: Agent,
+    base_url: String,
+    session: String,
+    min_interval: Duration,
+    last_request: Cell<Option<SystemTime>>,
+    /// Where the time of the latest request is kept, so that separate runs
+    /// space out their requests too
+    throttle_file: Option<PathBuf>,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        let agent = Agent::config_builder()
+            .http_status_as_error(false)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .user_agent(USER_AGENT)
+            .build()
+            .into();
+
+        Self {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            min_interval: MIN_REQUEST_INTERVAL,
+            last_request: Cell::new(None),
+            throttle_file: None,
+        }
+    }
+
+    /// Shares the time of the latest request through `path` with every other
+    /// client that uses it
+    pub fn with_throttle_file(mut self, path: PathBuf) -> Self {
+        self.throttle_file = Some(path);
+        self
+    }
+
+    #[cfg(test)]
+    pub fn with_min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = min_interval;
+        self
+    }
+
+    /// Waits until at least `min_interval` has passed since the previous
+    /// request, by this client or any other sharing its throttle file
+    fn throttle(&self) -> anyhow::Result<()> {
+        let Some(path) = &self.throttle_file else {
+            self.wait_after(self.last_request.get());
+            self.last_request.set(Some(SystemTime::now()));
+            return Ok(());
+        };
+
+        let mut file = open_throttle_file(path)?;
+        // Other runs wait for the lock until this one has recorded its request
+        file.lock()
+            .with_context(|| format!("failed to lock '{}'", path.display()))?;
+
+        let mut contents = String::new();
+        file.read_to_string(&mut contents)
+            .with_context(|| format!("failed to read '{}'", path.display()))?;
+        let recorded = contents
+            .trim()
+            .parse()
+            .ok()
+            .map(|millis| UNIX_EPOCH + Duration::from_millis(millis));
+        self.wait_after(self.last_request.get().max(recorded));
+
+        let now = SystemTime::now();
+        let millis = now
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis();
+        file.set_len(0)
+            .and_then(|_| file.rewind())
+            .and_then(|_| write!(file, "{millis}"))
+            .with_context(|| format!("failed to write '{}'", path.display()))?;
+        self.last_request.set(Some(now));
+
+        Ok(())
+    }
+
+    /// Sleeps until `min_interval` after `last_request`. A time in the future,
+    /// e.g. after the clock was turned back, waits the whole interval.
+    fn wait_after(&self, last_request: Option<SystemTime>) {
+        let Some(last_request) = last_request else {
+            return;
+        };
+        let elapsed = last_request.elapsed().unwrap_or_default();
+        if elapsed < self.min_interval {
+            thread::sleep(self.min_interval - elapsed);
+        }
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{path}", self.base_url)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    fn check_status(status: StatusCode, url: &str) -> anyhow::Result<()> {
+        match status {
+            StatusCode::OK => Ok(()),
+            StatusCode::NOT_FOUND => bail!("'{url}' was not found, is the puzzle unlocked yet?"),
+            StatusCode::BAD_REQUEST | StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+                bail!("'{url}' was refused ({status}), is the session token valid?")
+            }
+            StatusCode::TOO_MANY_REQUESTS => bail!("rate limited by '{url}', try again later"),
+            status => bail!("unexpected response from '{url}': {status}"),
+        }
+    }
+
+    pub fn get(&self, path: &str) -> anyhow::Result<String> {
+        let url = self.url(path);
+        self.throttle()?;
+
+        let mut response = self
+            .agent
+            .get(&url)
+            .header("Cookie", &self.cookie())
+            .call()
+            .with_context(|| format!("failed to request '{url}'"))?;
+        Self::check_status(response.status(), &url)?;
+
+        response
+            .body_mut()
+            .read_to_string()
+            .with_context(|| format!("failed to read response from '{url}'"))
+    }
+
+    pub fn post_form(&self, path: &str, form: &[(&str, &str)]) -> anyhow::Result<String> {
+        let url = self.url(path);
+        self.throttle()?;
+
+        let mut response = self
+            .agent
+            .post(&url)
+            .header("Cookie", &self.cookie())
+            .send_form(form.iter().copied())
+            .with_context(|| format!("failed to post to '{url}'"))?;
+        Self::check_status(response.status(), &url)?;
+
+        response
+            .body_mut()
+            .read_to_string()
+            .with_context(|| format!("failed to read response from '{url}'"))
+    }
+
+    pub fn input(&self, year: u16, day: u8) -> anyhow::Result<String> {
+        self.get(&format!("/{year}/day/{day}/input"))
+    }
+
+    /// The puzzle's page, which describes the parts that are unlocked
+    pub fn puzzle(&self, year: u16, day: u8) -> anyhow::Result<String> {
+        self.get(&format!("/{year}/day/{day}"))
+    }
+}
+
+fn open_throttle_file(path: &Path) -> anyhow::Result<File> {
+    if let Some(directory) = path.parent() {
+        fs::create_dir_all(directory)
+            .with_context(|| format!("failed to create '{}'", directory.display()))?;
+    }
+    File::options()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
+        .with_context(|| format!("failed to open '{}'", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_server::MockServer;
+    use std::time::Instant;
+
+    #[test]
+    fn requests_input_with_session() {
+        let server = MockServer::start(vec![(200, "1\n2\n3\n")]);
+        let client = Client::new(&server.url(), "secret\n");
+
+        assert_eq!(client.input(2024, 1).unwrap(), "1\n2\n3\n");
+
+        let request = server.requests().remove(0).to_lowercase();
+        assert!(request.starts_with("get /2024/day/1/input http/1.1"));
+        assert!(request.contains("cookie: session=secret\r\n"));
+        assert!(request.contains(&USER_AGENT.to_lowercase()));
+    }
+
+    #[test]
+    fn explains_missing_puzzle() {
+        let server = MockServer::start(vec![(404, "Not Found")]);
+        let client = Client::new(&server.url(), "secret");
+
+        let error = client.input(2024, 25).unwrap_err().to_string();
+        assert!(error.contains("is the puzzle unlocked yet?"));
+    }
+
+    #[test]
+    fn explains_rate_limit() {
+        let server = MockServer::start(vec![(429, "Too Many Requests")]);
+        let client = Client::new(&server.url(), "secret");
+
+        let error = client.input(2024, 1).unwrap_err().to_string();
+        assert!(error.contains("rate limited"));
+    }
+
+    #[test]
+    fn waits_between_requests() {
+        let server = MockServer::start(vec![(200, "a"), (200, "b")]);
+        let client =
+            Client::new(&server.url(), "secret").with_min_interval(Duration::from_millis(200));
+
+        let start = Instant::now();
+        client.input(2024, 1).unwrap();
+        client.input(2024, 2).unwrap();
+
+        assert!(start.elapsed() >= Duration::from_millis(200));
+    }
+
+    #[test]
+    fn waits_for_requests_by_other_clients() {
+        let server = MockServer::start(vec![(200, "a"), (200, "b")]);
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("inputs").join(".aoc-last-request");
+        let client = || {
+            Client::new(&server.url(), "secret")
+                .with_min_interval(Duration::from_millis(200))
+                .with_throttle_file(path.clone())
+        };
+
+        let start = Instant::now();
+        client().input(2024, 1).unwrap();
+        client().input(2024, 2).unwrap();
+
+        assert!(start.elapsed() >= Duration::from_millis(200));
+        let recorded: u128 = fs::read_to_string(&path).unwrap().parse().unwrap();
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+        assert!(now.as_millis() - recorded < 1000);
+    }
+}
@@ -0,0 +1,144 @@
+use crate::html::{elements, text};
+use anyhow::Context;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// The examples of a puzzle, as given in its description
+#[derive(Debug, Default, PartialEq)]
+pub struct Examples {
+    /// Every distinct `<pre><code>` block, some of which may illustrate steps
+    /// of the puzzle rather than be inputs
+    pub inputs: Vec<String>,
+    /// The last emphasized code in the description of each part, which is
+    /// where the answer to its example is given
+    pub answers: [Option<String>; 2],
+}
+
+impl Examples {
+    /// Extracts the examples from a saved puzzle page, which has one
+    /// `<article>` per part that has been unlocked
+    pub fn extract(page: &str) -> Self {
+        let mut articles: Vec<_> = elements(page, "<article", "</article>").collect();
+        if articles.is_empty() {
+            articles.push(page);
+        }
+
+        let mut examples = Examples::default();
+        for (part, article) in articles.into_iter().take(2).enumerate() {
+            for input in elements(article, "<pre><code>", "</code></pre>").map(text) {
+                if !examples.inputs.contains(&input) {
+                    examples.inputs.push(input);
+                }
+            }
+
+            examples.answers[part] = elements(article, "<code><em>", "</em></code>")
+                .last()
+                .map(text);
+        }
+
+        examples
+    }
+
+    /// Writes each input to a file named after its number and each answer to
+    /// `part1` or `part2` in `directory`. Returns the files that were written.
+    pub fn save(&self, directory: &Path) -> anyhow::Result<Vec<PathBuf>> {
+        fs::create_dir_all(directory)
+            .with_context(|| format!("failed to create '{}'", directory.display()))?;
+
+        let inputs = self
+            .inputs
+            .iter()
+            .enumerate()
+            .map(|(index, input)| ((index + 1).to_string(), input.clone()));
+        let answers = self
+            .answers
+            .iter()
+            .enumerate()
+            .filter_map(|(index, answer)| {
+                Some((
+                    format!("part{}", index + 1),
+                    format!("{}\n", answer.as_ref()?),
+                ))
+            });
+
+        inputs
+            .chain(answers)
+            .map(|(name, contents)| {
+                let path = directory.join(name);
+                fs::write(&path, contents)
+                    .with_context(|| format!("failed to write '{}'", path.display()))?;
+                Ok(path)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    const PAGE: &str = indoc! {r#"
+        <main>
+        <article class="day-desc"><h2>--- Day 7: Laboratories ---</h2>
+        <p>For example:</p>
+        <pre><code>..S..
+        .^.&lt;.
+        </code></pre>
+        <p>A tachyon beam is split a total of <code><em>2</em></code> times. After one step:</p>
+        <pre><code>..|..
+        </code></pre>
+        <p>In this example, a tachyon beam is split a total of <code><em>21</em></code> times.</p>
+        </article>
+        <p>Your puzzle answer was <code>1594</code>.</p>
+        <article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+        <p>Using the same example:</p>
+        <pre><code>..S..
+        .^.&lt;.
+        </code></pre>
+        <p>there are <code><em>40</em></code> timelines, with <em>some</em> emphasis.</p>
+        </article>
+        </main>
+    "#};
+
+    #[test]
+    fn extracts_examples_and_answers() {
+        assert_eq!(
+            Examples::extract(PAGE),
+            Examples {
+                inputs: vec!["..S..\n.^.<.\n".to_string(), "..|..\n".to_string()],
+                answers: [Some("21".to_string()), Some("40".to_string())],
+            }
+        );
+    }
+
+    #[test]
+    fn leaves_locked_part_unanswered() {
+        let (part1, _) = PAGE.split_once("<p>Your puzzle answer").unwrap();
+
+        assert_eq!(
+            Examples::extract(part1).answers,
+            [Some("21".to_string()), None]
+        );
+    }
+
+    #[test]
+    fn saves_numbered_fixtures() {
+        let directory = tempfile::tempdir().unwrap();
+        let examples = Examples {
+            inputs: vec!["1\n2\n".to_string(), "3\n".to_string()],
+            answers: [Some("3".to_string()), None],
+        };
+
+        let written = examples.save(directory.path()).unwrap();
+
+        assert_eq!(
+            written,
+            ["1", "2", "part1"].map(|name| directory.path().join(name))
+        );
+        assert_eq!(fs::read_to_string(&written[0]).unwrap(), "1\n2\n");
+        assert_eq!(fs::read_to_string(&written[2]).unwrap(), "3\n");
+    }
+}
@@ -0,0 +1,91 @@
+use crate::client::Client;
+use anyhow::Context;
+use aoc_common::InputStore;
+use std::{fs, path::PathBuf};
+
+#[derive(Debug, PartialEq)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// Downloads the input for `year`'s `day` into `store`, unless it is already
+/// there. An empty input, like the placeholder of a new day, isn't cached.
+pub fn fetch_input(
+    client: &Client,
+    store: &dyn InputStore,
+    year: u16,
+    day: u8,
+    force: bool,
+) -> anyhow::Result<Fetched> {
+    let path = store.path(year, day);
+    let cached = fs::metadata(&path).is_ok_and(|metadata| metadata.len() > 0);
+    if cached && !force {
+        return Ok(Fetched::Cached(path));
+    }
+
+    let input = client.input(year, day)?;
+
+    if let Some(directory) = path.parent() {
+        fs::create_dir_all(directory)
+            .with_context(|| format!("failed to create '{}'", directory.display()))?;
+    }
+    fs::write(&path, input).with_context(|| format!("failed to write '{}'", path.display()))?;
+
+    Ok(Fetched::Downloaded(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_server::MockServer;
+    use aoc_common::InputDirectory;
+
+    #[test]
+    fn downloads_into_store() {
+        let server = MockServer::start(vec![(200, "12345\n")]);
+        let client = Client::new(&server.url(), "secret");
+        let directory = tempfile::tempdir().unwrap();
+        let store = InputDirectory(directory.path().to_path_buf());
+
+        let fetched = fetch_input(&client, &store, 2024, 9, false).unwrap();
+
+        let path = directory.path().join("2024/day9");
+        assert_eq!(fetched, Fetched::Downloaded(path.clone()));
+        assert_eq!(fs::read_to_string(path).unwrap(), "12345\n");
+    }
+
+    #[test]
+    fn skips_cached_input() {
+        let server = MockServer::start(vec![]);
+        let client = Client::new(&server.url(), "secret");
+        let directory = tempfile::tempdir().unwrap();
+        let store = InputDirectory(directory.path().to_path_buf());
+
+        let path = directory.path().join("2024/day9");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "cached").unwrap();
+
+        let fetched = fetch_input(&client, &store, 2024, 9, false).unwrap();
+
+        assert_eq!(fetched, Fetched::Cached(path));
+        assert!(server.requests().is_empty());
+    }
+
+    #[test]
+    fn replaces_empty_placeholder() {
+        let server = MockServer::start(vec![(200, "12345\n")]);
+        let client = Client::new(&server.url(), "secret");
+        let directory = tempfile::tempdir().unwrap();
+        let store = InputDirectory(directory.path().to_path_buf());
+
+        let path = directory.path().join("2024/day9");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "").unwrap();
+
+        let fetched = fetch_input(&client, &store, 2024, 9, false).unwrap();
+
+        assert_eq!(fetched, Fetched::Downloaded(path.clone()));
+        assert_eq!(fs::read_to_string(path).unwrap(), "12345\n");
+    }
+}
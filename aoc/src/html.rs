@@ -0,0 +1,104 @@
+//! Just enough HTML handling for adventofcode.com's pages, which are simple
+//! and well-formed
+
+/// A piece of HTML, where tag names are lowercase
+#[derive(Debug, PartialEq)]
+pub enum Token<'a> {
+    Open(String),
+    Close(String),
+    Text(&'a str),
+}
+
+/// The tags and text of `html`, without comments
+pub fn tokens(html: &str) -> impl Iterator<Item = Token<'_>> {
+    let mut rest = html;
+
+    std::iter::from_fn(move || loop {
+        if rest.is_empty() {
+            return None;
+        }
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.split_once("-->").map_or("", |(_, after)| after);
+            continue;
+        }
+        if let Some(tag) = rest.strip_prefix('<') {
+            let (tag, after) = tag.split_once('>').unwrap_or((tag, ""));
+            rest = after;
+
+            let (closing, tag) = match tag.strip_prefix('/') {
+                Some(tag) => (true, tag),
+                None => (false, tag),
+            };
+            let name = tag
+                .split(|c: char| c.is_whitespace() || c == '/')
+                .next()
+                .unwrap_or_default()
+                .to_lowercase();
+
+            return Some(if closing {
+                Token::Close(name)
+            } else {
+                Token::Open(name)
+            });
+        }
+
+        let end = rest.find('<').unwrap_or(rest.len());
+        let (text, after) = rest.split_at(end);
+        rest = after;
+        return Some(Token::Text(text));
+    })
+}
+
+/// `text` with the entities adventofcode.com uses decoded
+pub fn decode(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// `html` without its tags and with its entities decoded
+pub fn text(html: &str) -> String {
+    let text: String = tokens(html)
+        .filter_map(|token| match token {
+            Token::Text(text) => Some(text),
+            _ => None,
+        })
+        .collect();
+
+    decode(&text)
+}
+
+/// The contents of each `<open>...</close>` in `html`
+pub fn elements<'a>(html: &'a str, open: &'a str, close: &'a str) -> impl Iterator<Item = &'a str> {
+    html.split(open)
+        .skip(1)
+        .filter_map(move |element| Some(element.split_once(close)?.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_tags_and_text() {
+        let tokens: Vec<_> = tokens("<p class=\"x\">a<!-- b --><br/>c</P>").collect();
+
+        assert_eq!(
+            tokens,
+            vec![
+                Token::Open("p".to_string()),
+                Token::Text("a"),
+                Token::Open("br".to_string()),
+                Token::Text("c"),
+                Token::Close("p".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn decodes_tags_and_entities() {
+        assert_eq!(text("<em>a</em> &amp;&lt;b&gt;"), "a &<b>");
+    }
+}
@@ -0,0 +1,199 @@
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fs, path::PathBuf};
+
+/// Everything known about the answers to one part of a puzzle
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct PartRecord {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub correct: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub wrong: Vec<String>,
+    /// Lowest answer known to be too high
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub too_high: Option<i64>,
+    /// Highest answer known to be too low
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub too_low: Option<i64>,
+}
+
+impl PartRecord {
+    fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Why `answer` is already known to be wrong, if it is
+    pub fn rejection(&self, answer: &str) -> Option<String> {
+        if let Some(correct) = &self.correct {
+            return (correct != answer).then(|| format!("the accepted answer is {correct}"));
+        }
+        if self.wrong.iter().any(|wrong| wrong == answer) {
+            return Some(format!("{answer} has already been rejected"));
+        }
+
+        let number: i64 = answer.parse().ok()?;
+        match (self.too_high, self.too_low) {
+            (Some(too_high), _) if number >= too_high => {
+                Some(format!("{answer} is too high, {too_high} already was"))
+            }
+            (_, Some(too_low)) if number <= too_low => {
+                Some(format!("{answer} is too low, {too_low} already was"))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn record_wrong(&mut self, answer: &str) {
+        if !self.wrong.iter().any(|wrong| wrong == answer) {
+            self.wrong.push(answer.to_string());
+        }
+    }
+
+    pub fn record_too_high(&mut self, answer: &str) {
+        self.record_wrong(answer);
+        if let Ok(number) = answer.parse() {
+            self.too_high = Some(
+                self.too_high
+                    .map_or(number, |too_high| too_high.min(number)),
+            );
+        }
+    }
+
+    pub fn record_too_low(&mut self, answer: &str) {
+        self.record_wrong(answer);
+        if let Ok(number) = answer.parse() {
+            self.too_low = Some(self.too_low.map_or(number, |too_low| too_low.max(number)));
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DayRecord {
+    #[serde(default, skip_serializing_if = "PartRecord::is_empty")]
+    pub part1: PartRecord,
+    #[serde(default, skip_serializing_if = "PartRecord::is_empty")]
+    pub part2: PartRecord,
+}
+
+impl DayRecord {
+    pub fn part(&self, part: u8) -> &PartRecord {
+        match part {
+            1 => &self.part1,
+            _ => &self.part2,
+        }
+    }
+
+    pub fn part_mut(&mut self, part: u8) -> &mut PartRecord {
+        match part {
+            1 => &mut self.part1,
+            _ => &mut self.part2,
+        }
+    }
+}
+
+/// Submitted answers for one year, kept in `{year}/answers.toml`
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Ledger {
+    #[serde(flatten)]
+    days: BTreeMap<String, DayRecord>,
+}
+
+fn day_key(day: u8) -> String {
+    format!("day{day:02}")
+}
+
+impl Ledger {
+    pub fn path(year: u16) -> PathBuf {
+        aoc_common::year_directory(year).join("answers.toml")
+    }
+
+    /// The year's ledger, or an empty one if nothing has been recorded yet
+    pub fn load(year: u16) -> anyhow::Result<Self> {
+        let path = Self::path(year);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let ledger = fs::read_to_string(&path)
+            .with_context(|| format!("failed to read '{}'", path.display()))?;
+        toml::from_str(&ledger).with_context(|| format!("failed to parse '{}'", path.display()))
+    }
+
+    pub fn save(&self, year: u16) -> anyhow::Result<()> {
+        let path = Self::path(year);
+        let ledger = toml::to_string(self)?;
+        fs::write(&path, ledger).with_context(|| format!("failed to write '{}'", path.display()))
+    }
+
+    pub fn part(&self, day: u8, part: u8) -> Option<&PartRecord> {
+        self.days.get(&day_key(day)).map(|record| record.part(part))
+    }
+
+    pub fn part_mut(&mut self, day: u8, part: u8) -> &mut PartRecord {
+        self.days.entry(day_key(day)).or_default().part_mut(part)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        let mut record = PartRecord::default();
+        record.record_wrong("abc");
+        record.record_too_high("100");
+        record.record_too_low("10");
+
+        assert!(record.rejection("abc").is_some());
+        assert!(record.rejection("150").is_some());
+        assert!(record.rejection("100").is_some());
+        assert!(record.rejection("5").is_some());
+        assert!(record.rejection("50").is_none());
+        assert!(record.rejection("xyz").is_none());
+    }
+
+    #[test]
+    fn keeps_tightest_bounds() {
+        let mut record = PartRecord::default();
+        record.record_too_high("100");
+        record.record_too_high("80");
+        record.record_too_high("90");
+
+        assert_eq!(record.too_high, Some(80));
+        assert_eq!(record.wrong, vec!["100", "80", "90"]);
+    }
+
+    #[test]
+    fn only_accepts_the_correct_answer() {
+        let record = PartRecord {
+            correct: Some("42".to_string()),
+            ..Default::default()
+        };
+
+        assert!(record.rejection("42").is_none());
+        assert!(record.rejection("43").is_some());
+    }
+
+    #[test]
+    fn round_trips_through_toml() {
+        let mut ledger = Ledger::default();
+        ledger.part_mut(3, 1).correct = Some("161".to_string());
+        ledger.part_mut(3, 2).record_too_low("10");
+
+        let serialized = toml::to_string(&ledger).unwrap();
+        assert_eq!(
+            serialized,
+            indoc! {r#"
+                [day03.part1]
+                correct = "161"
+
+                [day03.part2]
+                wrong = ["10"]
+                too_low = 10
+            "#}
+        );
+        assert_eq!(toml::from_str::<Ledger>(&serialized).unwrap(), ledger);
+    }
+}
@@ -0,0 +1,655 @@
+mod animate;
+mod bench;
+mod client;
+mod examples;
+mod fetch;
+mod html;
+mod ledger;
+#[cfg(test)]
+mod mock_server;
+mod puzzle;
+mod regression;
+mod render;
+mod scaffold;
+mod submit;
+
+use anyhow::{bail, Context};
+use aoc_common::{Answer, Day, Frames, InputSource, Year};
+use bench::Report;
+use clap::{Parser, Subcommand};
+use client::Client;
+use fetch::Fetched;
+use ledger::Ledger;
+use serde::Serialize;
+use std::{
+    env, fs,
+    io::{self, IsTerminal},
+    path::{Path, PathBuf},
+};
+
+const YEARS: [&Year; 4] = [
+    &aoc2022::YEAR,
+    &aoc2023::YEAR,
+    &aoc2024::YEAR,
+    &aoc2025::YEAR,
+];
+
+#[derive(Parser)]
+#[command(about = "Runs Advent of Code solutions from every year")]
+struct Cli {
+    /// Advent of Code server to download inputs from and submit answers to
+    #[arg(long, global = true, env = "AOC_BASE_URL", default_value = client::DEFAULT_BASE_URL)]
+    base_url: String,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a day's solution
+    Run {
+        year: u16,
+        day: u8,
+        /// Only run this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Puzzle input or `-` for stdin. Defaults to `$AOC_INPUT_DIR` or the
+        /// year's input directory
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Print the answers as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Play a day's simulation in the terminal, one tick at a time
+    Animate {
+        year: u16,
+        day: u8,
+        /// Ticks per second, which `+` and `-` change while playing
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        fps: u32,
+        /// Puzzle input or `-` for stdin
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// Draw a day's simulation as a PNG of one tick or a GIF of many, or a
+    /// day's input as an SVG
+    Export {
+        year: u16,
+        day: u8,
+        /// Where to write the image, ending in `.png` or `.gif`, or `.svg` for
+        /// days that draw their input
+        output: PathBuf,
+        /// The tick to draw, or the first of the GIF
+        #[arg(long, default_value_t = 0)]
+        tick: usize,
+        /// How many ticks the GIF shows
+        #[arg(long, default_value_t = 100)]
+        frames: usize,
+        /// Only show every this many ticks in the GIF
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u64).range(1..))]
+        every: u64,
+        /// Frames per second of the GIF
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..=100))]
+        fps: u32,
+        /// Width and height in pixels of each cell
+        #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u32).range(1..))]
+        scale: u32,
+        /// Colours of cells, such as `#=ffffff,O=c04000`. Other cells get
+        /// colours of their own
+        #[arg(long, default_value = "")]
+        palette: render::Palette,
+        /// Puzzle input or `-` for stdin
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// List every registered day
+    List,
+    /// Show a day's puzzle description, downloading it the first time
+    Puzzle {
+        year: u16,
+        day: u8,
+        /// Download the page again, e.g. to read part 2 after solving part 1
+        #[arg(long)]
+        refresh: bool,
+        /// Column to wrap the text at
+        #[arg(long, default_value_t = 80)]
+        width: usize,
+    },
+    /// Save the example inputs and answers of a puzzle page as the day's test
+    /// fixtures
+    Examples {
+        year: u16,
+        day: u8,
+        /// The puzzle's page, as saved from adventofcode.com. Defaults to the
+        /// page cached by `aoc puzzle`
+        page: Option<PathBuf>,
+    },
+    /// Generate a day from the year's template, register it with the runner
+    /// and create an empty input for it
+    New {
+        year: u16,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Download puzzle inputs into the input directory
+    Fetch {
+        year: u16,
+        /// Only fetch this day, defaults to every registered day of the year
+        day: Option<u8>,
+        /// Download the input even if it is already cached
+        #[arg(long)]
+        force: bool,
+    },
+    /// Check that solutions still give the accepted answers on their real inputs
+    Check {
+        /// Defaults to every year
+        year: Option<u16>,
+        /// Defaults to every registered day
+        day: Option<u8>,
+    },
+    /// Time parsing and each part of every registered day on its real input
+    Bench {
+        /// Defaults to every year
+        year: Option<u16>,
+        /// Defaults to every registered day
+        day: Option<u8>,
+        /// Untimed runs before timing each part
+        #[arg(long, default_value_t = 1)]
+        warmup: u32,
+        /// Timed runs of each part
+        #[arg(long, default_value_t = 5, value_parser = clap::value_parser!(u32).range(1..))]
+        runs: u32,
+        /// Add the timings to the history in this file, as a line of JSON
+        #[arg(long)]
+        json: Option<PathBuf>,
+        /// A history written with `--json` to compare against its latest entry
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+        /// Compare against this entry of the baseline instead, counting from 1
+        /// for the oldest
+        #[arg(long, requires = "baseline", value_parser = clap::value_parser!(u64).range(1..))]
+        entry: Option<u64>,
+        /// How many percent slower than the baseline counts as a regression
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Submit an answer and record the verdict in the year's ledger
+    Submit {
+        year: u16,
+        day: u8,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Defaults to running the solution
+        answer: Option<String>,
+        /// Puzzle input to run the solution on, or `-` for stdin
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+}
+
+fn find_day(year: u16, day: u8) -> anyhow::Result<&'static Day> {
+    let Some(registered_year) = YEARS.iter().find(|registered| registered.year == year) else {
+        bail!("there are no solutions for {year}");
+    };
+
+    registered_year
+        .get(day)
+        .with_context(|| format!("there is no solution for {year} day {day}"))
+}
+
+/// A day's answers as printed by `run --json`
+#[derive(Serialize)]
+struct Answers {
+    year: u16,
+    day: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part1: Option<Answer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part2: Option<Answer>,
+}
+
+fn run(
+    year: u16,
+    day: u8,
+    part: Option<u8>,
+    input: Option<PathBuf>,
+    json: bool,
+) -> anyhow::Result<()> {
+    let registered = find_day(year, day)?;
+    let input = InputSource::resolve(input.as_deref(), year, day).read()?;
+
+    let (part1, part2) = match part {
+        Some(part) => {
+            let answer = registered
+                .solve(part, &input)?
+                .with_context(|| format!("part {part} of {year} day {day} is unsolved"))?;
+            match part {
+                1 => (Some(answer), None),
+                _ => (None, Some(answer)),
+            }
+        }
+        // Unsolved parts are skipped when running the whole day
+        None => {
+            let solution = registered.solution;
+            let input = solution.parse(&input)?;
+            (solution.part1(&input)?, solution.part2(&input)?)
+        }
+    };
+
+    if json {
+        let answers = Answers {
+            year,
+            day,
+            part1,
+            part2,
+        };
+        println!("{}", serde_json::to_string_pretty(&answers)?);
+        return Ok(());
+    }
+
+    if let Some(answer) = part1 {
+        print_answer(1, &answer);
+    }
+    if let Some(answer) = part2 {
+        print_answer(2, &answer);
+    }
+
+    Ok(())
+}
+
+/// Images start on the line after the part so that their rows line up
+fn print_answer(part: u8, answer: &Answer) {
+    if answer.is_multiline() {
+        println!("Part {part}:\n{answer}");
+    } else {
+        println!("Part {part}: {answer}");
+    }
+}
+
+fn animate(year: u16, day: u8, fps: u32, input: Option<PathBuf>) -> anyhow::Result<()> {
+    let solution = find_day(year, day)?.solution;
+    if !io::stdout().is_terminal() {
+        bail!("animations need a terminal to play in");
+    }
+
+    let input = InputSource::resolve(input.as_deref(), year, day).read()?;
+    let simulation = solution
+        .simulation(&solution.parse(&input)?)
+        .with_context(|| format!("{year} day {day} has no simulation to animate"))?;
+
+    animate::play(animate::Player::new(Frames::new(simulation), fps))
+}
+
+/// What `export` draws and how
+struct Export {
+    output: PathBuf,
+    tick: usize,
+    frames: usize,
+    every: usize,
+    fps: u32,
+    style: render::Style,
+}
+
+fn export(year: u16, day: u8, export: Export, input: Option<PathBuf>) -> anyhow::Result<()> {
+    let solution = find_day(year, day)?.solution;
+    let input = InputSource::resolve(input.as_deref(), year, day).read()?;
+    let input = solution.parse(&input)?;
+    let extension = export
+        .output
+        .extension()
+        .and_then(|extension| extension.to_str());
+
+    if extension == Some("svg") {
+        let svg = solution
+            .svg(&input)
+            .with_context(|| format!("{year} day {day} has no drawing to export"))?;
+        fs::write(&export.output, svg)
+            .with_context(|| format!("failed to write '{}'", export.output.display()))?;
+        println!("Drew {year} day {day} to {}", export.output.display());
+        return Ok(());
+    }
+
+    let simulation = solution
+        .simulation(&input)
+        .with_context(|| format!("{year} day {day} has no simulation to export"))?;
+    let mut frames = Frames::new(simulation);
+
+    match extension {
+        Some("png") => {
+            let (tick, frame) = frames.get(export.tick);
+            render::write_png(&export.output, frame, &export.style)?;
+            println!("Wrote tick {tick} to {}", export.output.display());
+        }
+        Some("gif") => {
+            let mut ticks = vec![];
+            for tick in (export.tick..).step_by(export.every).take(export.frames) {
+                let (reached, _) = frames.get(tick);
+                ticks.push(reached);
+                if reached < tick {
+                    break;
+                }
+            }
+            ticks.dedup();
+            let drawn: Vec<String> = ticks
+                .iter()
+                .map(|&tick| frames.get(tick).1.to_string())
+                .collect();
+
+            render::write_gif(&export.output, &drawn, &export.style, export.fps)?;
+            println!(
+                "Wrote {} ticks from {} to {}",
+                ticks.len(),
+                export.tick,
+                export.output.display()
+            );
+        }
+        _ => bail!(
+            "'{}' is not a .png, .gif or .svg file",
+            export.output.display()
+        ),
+    }
+
+    Ok(())
+}
+
+fn new(year: u16, day: u8) -> anyhow::Result<()> {
+    let store = aoc_common::default_store();
+    let written = scaffold::new_day(&aoc_common::year_directory(year), store.as_ref(), year, day)?;
+
+    for path in written {
+        println!("Wrote {}", path.display());
+    }
+    if !YEARS.iter().any(|registered| registered.year == year) {
+        println!("Add aoc{year}::YEAR to the runner's years to run it");
+    }
+
+    Ok(())
+}
+
+fn puzzle(base_url: &str, year: u16, day: u8, refresh: bool, width: usize) -> anyhow::Result<()> {
+    let store = aoc_common::default_store();
+    let page = puzzle::load_page(|| client(base_url), store.as_ref(), year, day, refresh)?;
+
+    print!(
+        "{}",
+        puzzle::render(&page, width, io::stdout().is_terminal())
+    );
+
+    Ok(())
+}
+
+fn examples(base_url: &str, year: u16, day: u8, page: Option<&Path>) -> anyhow::Result<()> {
+    let page = match page {
+        Some(page) => fs::read_to_string(page)
+            .with_context(|| format!("failed to read '{}'", page.display()))?,
+        None => {
+            let store = aoc_common::default_store();
+            puzzle::load_page(|| client(base_url), store.as_ref(), year, day, false)?
+        }
+    };
+    let examples = examples::Examples::extract(&page);
+    if examples.inputs.is_empty() {
+        bail!("there are no examples in the page");
+    }
+
+    for path in examples.save(&aoc_common::example_directory(year, day))? {
+        println!("Wrote {}", path.display());
+    }
+
+    Ok(())
+}
+
+fn list() {
+    for year in YEARS {
+        let days: Vec<_> = year.days.iter().map(|day| day.day.to_string()).collect();
+        println!("{}: {}", year.year, days.join(" "));
+    }
+}
+
+/// Environment variable holding the adventofcode.com session cookie
+const SESSION_VARIABLE: &str = "AOC_SESSION";
+
+/// File next to the inputs with the time of the latest request to the site
+const THROTTLE_FILE: &str = ".aoc-last-request";
+
+fn client(base_url: &str) -> anyhow::Result<Client> {
+    let session = env::var(SESSION_VARIABLE).with_context(|| {
+        format!("set {SESSION_VARIABLE} to the session cookie from adventofcode.com")
+    })?;
+
+    let throttle_file = aoc_common::default_store().root().join(THROTTLE_FILE);
+
+    Ok(Client::new(base_url, &session).with_throttle_file(throttle_file))
+}
+
+fn fetch(base_url: &str, year: u16, day: Option<u8>, force: bool) -> anyhow::Result<()> {
+    let days = match day {
+        Some(day) => vec![day],
+        None => {
+            let Some(registered_year) = YEARS.iter().find(|registered| registered.year == year)
+            else {
+                bail!("there are no solutions for {year}, pass a day to fetch");
+            };
+            registered_year.days.iter().map(|day| day.day).collect()
+        }
+    };
+
+    let client = client(base_url)?;
+    let store = aoc_common::default_store();
+
+    for day in days {
+        match fetch::fetch_input(&client, store.as_ref(), year, day, force)? {
+            Fetched::Cached(path) => println!("{year} day {day} is cached at {}", path.display()),
+            Fetched::Downloaded(path) => {
+                println!("Downloaded {year} day {day} to {}", path.display())
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn submit(
+    base_url: &str,
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: Option<String>,
+    input: Option<PathBuf>,
+) -> anyhow::Result<()> {
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let input = InputSource::resolve(input.as_deref(), year, day).read()?;
+            let answer = find_day(year, day)?
+                .solve(part, &input)?
+                .with_context(|| format!("part {part} of {year} day {day} is unsolved"))?;
+            if let Answer::Image(_) = answer {
+                bail!("part {part} of {year} day {day} is an image, read it and pass the answer:\n{answer}");
+            }
+            answer.to_string()
+        }
+    };
+
+    let mut ledger = Ledger::load(year)?;
+    let client = client(base_url)?;
+    let verdict = submit::submit(
+        &client,
+        ledger.part_mut(day, part),
+        year,
+        day,
+        part,
+        &answer,
+    )?;
+    ledger.save(year)?;
+
+    println!("{year} day {day} part {part}: {answer} is {verdict}");
+    Ok(())
+}
+
+fn check(year: Option<u16>, day: Option<u8>) -> anyhow::Result<()> {
+    let years = YEARS
+        .iter()
+        .filter(|registered| year.is_none_or(|year| year == registered.year));
+    let store = aoc_common::default_store();
+
+    let mut failures = 0;
+    for year in years {
+        let ledger = Ledger::load(year.year)?;
+        for check in regression::check_year(year, day, &ledger, store.as_ref()) {
+            if check.outcome.is_failure() {
+                failures += 1;
+            }
+            println!("{check}");
+        }
+    }
+
+    if failures > 0 {
+        bail!("{failures} answers no longer match the ledger");
+    }
+    Ok(())
+}
+
+fn bench(
+    year: Option<u16>,
+    day: Option<u8>,
+    options: bench::Options,
+    json: Option<PathBuf>,
+    baseline: Option<PathBuf>,
+    entry: Option<u64>,
+    threshold: f64,
+) -> anyhow::Result<()> {
+    // Load the baseline first so a bad path fails before the slow part, and
+    // before this run is added to it if it's also the `--json` file
+    let entry = entry.map(|entry| entry as usize);
+    let baseline = baseline
+        .as_deref()
+        .map(|path| Report::from_history(path, entry))
+        .transpose()?;
+    let years = YEARS
+        .iter()
+        .filter(|registered| year.is_none_or(|year| year == registered.year));
+    let store = aoc_common::default_store();
+
+    let mut report = Report::now();
+    for year in years {
+        let days = year
+            .days
+            .iter()
+            .filter(|registered| day.is_none_or(|day| day == registered.day));
+
+        for day in days {
+            let input_path = store.path(year.year, day.day);
+            let Ok(input) = InputSource::File(input_path).read() else {
+                eprintln!("{} day {}: skipped, no input", year.year, day.day);
+                continue;
+            };
+
+            eprintln!("{} day {}: benchmarking", year.year, day.day);
+            match bench::bench_day(year.year, day, &input, options) {
+                Ok(timings) => report.days.push(timings),
+                Err(error) => eprintln!("{} day {}: skipped, {error:#}", year.year, day.day),
+            }
+        }
+    }
+
+    print!("{report}");
+
+    if let Some(path) = &json {
+        report.append(path)?;
+    }
+
+    let Some(baseline) = baseline else {
+        return Ok(());
+    };
+    let regressions = report.regressions(&baseline, threshold);
+    for regression in &regressions {
+        println!("REGRESSED {regression}");
+    }
+    if !regressions.is_empty() {
+        bail!(
+            "{} timings regressed against the baseline",
+            regressions.len()
+        );
+    }
+    Ok(())
+}
+
+fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run {
+            year,
+            day,
+            part,
+            input,
+            json,
+        } => run(year, day, part, input, json),
+        Command::Animate {
+            year,
+            day,
+            fps,
+            input,
+        } => animate(year, day, fps, input),
+        Command::Export {
+            year,
+            day,
+            output,
+            tick,
+            frames,
+            every,
+            fps,
+            scale,
+            palette,
+            input,
+        } => {
+            let export_options = Export {
+                output,
+                tick,
+                frames,
+                every: every as usize,
+                fps,
+                style: render::Style { palette, scale },
+            };
+            export(year, day, export_options, input)
+        }
+        Command::List => {
+            list();
+            Ok(())
+        }
+        Command::New { year, day } => new(year, day),
+        Command::Puzzle {
+            year,
+            day,
+            refresh,
+            width,
+        } => puzzle(&cli.base_url, year, day, refresh, width),
+        Command::Examples { year, day, page } => {
+            examples(&cli.base_url, year, day, page.as_deref())
+        }
+        Command::Fetch { year, day, force } => fetch(&cli.base_url, year, day, force),
+        Command::Check { year, day } => check(year, day),
+        Command::Bench {
+            year,
+            day,
+            warmup,
+            runs,
+            json,
+            baseline,
+            entry,
+            threshold,
+        } => {
+            let options = bench::Options { warmup, runs };
+            bench(year, day, options, json, baseline, entry, threshold)
+        }
+        Command::Submit {
+            year,
+            day,
+            part,
+            answer,
+            input,
+        } => submit(&cli.base_url, year, day, part, answer, input),
+    }
+}
@@ -0,0 +1,295 @@
+use crate::{
+    client::Client,
+    html::{decode, tokens, Token},
+};
+use anyhow::Context;
+use aoc_common::InputStore;
+use std::{fs, path::PathBuf};
+
+/// Where the puzzle page of `day` is cached, next to its input in `store`,
+/// e.g. `2024/data/day12.html`
+pub fn page_path(store: &dyn InputStore, year: u16, day: u8) -> PathBuf {
+    store
+        .path(year, day)
+        .with_file_name(format!("day{day}.html"))
+}
+
+/// The puzzle page of `year`'s `day` from `store`, downloading it first if it
+/// isn't cached or `refresh` is set. The page only has part 2 once part 1 is
+/// solved, so refreshing it is needed to read on.
+pub fn load_page(
+    client: impl FnOnce() -> anyhow::Result<Client>,
+    store: &dyn InputStore,
+    year: u16,
+    day: u8,
+    refresh: bool,
+) -> anyhow::Result<String> {
+    let path = page_path(store, year, day);
+    if path.exists() && !refresh {
+        return fs::read_to_string(&path)
+            .with_context(|| format!("failed to read '{}'", path.display()));
+    }
+
+    let page = client()?.puzzle(year, day)?;
+
+    if let Some(directory) = path.parent() {
+        fs::create_dir_all(directory)
+            .with_context(|| format!("failed to create '{}'", directory.display()))?;
+    }
+    fs::write(&path, &page).with_context(|| format!("failed to write '{}'", path.display()))?;
+
+    Ok(page)
+}
+
+const BOLD: &str = "\x1b[1m";
+const RESET: &str = "\x1b[0m";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Kind {
+    Heading,
+    Paragraph,
+    Item,
+    Code,
+}
+
+/// Accumulates the text of the block being read and the finished ones
+struct Blocks {
+    color: bool,
+    finished: Vec<(Kind, String)>,
+    kind: Kind,
+    current: String,
+}
+
+impl Blocks {
+    fn start(&mut self, kind: Kind) {
+        self.finish();
+        self.kind = kind;
+    }
+
+    fn finish(&mut self) {
+        let text = std::mem::take(&mut self.current);
+        if !text.trim().is_empty() {
+            self.finished.push((self.kind, text));
+        }
+        self.kind = Kind::Paragraph;
+    }
+
+    /// Emphasis is bold on a terminal and between asterisks otherwise
+    fn emphasize(&mut self, start: bool) {
+        match (self.color, start) {
+            (true, true) => self.current.push_str(BOLD),
+            (true, false) => self.current.push_str(RESET),
+            (false, _) => self.current.push('*'),
+        }
+    }
+
+    fn push_text(&mut self, text: &str) {
+        let text = decode(text);
+        if self.kind == Kind::Code {
+            self.current.push_str(&text);
+            return;
+        }
+
+        // Outside of code, line breaks in the HTML are just spaces
+        let mut words = text.split_whitespace().peekable();
+        if text.starts_with(char::is_whitespace) && words.peek().is_some() {
+            self.current.push(' ');
+        }
+        while let Some(word) = words.next() {
+            self.current.push_str(word);
+            if words.peek().is_some() {
+                self.current.push(' ');
+            }
+        }
+        if text.ends_with(char::is_whitespace) && !text.trim().is_empty() {
+            self.current.push(' ');
+        }
+    }
+}
+
+/// Width of `text` on a terminal, without its escape codes
+fn visible_width(text: &str) -> usize {
+    let mut width = 0;
+    let mut escaped = false;
+    for c in text.chars() {
+        match c {
+            '\x1b' => escaped = true,
+            'm' if escaped => escaped = false,
+            _ if !escaped => width += 1,
+            _ => {}
+        }
+    }
+    width
+}
+
+/// `text` broken into lines of at most `width` columns, where all but the
+/// first line start with `indent`
+fn wrap(text: &str, width: usize, first: &str, indent: &str) -> String {
+    let mut lines = vec![];
+    let mut line = first.to_string();
+    for (index, word) in text.split_whitespace().enumerate() {
+        if index == 0 {
+            line.push_str(word);
+        } else if visible_width(&line) + 1 + visible_width(word) > width {
+            lines.push(line);
+            line = format!("{indent}{word}");
+        } else {
+            line.push(' ');
+            line.push_str(word);
+        }
+    }
+    lines.push(line);
+    lines.join("\n")
+}
+
+/// The puzzle descriptions on `page` as text for a terminal `width` columns
+/// wide, with code blocks indented and emphasis marked, or in bold if `color`
+pub fn render(page: &str, width: usize, color: bool) -> String {
+    let main = page
+        .split_once("<main>")
+        .and_then(|(_, main)| Some(main.split_once("</main>")?.0))
+        .unwrap_or(page);
+
+    let mut blocks = Blocks {
+        color,
+        finished: vec![],
+        kind: Kind::Paragraph,
+        current: String::new(),
+    };
+    // The answer forms and scripts aren't part of the description
+    let mut hidden = 0;
+
+    for token in tokens(main) {
+        match token {
+            Token::Open(tag) if matches!(tag.as_str(), "form" | "script" | "style") => hidden += 1,
+            Token::Close(tag) if matches!(tag.as_str(), "form" | "script" | "style") => hidden -= 1,
+            _ if hidden > 0 => {}
+            Token::Open(tag) => match tag.as_str() {
+                "h2" => blocks.start(Kind::Heading),
+                "p" | "article" | "ul" => blocks.start(Kind::Paragraph),
+                "li" => blocks.start(Kind::Item),
+                "pre" => blocks.start(Kind::Code),
+                "em" if blocks.kind != Kind::Code => blocks.emphasize(true),
+                _ => {}
+            },
+            Token::Close(tag) => match tag.as_str() {
+                "h2" | "p" | "li" | "pre" | "article" | "ul" => blocks.finish(),
+                "em" if blocks.kind != Kind::Code => blocks.emphasize(false),
+                _ => {}
+            },
+            Token::Text(text) => blocks.push_text(text),
+        }
+    }
+    blocks.finish();
+
+    let rendered: Vec<_> = blocks
+        .finished
+        .iter()
+        .map(|(kind, text)| match kind {
+            Kind::Heading if color => format!("{BOLD}{}{RESET}", text.trim()),
+            Kind::Heading => text.trim().to_string(),
+            Kind::Paragraph => wrap(text, width, "", ""),
+            Kind::Item => wrap(text, width, "- ", "  "),
+            Kind::Code => text
+                .trim_end()
+                .lines()
+                .map(|line| format!("    {line}"))
+                .collect::<Vec<_>>()
+                .join("\n"),
+        })
+        .collect();
+
+    rendered.join("\n\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_server::MockServer;
+    use aoc_common::InputDirectory;
+    use indoc::indoc;
+
+    const PAGE: &str = indoc! {r#"
+        <html><head><title>Day 7</title></head><body>
+        <header><h1>Advent of Code</h1></header>
+        <main>
+        <script>window.x = "<p>";</script>
+        <article class="day-desc"><h2>--- Day 7: Laboratories ---</h2><p>You thank the
+        elves and <em>head</em> down to the lab &amp; look around.</p>
+        <ul>
+        <li>Beams move <em>downward</em>.</li>
+        </ul>
+        <pre><code>..S..
+        .^.<em>^</em>.
+        </code></pre>
+        <p>It splits <code><em>21</em></code> times.</p>
+        </article>
+        <p>Your puzzle answer was <code>1594</code>.</p>
+        <article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Count timelines.</p></article>
+        <form method="post"><p>Answer: <input type="text" name="answer"/></p></form>
+        </main>
+        </body></html>
+    "#};
+
+    #[test]
+    fn renders_description_as_text() {
+        assert_eq!(
+            render(PAGE, 40, false),
+            indoc! {"
+                --- Day 7: Laboratories ---
+
+                You thank the elves and *head* down to
+                the lab & look around.
+
+                - Beams move *downward*.
+
+                    ..S..
+                    .^.^.
+
+                It splits *21* times.
+
+                Your puzzle answer was 1594.
+
+                --- Part Two ---
+
+                Count timelines.
+            "}
+        );
+    }
+
+    #[test]
+    fn emphasizes_in_bold_on_terminals() {
+        let rendered = render(PAGE, 80, true);
+
+        assert!(rendered.starts_with(&format!("{BOLD}--- Day 7: Laboratories ---{RESET}")));
+        assert!(rendered.contains(&format!("and {BOLD}head{RESET} down")));
+    }
+
+    #[test]
+    fn wraps_without_counting_escape_codes() {
+        let text = format!("{BOLD}aaa{RESET} bbb ccc");
+
+        assert_eq!(wrap(&text, 7, "", ""), format!("{BOLD}aaa{RESET} bbb\nccc"));
+    }
+
+    #[test]
+    fn caches_downloaded_page() {
+        let server = MockServer::start(vec![(200, "<main>puzzle</main>")]);
+        let directory = tempfile::tempdir().unwrap();
+        let store = InputDirectory(directory.path().to_path_buf());
+        let client = || Ok(Client::new(&server.url(), "secret"));
+
+        let page = load_page(client, &store, 2024, 7, false).unwrap();
+        let cached = load_page(|| unreachable!(), &store, 2024, 7, false).unwrap();
+
+        assert_eq!(page, "<main>puzzle</main>");
+        assert_eq!(cached, page);
+        assert_eq!(
+            fs::read_to_string(directory.path().join("2024/day7.html")).unwrap(),
+            page
+        );
+        assert!(server.requests()[0]
+            .to_lowercase()
+            .starts_with("get /2024/day/7 "));
+    }
+}
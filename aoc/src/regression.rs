@@ -0,0 +1,195 @@
+use crate::ledger::Ledger;
+use aoc_common::{Answer, InputSource, InputStore, Year};
+use std::fmt;
+
+#[derive(Debug, PartialEq)]
+pub enum Outcome {
+    Passed,
+    Failed {
+        expected: String,
+        actual: Option<Answer>,
+    },
+    /// The input couldn't be parsed, or the part failed to solve it
+    Error(String),
+    /// Nothing to compare against, e.g. because the input isn't downloaded
+    Skipped(&'static str),
+}
+
+impl Outcome {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Outcome::Failed { .. } | Outcome::Error(_))
+    }
+}
+
+/// The result of running one part of a day on its real input
+#[derive(Debug)]
+pub struct Check {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub outcome: Outcome,
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} day {} part {}: ", self.year, self.day, self.part)?;
+        match &self.outcome {
+            Outcome::Passed => write!(f, "ok"),
+            Outcome::Failed {
+                expected,
+                actual: Some(actual),
+            } => write!(f, "FAILED, expected {expected} but got {actual}"),
+            Outcome::Failed {
+                expected,
+                actual: None,
+            } => write!(f, "FAILED, expected {expected} but the part is unsolved"),
+            Outcome::Error(error) => write!(f, "ERROR, {error}"),
+            Outcome::Skipped(reason) => write!(f, "skipped, {reason}"),
+        }
+    }
+}
+
+/// Runs each of `year`'s registered days, optionally only `day`, on its input
+/// in `store` and compares the answers with the accepted ones in `ledger`
+pub fn check_year(
+    year: &Year,
+    day: Option<u8>,
+    ledger: &Ledger,
+    store: &dyn InputStore,
+) -> Vec<Check> {
+    let days = year
+        .days
+        .iter()
+        .filter(|registered| day.is_none_or(|day| day == registered.day));
+
+    days.flat_map(|registered| {
+        let input_path = store.path(year.year, registered.day);
+        let input = input_path
+            .exists()
+            .then(|| InputSource::File(input_path).read().ok())
+            .flatten()
+            .map(|input| registered.solution.parse(&input));
+
+        [1, 2].map(|part| {
+            let expected = ledger
+                .part(registered.day, part)
+                .and_then(|record| record.correct.clone());
+
+            let outcome = match (expected, &input) {
+                (None, _) => Outcome::Skipped("no accepted answer"),
+                (Some(_), None) => Outcome::Skipped("no input"),
+                (Some(_), Some(Err(error))) => Outcome::Error(format!("{error:#}")),
+                (Some(expected), Some(Ok(input))) => {
+                    let actual = match part {
+                        1 => registered.solution.part1(input),
+                        _ => registered.solution.part2(input),
+                    };
+                    match actual {
+                        Err(error) => Outcome::Error(format!("{error:#}")),
+                        Ok(Some(actual)) if actual == expected => Outcome::Passed,
+                        Ok(actual) => Outcome::Failed { expected, actual },
+                    }
+                }
+            };
+
+            Check {
+                year: year.year,
+                day: registered.day,
+                part,
+                outcome,
+            }
+        })
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{Day, InputDirectory, Solution};
+    use std::fs;
+
+    struct Lines;
+
+    impl Solution for Lines {
+        type Input = Vec<String>;
+
+        fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+            if input.is_empty() {
+                anyhow::bail!("input is empty");
+            }
+            Ok(input.lines().map(String::from).collect())
+        }
+
+        fn part1(&self, lines: &Self::Input) -> Option<Answer> {
+            Some(lines.len().into())
+        }
+    }
+
+    const YEAR: Year = Year {
+        year: 2024,
+        days: &[
+            Day::new(1, &Lines),
+            Day::new(2, &Lines),
+            Day::new(3, &Lines),
+        ],
+    };
+
+    #[test]
+    fn compares_with_accepted_answers() {
+        let directory = tempfile::tempdir().unwrap();
+        let store = InputDirectory(directory.path().to_path_buf());
+        fs::create_dir_all(directory.path().join("2024")).unwrap();
+        fs::write(store.path(2024, 1), "a\nb\n").unwrap();
+        fs::write(store.path(2024, 3), "").unwrap();
+
+        let mut ledger = Ledger::default();
+        ledger.part_mut(1, 1).correct = Some("2".to_string());
+        ledger.part_mut(1, 2).correct = Some("7".to_string());
+        ledger.part_mut(2, 1).correct = Some("3".to_string());
+        ledger.part_mut(3, 1).correct = Some("0".to_string());
+
+        let outcomes: Vec<_> = check_year(&YEAR, None, &ledger, &store)
+            .into_iter()
+            .map(|check| (check.day, check.part, check.outcome))
+            .collect();
+
+        assert_eq!(
+            outcomes,
+            vec![
+                (1, 1, Outcome::Passed),
+                (
+                    1,
+                    2,
+                    Outcome::Failed {
+                        expected: "7".to_string(),
+                        actual: None
+                    }
+                ),
+                (2, 1, Outcome::Skipped("no input")),
+                (2, 2, Outcome::Skipped("no accepted answer")),
+                (3, 1, Outcome::Error("input is empty".to_string())),
+                (3, 2, Outcome::Skipped("no accepted answer")),
+            ]
+        );
+    }
+
+    /// Runs every registered day on its cached real input, skipping days
+    /// without an input or an accepted answer
+    #[test]
+    fn registered_days_give_accepted_answers() {
+        let store = aoc_common::default_store();
+
+        let failures: Vec<_> = crate::YEARS
+            .iter()
+            .flat_map(|year| {
+                let ledger = Ledger::load(year.year).unwrap();
+                check_year(year, None, &ledger, store.as_ref())
+            })
+            .filter(|check| check.outcome.is_failure())
+            .map(|check| check.to_string())
+            .collect();
+
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+}
@@ -0,0 +1,266 @@
+use anyhow::{bail, Context};
+use aoc_common::{Grid, Point2};
+use std::{collections::HashMap, fs::File, io::BufWriter, path::Path, str::FromStr};
+
+type Colour = [u8; 3];
+
+const BACKGROUND: Colour = [15, 15, 35];
+
+/// Colours for cells that the palette doesn't name, picked by their character
+const COLOURS: [Colour; 12] = [
+    [230, 25, 75],
+    [60, 180, 75],
+    [255, 225, 25],
+    [67, 99, 216],
+    [245, 130, 49],
+    [145, 30, 180],
+    [66, 212, 244],
+    [240, 50, 230],
+    [191, 239, 69],
+    [250, 190, 212],
+    [70, 153, 144],
+    [220, 190, 255],
+];
+
+/// The colour of each kind of cell, by the character it's drawn as
+#[derive(Debug, Clone, PartialEq)]
+pub struct Palette(HashMap<char, Colour>);
+
+impl Palette {
+    pub fn colour(&self, cell: char) -> Colour {
+        self.0.get(&cell).copied().unwrap_or(match cell {
+            ' ' | '.' => BACKGROUND,
+            '#' => [255, 255, 255],
+            _ => COLOURS[cell as usize % COLOURS.len()],
+        })
+    }
+}
+
+/// Colours such as `#=ffffff,O=c04000`, which other cells keep their default
+/// colour next to
+impl FromStr for Palette {
+    type Err = anyhow::Error;
+
+    fn from_str(palette: &str) -> anyhow::Result<Self> {
+        let colours = palette
+            .split(',')
+            .filter(|entry| !entry.is_empty())
+            .map(|entry| {
+                let mut chars = entry.chars();
+                let (Some(cell), Some('='), hex) = (chars.next(), chars.next(), chars.as_str())
+                else {
+                    bail!("expected a cell and its colour like '#=ffffff' but found '{entry}'");
+                };
+                let colour = u32::from_str_radix(hex, 16)
+                    .ok()
+                    .filter(|_| hex.len() == 6)
+                    .with_context(|| format!("'{hex}' is not a colour like 'ffffff'"))?;
+                let [_, red, green, blue] = colour.to_be_bytes();
+
+                Ok((cell, [red, green, blue]))
+            });
+
+        Ok(Palette(colours.collect::<anyhow::Result<_>>()?))
+    }
+}
+
+/// How frames are turned into pixels
+#[derive(Debug, Clone)]
+pub struct Style {
+    pub palette: Palette,
+    /// Width and height in pixels of each cell
+    pub scale: u32,
+}
+
+/// The characters of a drawn frame as a grid `width` by `height` cells, with
+/// short lines padded with spaces
+fn cells(frame: &str, width: usize, height: usize) -> Grid<char> {
+    let mut grid = Grid::new(width, height, ' ');
+    for (y, line) in frame.lines().enumerate().take(height) {
+        for (x, cell) in line.chars().enumerate().take(width) {
+            grid[Point2::new(x as i32, y as i32)] = cell;
+        }
+    }
+    grid
+}
+
+/// The width and height in cells that fits every one of `frames`
+fn size<'a>(frames: impl IntoIterator<Item = &'a str>) -> (usize, usize) {
+    frames.into_iter().fold((0, 0), |(width, height), frame| {
+        let frame_width = frame.lines().map(|line| line.chars().count()).max();
+        (
+            width.max(frame_width.unwrap_or(0)),
+            height.max(frame.lines().count()),
+        )
+    })
+}
+
+/// Each cell of `grid` turned into a `scale` by `scale` square of `pixel`s,
+/// row by row
+fn pixels<T>(grid: &Grid<char>, scale: u32, mut pixel: impl FnMut(char) -> T) -> Vec<T>
+where
+    T: Clone,
+{
+    let scale = scale as usize;
+    let mut pixels = Vec::with_capacity(grid.width() * grid.height() * scale * scale);
+
+    for row in grid.rows() {
+        let row: Vec<T> = row
+            .iter()
+            .flat_map(|&cell| std::iter::repeat_n(pixel(cell), scale))
+            .collect();
+        for _ in 0..scale {
+            pixels.extend_from_slice(&row);
+        }
+    }
+    pixels
+}
+
+fn create(path: &Path) -> anyhow::Result<BufWriter<File>> {
+    let file =
+        File::create(path).with_context(|| format!("failed to create '{}'", path.display()))?;
+    Ok(BufWriter::new(file))
+}
+
+/// Draws `frame` to `path` as a PNG
+pub fn write_png(path: &Path, frame: &str, style: &Style) -> anyhow::Result<()> {
+    let (width, height) = size([frame]);
+    let grid = cells(frame, width, height);
+    let pixels = pixels(&grid, style.scale, |cell| style.palette.colour(cell));
+
+    let scaled = |cells: usize| cells as u32 * style.scale;
+    let mut encoder = png::Encoder::new(create(path)?, scaled(width), scaled(height));
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.write_header()?.write_image_data(&pixels.concat())?;
+
+    Ok(())
+}
+
+/// Draws `frames` to `path` as a GIF that loops through them at `fps`
+pub fn write_gif(
+    path: &Path,
+    frames: &[impl AsRef<str>],
+    style: &Style,
+    fps: u32,
+) -> anyhow::Result<()> {
+    let (width, height) = size(frames.iter().map(AsRef::as_ref));
+    let grids: Vec<Grid<char>> = frames
+        .iter()
+        .map(|frame| cells(frame.as_ref(), width, height))
+        .collect();
+
+    // Every frame shares one palette with an entry for each kind of cell
+    let mut indices = HashMap::new();
+    let mut palette = vec![];
+    for grid in &grids {
+        for (_, &cell) in grid.iter() {
+            if !indices.contains_key(&cell) {
+                indices.insert(cell, indices.len());
+                palette.extend(style.palette.colour(cell));
+            }
+        }
+    }
+    if indices.len() > 256 {
+        bail!(
+            "a GIF can't have more than 256 kinds of cells, but these frames have {}",
+            indices.len()
+        );
+    }
+
+    let scaled = |cells: usize| -> anyhow::Result<u16> {
+        u16::try_from(cells * style.scale as usize).context("the frames are too large for a GIF")
+    };
+    let (gif_width, gif_height) = (scaled(width)?, scaled(height)?);
+    let mut encoder = gif::Encoder::new(create(path)?, gif_width, gif_height, &palette)?;
+    encoder.set_repeat(gif::Repeat::Infinite)?;
+
+    for grid in &grids {
+        let pixels = pixels(grid, style.scale, |cell| indices[&cell] as u8);
+        let mut frame = gif::Frame::from_indexed_pixels(gif_width, gif_height, pixels, None);
+        // In hundredths of a second
+        frame.delay = (100 / fps.max(1)) as u16;
+        encoder.write_frame(&frame)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn style(scale: u32) -> Style {
+        Style {
+            palette: "#=ff0000,O=00ff00".parse().unwrap(),
+            scale,
+        }
+    }
+
+    #[test]
+    fn parses_palettes() {
+        let palette: Palette = "#=ffffff,O=c04000".parse().unwrap();
+
+        assert_eq!(palette.colour('O'), [0xc0, 0x40, 0x00]);
+        assert_eq!(palette.colour('.'), BACKGROUND);
+        assert_eq!(palette.colour('1'), palette.colour('1'));
+        assert_ne!(palette.colour('1'), palette.colour('2'));
+        assert!("#ffffff".parse::<Palette>().is_err());
+        assert!("#=fff".parse::<Palette>().is_err());
+        assert!("#=gggggg".parse::<Palette>().is_err());
+    }
+
+    #[test]
+    fn pads_ragged_frames() {
+        let grid = cells("ab\nc", 3, 3);
+
+        assert_eq!(grid.to_string(), "ab \nc  \n   ");
+        assert_eq!(size(["ab\nc", "abcd"]), (4, 2));
+    }
+
+    #[test]
+    fn scales_cells_into_squares() {
+        let grid = cells("#.", 2, 1);
+
+        assert_eq!(
+            pixels(&grid, 2, |cell| cell),
+            ['#', '#', '.', '.', '#', '#', '.', '.']
+        );
+    }
+
+    #[test]
+    fn writes_pngs() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("frame.png");
+
+        write_png(&path, "#.\n.O", &style(3)).unwrap();
+
+        let decoder = png::Decoder::new(File::open(&path).unwrap());
+        let mut reader = decoder.read_info().unwrap();
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut pixels).unwrap();
+        assert_eq!((info.width, info.height), (6, 6));
+        assert_eq!(pixels[..3], [255, 0, 0]);
+        assert_eq!(pixels[pixels.len() - 3..], [0, 255, 0]);
+    }
+
+    #[test]
+    fn writes_gifs() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("frames.gif");
+
+        write_gif(&path, &["#.", ".#\nO"], &style(2), 10).unwrap();
+
+        let mut options = gif::DecodeOptions::new();
+        options.set_color_output(gif::ColorOutput::RGBA);
+        let mut decoder = options.read_info(File::open(&path).unwrap()).unwrap();
+        assert_eq!((decoder.width(), decoder.height()), (4, 4));
+
+        let mut frames = 0;
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            assert_eq!(frame.delay, 10);
+            frames += 1;
+        }
+        assert_eq!(frames, 2);
+    }
+}
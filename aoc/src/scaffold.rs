@@ -0,0 +1,433 @@
+use anyhow::{bail, Context};
+use aoc_common::InputStore;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// How a year lays out the two parts of a day
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Layout {
+    /// Top-level functions under `// Part 1` and `// Part 2` comments
+    Comments,
+    /// Functions inside `mod part1` and `mod part2`
+    Modules,
+}
+
+impl Layout {
+    pub fn of(year: u16) -> Self {
+        match year {
+            2025 => Layout::Modules,
+            _ => Layout::Comments,
+        }
+    }
+}
+
+/// Source of a new day, with both parts left to solve and a test of the
+/// puzzle's first example, as extracted by `aoc examples`
+pub fn day_file(year: u16, day: u8) -> String {
+    let (parts, part1, part2, tests) = match Layout::of(year) {
+        Layout::Comments => (PARTS_AS_FUNCTIONS, "part1", "part2", TESTS_OF_FUNCTIONS),
+        Layout::Modules => (
+            PARTS_AS_MODULES,
+            "part1::solve",
+            "part2::solve",
+            TESTS_OF_MODULES,
+        ),
+    };
+
+    let tests = tests
+        .replace("{year}", &year.to_string())
+        .replace("{day}", &day.to_string());
+
+    format!(
+        r#"use aoc_common::{{read_input, Solution}};
+
+fn parse(input: &str) -> Vec<String> {{
+    input.lines().map(String::from).collect()
+}}
+{parts}
+pub struct Day{day};
+
+impl Solution for Day{day} {{
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {{
+        Ok(parse(input))
+    }}
+
+    // The runner skips each part until it is added here
+}}
+
+fn main() -> anyhow::Result<()> {{
+    let lines = parse(&read_input({year}, {day})?);
+
+    println!("Part 1: {{}}", {part1}(&lines));
+    println!("Part 2: {{}}", {part2}(&lines));
+
+    Ok(())
+}}
+
+#[cfg(test)]
+mod tests {{
+{tests}}}
+"#
+    )
+}
+
+const PARTS_AS_FUNCTIONS: &str = r#"
+// Part 1
+fn part1(_lines: &[String]) -> usize {
+    todo!()
+}
+
+// Part 2
+fn part2(_lines: &[String]) -> usize {
+    todo!()
+}
+"#;
+
+const PARTS_AS_MODULES: &str = r#"
+mod part1 {
+    pub fn solve(_lines: &[String]) -> usize {
+        todo!()
+    }
+}
+
+mod part2 {
+    pub fn solve(_lines: &[String]) -> usize {
+        todo!()
+    }
+}
+"#;
+
+// `{year}` and `{day}` are filled in by `day_file`
+const TESTS_OF_FUNCTIONS: &str = r#"    use super::*;
+    use aoc_common::{example, example_answer};
+
+    #[test]
+    fn solves_part1() {
+        let example = example({year}, {day}, 1);
+
+        assert_eq!(
+            part1(&parse(&example)).to_string(),
+            example_answer({year}, {day}, 1)
+        );
+    }
+"#;
+
+const TESTS_OF_MODULES: &str = r#"    mod part1 {
+        use aoc_common::{example, example_answer};
+
+        #[test]
+        fn solves() {
+            let example = example({year}, {day}, 1);
+
+            assert_eq!(
+                super::super::part1::solve(&super::super::parse(&example)).to_string(),
+                example_answer({year}, {day}, 1)
+            );
+        }
+    }
+"#;
+
+/// The number in `line` between `prefix` and `suffix`, e.g. the `12` of
+/// `pub mod day12;`
+fn day_in(line: &str, prefix: &str, suffix: &str) -> Option<u8> {
+    line.trim()
+        .strip_prefix(prefix)?
+        .strip_suffix(suffix)?
+        .parse()
+        .ok()
+}
+
+/// Inserts `entry` into `lines` so that days stay in order. An existing entry
+/// ends on the line that `numbered` gives the day of and starts `lines_above`
+/// lines before it. `entry` is told the indentation of its neighbour and
+/// whether it goes after it.
+fn insert_in_order(
+    lines: &mut Vec<String>,
+    day: u8,
+    numbered: impl Fn(&str) -> Option<u8>,
+    lines_above: usize,
+    entry: impl Fn(&str, bool) -> Vec<String>,
+) -> anyhow::Result<()> {
+    let numbered: Vec<_> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| Some((index, numbered(line)?)))
+        .collect();
+
+    if numbered.iter().any(|&(_, registered)| registered == day) {
+        bail!("day {day} is already registered");
+    }
+
+    let (index, position, after) = match numbered.iter().rev().find(|&&(_, n)| n < day) {
+        Some(&(index, _)) => (index, index + 1, true),
+        None => match numbered.first() {
+            Some(&(index, _)) => (index, index - lines_above, false),
+            None => bail!("there are no registered days to add day {day} next to"),
+        },
+    };
+
+    let indentation: String = lines[index]
+        .chars()
+        .take_while(|c| c.is_whitespace())
+        .collect();
+    lines.splice(position..position, entry(&indentation, after));
+
+    Ok(())
+}
+
+/// `lib` with `day`'s module declared and added to the year's days
+pub fn register_module(lib: &str, day: u8) -> anyhow::Result<String> {
+    let mut lines: Vec<_> = lib.lines().map(String::from).collect();
+
+    insert_in_order(
+        &mut lines,
+        day,
+        |line| day_in(line, "pub mod day", ";"),
+        1,
+        |_, _| {
+            vec![
+                format!("#[path = \"bin/day{day}.rs\"]"),
+                format!("pub mod day{day};"),
+            ]
+        },
+    )?;
+    insert_in_order(
+        &mut lines,
+        day,
+        |line| day_in(line.split(',').next()?, "Day::new(", ""),
+        0,
+        |indentation, _| {
+            vec![format!(
+                "{indentation}Day::new({day}, &day{day}::Day{day}),"
+            )]
+        },
+    )?;
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// `manifest` with a `[[bin]]` target for `year`'s `day`
+pub fn register_binary(manifest: &str, year: u16, day: u8) -> anyhow::Result<String> {
+    let mut lines: Vec<_> = manifest.lines().map(String::from).collect();
+
+    insert_in_order(
+        &mut lines,
+        day,
+        |line| day_in(line, "path = \"src/bin/day", ".rs\""),
+        2,
+        |_, after| {
+            let target = [
+                "[[bin]]".to_string(),
+                format!("name = \"aoc{year}-day{day}\""),
+                format!("path = \"src/bin/day{day}.rs\""),
+            ];
+            // Targets are separated by an empty line
+            if after {
+                [String::new()].into_iter().chain(target).collect()
+            } else {
+                target.into_iter().chain([String::new()]).collect()
+            }
+        },
+    )?;
+
+    Ok(lines.join("\n") + "\n")
+}
+
+fn write(path: &Path, contents: &str) -> anyhow::Result<()> {
+    fs::write(path, contents).with_context(|| format!("failed to write '{}'", path.display()))
+}
+
+/// Generates `day` in the crate at `year_directory`, registers it with the
+/// runner and creates an empty input in `store` for it. Returns the files that
+/// were written.
+pub fn new_day(
+    year_directory: &Path,
+    store: &dyn InputStore,
+    year: u16,
+    day: u8,
+) -> anyhow::Result<Vec<PathBuf>> {
+    let lib = year_directory.join("src/lib.rs");
+    let manifest = year_directory.join("Cargo.toml");
+    let day_path = year_directory.join(format!("src/bin/day{day}.rs"));
+
+    if !lib.exists() || !manifest.exists() {
+        bail!(
+            "there is no crate for {year} in '{}'",
+            year_directory.display()
+        );
+    }
+    if day_path.exists() {
+        bail!("'{}' already exists", day_path.display());
+    }
+
+    // Both are updated before anything is written, so a day that is already
+    // registered leaves the crate untouched
+    let lib_contents = register_module(&fs::read_to_string(&lib)?, day)
+        .with_context(|| format!("failed to register day {day} in '{}'", lib.display()))?;
+    let manifest_contents = register_binary(&fs::read_to_string(&manifest)?, year, day)
+        .with_context(|| format!("failed to add day {day} to '{}'", manifest.display()))?;
+
+    write(&day_path, &day_file(year, day))?;
+    write(&lib, &lib_contents)?;
+    write(&manifest, &manifest_contents)?;
+
+    let mut written = vec![day_path, lib, manifest];
+
+    let input = store.path(year, day);
+    if !input.exists() {
+        if let Some(directory) = input.parent() {
+            fs::create_dir_all(directory)
+                .with_context(|| format!("failed to create '{}'", directory.display()))?;
+        }
+        write(&input, "")?;
+        written.push(input);
+    }
+
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::InputDirectory;
+    use indoc::indoc;
+
+    const LIB: &str = indoc! {"
+        use aoc_common::{Day, Year};
+
+        #[path = \"bin/day1.rs\"]
+        pub mod day1;
+        #[path = \"bin/day3.rs\"]
+        pub mod day3;
+
+        pub const YEAR: Year = Year {
+            year: 2024,
+            days: &[
+                Day::new(1, &day1::Day1),
+                Day::new(3, &day3::Day3),
+            ],
+        };
+    "};
+
+    const MANIFEST: &str = indoc! {"
+        [package]
+        name = \"aoc2024\"
+
+        [[bin]]
+        name = \"aoc2024-day2\"
+        path = \"src/bin/day2.rs\"
+
+        [[bin]]
+        name = \"aoc2024-day3\"
+        path = \"src/bin/day3.rs\"
+
+        [dependencies]
+    "};
+
+    #[test]
+    fn registers_module_between_days() {
+        assert_eq!(
+            register_module(LIB, 2).unwrap(),
+            indoc! {"
+                use aoc_common::{Day, Year};
+
+                #[path = \"bin/day1.rs\"]
+                pub mod day1;
+                #[path = \"bin/day2.rs\"]
+                pub mod day2;
+                #[path = \"bin/day3.rs\"]
+                pub mod day3;
+
+                pub const YEAR: Year = Year {
+                    year: 2024,
+                    days: &[
+                        Day::new(1, &day1::Day1),
+                        Day::new(2, &day2::Day2),
+                        Day::new(3, &day3::Day3),
+                    ],
+                };
+            "}
+        );
+    }
+
+    #[test]
+    fn registers_binaries_in_order() {
+        let manifest = register_binary(MANIFEST, 2024, 1).unwrap();
+        let manifest = register_binary(&manifest, 2024, 12).unwrap();
+
+        assert_eq!(
+            manifest,
+            indoc! {"
+                [package]
+                name = \"aoc2024\"
+
+                [[bin]]
+                name = \"aoc2024-day1\"
+                path = \"src/bin/day1.rs\"
+
+                [[bin]]
+                name = \"aoc2024-day2\"
+                path = \"src/bin/day2.rs\"
+
+                [[bin]]
+                name = \"aoc2024-day3\"
+                path = \"src/bin/day3.rs\"
+
+                [[bin]]
+                name = \"aoc2024-day12\"
+                path = \"src/bin/day12.rs\"
+
+                [dependencies]
+            "}
+        );
+    }
+
+    #[test]
+    fn refuses_registered_day() {
+        assert!(register_module(LIB, 3).is_err());
+        assert!(register_binary(MANIFEST, 2024, 2).is_err());
+    }
+
+    #[test]
+    fn follows_year_layout() {
+        let day = day_file(2024, 16);
+        assert!(day.contains("// Part 1\nfn part1("));
+        assert!(day.contains("pub struct Day16;"));
+        assert!(day.contains("read_input(2024, 16)"));
+
+        let day = day_file(2025, 10);
+        assert!(day.contains("mod part1 {\n    pub fn solve("));
+        assert!(day.contains("part2::solve(&lines)"));
+    }
+
+    #[test]
+    fn writes_and_registers_new_day() {
+        let directory = tempfile::tempdir().unwrap();
+        let year_directory = directory.path().join("2024");
+        fs::create_dir_all(year_directory.join("src/bin")).unwrap();
+        fs::write(year_directory.join("src/lib.rs"), LIB).unwrap();
+        fs::write(year_directory.join("Cargo.toml"), MANIFEST).unwrap();
+        let store = InputDirectory(directory.path().join("inputs"));
+
+        let written = new_day(&year_directory, &store, 2024, 4).unwrap();
+
+        let day = year_directory.join("src/bin/day4.rs");
+        let input = directory.path().join("inputs/2024/day4");
+        assert_eq!(
+            written,
+            vec![
+                day.clone(),
+                year_directory.join("src/lib.rs"),
+                year_directory.join("Cargo.toml"),
+                input.clone(),
+            ]
+        );
+        assert_eq!(fs::read_to_string(day).unwrap(), day_file(2024, 4));
+        assert_eq!(fs::read_to_string(input).unwrap(), "");
+        assert!(new_day(&year_directory, &store, 2024, 4).is_err());
+    }
+}
@@ -0,0 +1,176 @@
+use crate::{client::Client, ledger::PartRecord};
+use anyhow::bail;
+use std::fmt;
+
+/// What the website said about a submitted answer
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// Answered too recently, with how long is left to wait if it was given
+    RateLimited(Option<String>),
+    /// The part has already been solved, so the answer was not checked
+    AlreadySolved,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "wrong, too high"),
+            Verdict::TooLow => write!(f, "wrong, too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::RateLimited(Some(wait)) => write!(f, "rate limited, wait {wait}"),
+            Verdict::RateLimited(None) => write!(f, "rate limited"),
+            Verdict::AlreadySolved => write!(f, "already solved"),
+        }
+    }
+}
+
+impl Verdict {
+    /// Reads the verdict from the page returned after submitting an answer
+    pub fn parse(page: &str) -> Option<Self> {
+        if page.contains("That's the right answer") {
+            Some(Verdict::Correct)
+        } else if page.contains("your answer is too high") {
+            Some(Verdict::TooHigh)
+        } else if page.contains("your answer is too low") {
+            Some(Verdict::TooLow)
+        } else if page.contains("That's not the right answer") {
+            Some(Verdict::Wrong)
+        } else if page.contains("You gave an answer too recently") {
+            let wait = page
+                .split_once("You have ")
+                .and_then(|(_, rest)| rest.split_once(" left to wait"))
+                .map(|(wait, _)| wait.to_string());
+            Some(Verdict::RateLimited(wait))
+        } else if page.contains("Did you already complete it?") {
+            Some(Verdict::AlreadySolved)
+        } else {
+            None
+        }
+    }
+
+    /// Adds what the verdict says about `answer` to `record`
+    pub fn record(&self, record: &mut PartRecord, answer: &str) {
+        match self {
+            Verdict::Correct => record.correct = Some(answer.to_string()),
+            Verdict::TooHigh => record.record_too_high(answer),
+            Verdict::TooLow => record.record_too_low(answer),
+            Verdict::Wrong => record.record_wrong(answer),
+            Verdict::RateLimited(_) | Verdict::AlreadySolved => {}
+        }
+    }
+}
+
+/// Submits `answer` unless `record` already knows it to be wrong
+pub fn submit(
+    client: &Client,
+    record: &mut PartRecord,
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> anyhow::Result<Verdict> {
+    if record.correct.as_deref() == Some(answer) {
+        return Ok(Verdict::AlreadySolved);
+    }
+    if let Some(reason) = record.rejection(answer) {
+        bail!("refusing to submit {answer} for {year} day {day} part {part}: {reason}");
+    }
+
+    let level = part.to_string();
+    let page = client.post_form(
+        &format!("/{year}/day/{day}/answer"),
+        &[("level", &level), ("answer", answer)],
+    )?;
+    let Some(verdict) = Verdict::parse(&page) else {
+        bail!("could not find a verdict in the response to {answer}");
+    };
+
+    verdict.record(record, answer);
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_server::MockServer;
+
+    const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; there are also some general tips on the <a href=\"/2024/about\">about page</a>, or you can ask for hints on the <a href=\"https://www.reddit.com/r/adventofcode/\" target=\"_blank\">subreddit</a>.  Please wait one minute before trying again. <a href=\"/2024/day/1\">[Return to Day 1]</a></p></article>";
+    const RATE_LIMITED: &str = "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 48s left to wait. <a href=\"/2024/day/1\">[Return to Day 1]</a></p></article>";
+
+    #[test]
+    fn parses_verdicts() {
+        assert_eq!(
+            Verdict::parse("<p>That's the right answer! You are one gold star closer"),
+            Some(Verdict::Correct)
+        );
+        assert_eq!(Verdict::parse(TOO_HIGH), Some(Verdict::TooHigh));
+        assert_eq!(
+            Verdict::parse("That's not the right answer; your answer is too low."),
+            Some(Verdict::TooLow)
+        );
+        assert_eq!(
+            Verdict::parse("That's not the right answer.  If you're stuck"),
+            Some(Verdict::Wrong)
+        );
+        assert_eq!(
+            Verdict::parse(RATE_LIMITED),
+            Some(Verdict::RateLimited(Some("48s".to_string())))
+        );
+        assert_eq!(
+            Verdict::parse(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            ),
+            Some(Verdict::AlreadySolved)
+        );
+        assert_eq!(Verdict::parse("<html></html>"), None);
+    }
+
+    #[test]
+    fn posts_answer_and_records_verdict() {
+        let server = MockServer::start(vec![(200, TOO_HIGH)]);
+        let client = Client::new(&server.url(), "secret");
+        let mut record = PartRecord::default();
+
+        let verdict = submit(&client, &mut record, 2024, 1, 2, "1234").unwrap();
+
+        assert_eq!(verdict, Verdict::TooHigh);
+        assert_eq!(record.too_high, Some(1234));
+
+        let request = server.requests().remove(0);
+        assert!(request.starts_with("POST /2024/day/1/answer HTTP/1.1"));
+        assert!(request.ends_with("level=2&answer=1234"));
+    }
+
+    #[test]
+    fn refuses_known_wrong_answer_locally() {
+        let server = MockServer::start(vec![]);
+        let client = Client::new(&server.url(), "secret");
+        let mut record = PartRecord::default();
+        record.record_too_high("1234");
+
+        let error = submit(&client, &mut record, 2024, 1, 2, "2000").unwrap_err();
+
+        assert!(error.to_string().starts_with("refusing to submit 2000"));
+        assert!(server.requests().is_empty());
+    }
+
+    #[test]
+    fn does_not_resubmit_correct_answer() {
+        let server = MockServer::start(vec![]);
+        let client = Client::new(&server.url(), "secret");
+        let mut record = PartRecord {
+            correct: Some("42".to_string()),
+            ..Default::default()
+        };
+
+        let verdict = submit(&client, &mut record, 2024, 1, 1, "42").unwrap();
+
+        assert_eq!(verdict, Verdict::AlreadySolved);
+        assert!(server.requests().is_empty());
+    }
+}
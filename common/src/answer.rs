@@ -0,0 +1,139 @@
+use serde::Serialize;
+use std::fmt;
+
+/// What a part of a day comes up with. Integers of any width are kept as
+/// numbers, so they print and serialize the same whatever type the day
+/// counted in.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+    /// Rows of an image, such as letters drawn on a screen, to be read by a
+    /// human
+    Image(Vec<String>),
+}
+
+impl Answer {
+    /// An image of each line of `picture` once it has been displayed
+    pub fn image(picture: impl fmt::Display) -> Self {
+        Answer::Image(picture.to_string().lines().map(String::from).collect())
+    }
+
+    /// Whether the answer takes up more than one line when printed
+    pub fn is_multiline(&self) -> bool {
+        matches!(self, Answer::Image(rows) if rows.len() > 1)
+    }
+}
+
+/// Images are printed one row per line, without a trailing newline
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(integer) => write!(f, "{integer}"),
+            Answer::Text(text) => write!(f, "{text}"),
+            Answer::Image(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
+
+/// Compares with an answer written down as text, such as an accepted one
+impl PartialEq<str> for Answer {
+    fn eq(&self, other: &str) -> bool {
+        match self {
+            Answer::Integer(integer) => other.trim().parse() == Ok(*integer),
+            Answer::Text(text) => text == other,
+            Answer::Image(rows) => other.lines().eq(rows.iter().map(String::as_str)),
+        }
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+impl PartialEq<String> for Answer {
+    fn eq(&self, other: &String) -> bool {
+        self == other.as_str()
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($integer:ty),+) => {
+        $(
+            impl From<$integer> for Answer {
+                fn from(integer: $integer) -> Self {
+                    Answer::Integer(integer as i128)
+                }
+            }
+        )+
+    };
+}
+
+impl_from_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, i128);
+
+/// Integers beyond `i128::MAX` are kept as their digits
+impl From<u128> for Answer {
+    fn from(integer: u128) -> Self {
+        i128::try_from(integer).map_or_else(|_| Answer::Text(integer.to_string()), Answer::Integer)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prints_integers_of_any_width_alike() {
+        assert_eq!(Answer::from(42u8), Answer::from(42usize));
+        assert_eq!(Answer::from(u64::MAX).to_string(), "18446744073709551615");
+        assert_eq!(Answer::from(-3i32).to_string(), "-3");
+        assert_eq!(Answer::from(u128::MAX).to_string(), u128::MAX.to_string());
+    }
+
+    #[test]
+    fn compares_with_written_answers() {
+        assert_eq!(Answer::from(12520), "12520");
+        assert_eq!(Answer::from(12520), "12520\n".to_string());
+        assert_ne!(Answer::from(12520), "1252");
+        assert_eq!(Answer::from("CVCWCRTVQ"), "CVCWCRTVQ");
+    }
+
+    #[test]
+    fn keeps_rows_of_images() {
+        let image = Answer::image("#..#\n####\n");
+
+        assert_eq!(image, Answer::Image(vec!["#..#".into(), "####".into()]));
+        assert_eq!(image.to_string(), "#..#\n####");
+        assert!(image.is_multiline());
+        assert!(!Answer::from(1).is_multiline());
+    }
+
+    #[test]
+    fn serializes_as_plain_json() {
+        let answers = [
+            Answer::from(u64::MAX),
+            Answer::from("CVCWCRTVQ"),
+            Answer::image("#.\n.#"),
+        ];
+
+        assert_eq!(
+            serde_json::to_string(&answers).unwrap(),
+            r##"[18446744073709551615,"CVCWCRTVQ",["#.",".#"]]"##
+        );
+    }
+}
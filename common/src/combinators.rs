@@ -0,0 +1,221 @@
+//! nom parsers for the shapes that puzzle inputs keep coming in, and
+//! [`finish`] to run one over a whole input and get a [`ParseError`] out of it
+
+use crate::{Grid, ParseError};
+use nom::{
+    bytes::complete::{tag, take_while1},
+    character::complete::{char, digit1, line_ending, one_of, space0, space1},
+    combinator::{map_res, opt, recognize},
+    error::{Error, ErrorKind},
+    multi::separated_list1,
+    sequence::{delimited, pair, separated_pair},
+    Finish, IResult, Parser,
+};
+use std::str::FromStr;
+
+/// An integer without a sign, such as `42`
+pub fn unsigned<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(digit1, str::parse)(input)
+}
+
+/// An integer with an optional sign, such as `-3` or `+7`
+pub fn signed<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(recognize(pair(opt(one_of("+-")), digit1)), str::parse)(input)
+}
+
+/// One or more `item`s separated by commas, with or without a space after
+/// each comma, such as `1,2,3` or `a, b`
+pub fn comma_list<'a, O>(
+    item: impl Parser<&'a str, O, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    separated_list1(pair(char(','), space0), item)
+}
+
+/// One or more `item`s separated by spaces, such as `7 6 4 2 1`
+pub fn space_list<'a, O>(
+    item: impl Parser<&'a str, O, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    separated_list1(space1, item)
+}
+
+/// One or more `item`s, each on its own line
+pub fn lines<'a, O>(
+    item: impl Parser<&'a str, O, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    separated_list1(line_ending, item)
+}
+
+/// The end of a line followed by an empty line, which separates sections
+pub fn blank_line(input: &str) -> IResult<&str, &str> {
+    recognize(pair(line_ending, line_ending))(input)
+}
+
+/// One or more `section`s separated by blank lines
+pub fn sections<'a, O>(
+    section: impl Parser<&'a str, O, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    separated_list1(blank_line, section)
+}
+
+/// A `key` and a `value` joined by `separator`, which may have spaces around
+/// it, such as `p=0,4` or `AAA = (BBB, CCC)`
+pub fn key_value<'a, K, V>(
+    key: impl Parser<&'a str, K, Error<&'a str>>,
+    separator: &'a str,
+    value: impl Parser<&'a str, V, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, (K, V)> {
+    separated_pair(key, delimited(space0, tag(separator), space0), value)
+}
+
+/// Lines of characters up to a blank line or the end of the input, with each
+/// character turned into a cell by `cell`. Fails on a character that `cell`
+/// rejects or on a line that is shorter or longer than the first.
+pub fn grid<'a, T>(
+    mut cell: impl FnMut(char) -> Option<T>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Grid<T>> {
+    move |input| {
+        let (rest, rows) = lines(take_while1(|c| c != '\n' && c != '\r'))(input)?;
+        let width = rows[0].chars().count();
+        let mut cells = vec![];
+
+        for row in &rows {
+            if row.chars().count() != width {
+                return Err(nom::Err::Failure(Error::new(*row, ErrorKind::Verify)));
+            }
+            for (index, c) in row.char_indices() {
+                let parsed = cell(c)
+                    .ok_or_else(|| nom::Err::Failure(Error::new(&row[index..], ErrorKind::Char)))?;
+                cells.push(parsed);
+            }
+        }
+
+        Ok((rest, Grid::from_cells(width, cells)))
+    }
+}
+
+/// Runs `parser` over the whole of `input`, which may only have blank lines
+/// left over. Failures and leftovers are reported as the line where parsing
+/// stopped not being what was `expected`.
+pub fn finish<'a, O>(
+    mut parser: impl Parser<&'a str, O, Error<&'a str>>,
+    input: &'a str,
+    expected: &str,
+) -> Result<O, ParseError> {
+    let (rest, output) = parser
+        .parse(input)
+        .finish()
+        .map_err(|error| ParseError::new(rest_of_line(error.input), expected))?;
+
+    // Lists stop before the first item that doesn't parse, leaving it over
+    if let Some(line) = rest.lines().find(|line| !line.trim().is_empty()) {
+        return Err(ParseError::new(line, expected));
+    }
+
+    Ok(output)
+}
+
+/// The text up to the end of the line, or the line break itself when there is
+/// nothing before it
+fn rest_of_line(text: &str) -> &str {
+    let end = text.find('\n').map_or(text.len(), |newline| newline + 1);
+    let line = text[..end].trim_end_matches(['\r', '\n']);
+
+    if line.is_empty() {
+        &text[..end]
+    } else {
+        line
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use nom::{character::complete::alpha1, sequence::tuple};
+
+    #[test]
+    fn parses_numbers() {
+        assert_eq!(unsigned::<u32>("42 rest"), Ok((" rest", 42)));
+        assert!(unsigned::<u32>("-42").is_err());
+        assert_eq!(signed::<i32>("-3,4"), Ok((",4", -3)));
+        assert_eq!(signed::<i32>("+7"), Ok(("", 7)));
+    }
+
+    #[test]
+    fn parses_lists() {
+        assert_eq!(
+            comma_list(unsigned::<u8>)("1,2, 3"),
+            Ok(("", vec![1, 2, 3]))
+        );
+        assert_eq!(
+            space_list(signed::<i8>)("7  -6 4"),
+            Ok(("", vec![7, -6, 4]))
+        );
+        assert_eq!(lines(alpha1)("ab\ncd\n"), Ok(("\n", vec!["ab", "cd"])));
+    }
+
+    #[test]
+    fn parses_key_values() {
+        let vector = || separated_pair(signed::<i32>, char(','), signed);
+
+        assert_eq!(
+            key_value(tag("p"), "=", vector())("p=0,-4"),
+            Ok(("", ("p", (0, -4))))
+        );
+        assert_eq!(
+            key_value(alpha1, "=", alpha1)("AAA = BBB"),
+            Ok(("", ("AAA", "BBB")))
+        );
+    }
+
+    #[test]
+    fn parses_sections() {
+        let input = indoc! {"
+            1
+            2
+
+            3
+        "};
+
+        assert_eq!(
+            finish(sections(lines(unsigned::<u32>)), input, "numbers"),
+            Ok(vec![vec![1, 2], vec![3]])
+        );
+    }
+
+    #[test]
+    fn parses_grids() {
+        let digit = |c: char| c.to_digit(10);
+        let (rest, parsed) = grid(digit)("12\n34\n\nrest").unwrap();
+
+        assert_eq!(parsed.to_string(), "12\n34");
+        assert_eq!(rest, "\n\nrest");
+        assert!(grid(digit)("12\n3x").is_err());
+        assert!(grid(digit)("12\n345").is_err());
+    }
+
+    #[test]
+    fn reports_where_parsing_stopped() {
+        let input = "1,2\n3,x\n";
+        let error = finish(lines(comma_list(unsigned::<u32>)), input, "numbers")
+            .unwrap_err()
+            .locate(input, 2024, 1);
+        assert_eq!(
+            error.to_string(),
+            "2024 day 1, line 2, column 2: expected numbers but found ',x'"
+        );
+
+        let input = "p=1";
+        let error = finish(
+            tuple((tag("p="), signed::<i32>, tag(","))),
+            input,
+            "a robot",
+        )
+        .unwrap_err()
+        .locate(input, 2024, 14);
+        assert_eq!(
+            error.to_string(),
+            "2024 day 14, line 1, column 4: expected a robot but the input ended"
+        );
+    }
+}
@@ -0,0 +1,297 @@
+use crate::Point2;
+use std::{
+    error::Error,
+    fmt,
+    ops::{Add, AddAssign, Sub},
+    str::FromStr,
+};
+
+/// One of the four orthogonal directions. `Up` is towards the first line of the
+/// input, so it decreases `y`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+/// One of the eight directions to a surrounding cell, including the diagonals
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+/// A character that isn't one of `^>v<`, `URDL` or `NESW`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDirectionError(pub String);
+
+impl fmt::Display for ParseDirectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "'{}' is not a direction", self.0)
+    }
+}
+
+impl Error for ParseDirectionError {}
+
+impl Direction {
+    /// Clockwise, starting from `Up`
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    /// A quarter turn to the right
+    pub fn clockwise(self) -> Self {
+        Self::ALL[(self.index() + 1) % 4]
+    }
+
+    /// A quarter turn to the left
+    pub fn counter_clockwise(self) -> Self {
+        Self::ALL[(self.index() + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self.index() + 2) % 4]
+    }
+
+    /// The step from a position to its neighbour in this direction
+    pub fn offset(self) -> Point2<i32> {
+        match self {
+            Direction::Up => Point2::new(0, -1),
+            Direction::Right => Point2::new(1, 0),
+            Direction::Down => Point2::new(0, 1),
+            Direction::Left => Point2::new(-1, 0),
+        }
+    }
+}
+
+impl Direction8 {
+    /// Clockwise, starting from `Up`
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    /// An eighth of a turn to the right
+    pub fn clockwise(self) -> Self {
+        Self::ALL[(self.index() + 1) % 8]
+    }
+
+    /// An eighth of a turn to the left
+    pub fn counter_clockwise(self) -> Self {
+        Self::ALL[(self.index() + 7) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self.index() + 4) % 8]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self.index() % 2 == 1
+    }
+
+    /// The step from a position to its neighbour in this direction
+    pub fn offset(self) -> Point2<i32> {
+        match self {
+            Direction8::Up => Point2::new(0, -1),
+            Direction8::UpRight => Point2::new(1, -1),
+            Direction8::Right => Point2::new(1, 0),
+            Direction8::DownRight => Point2::new(1, 1),
+            Direction8::Down => Point2::new(0, 1),
+            Direction8::DownLeft => Point2::new(-1, 1),
+            Direction8::Left => Point2::new(-1, 0),
+            Direction8::UpLeft => Point2::new(-1, -1),
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        Direction8::ALL[direction.index() * 2]
+    }
+}
+
+impl TryFrom<char> for Direction {
+    type Error = ParseDirectionError;
+
+    /// Parses arrows (`^>v<`), letters (`URDL`) or compass points (`NESW`)
+    fn try_from(direction: char) -> Result<Self, Self::Error> {
+        match direction {
+            '^' | 'U' | 'N' => Ok(Direction::Up),
+            '>' | 'R' | 'E' => Ok(Direction::Right),
+            'v' | 'D' | 'S' => Ok(Direction::Down),
+            '<' | 'L' | 'W' => Ok(Direction::Left),
+            other => Err(ParseDirectionError(other.to_string())),
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = ParseDirectionError;
+
+    fn from_str(direction: &str) -> Result<Self, Self::Err> {
+        let mut chars = direction.chars();
+        match (chars.next(), chars.next()) {
+            (Some(direction), None) => direction.try_into(),
+            _ => Err(ParseDirectionError(direction.to_string())),
+        }
+    }
+}
+
+/// Arrows, like in the puzzles' maps
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let arrow = match self {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+        };
+        write!(f, "{arrow}")
+    }
+}
+
+/// Takes one step from `point`, where `x` and `y` are each changed by at most one
+fn step<T>(point: Point2<T>, offset: Point2<i32>) -> Point2<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + From<u8>,
+{
+    let shift = |value: T, delta: i32| match delta {
+        1 => value + T::from(1),
+        -1 => value - T::from(1),
+        _ => value,
+    };
+
+    Point2::new(shift(point.x, offset.x), shift(point.y, offset.y))
+}
+
+impl<T> Add<Direction> for Point2<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + From<u8>,
+{
+    type Output = Self;
+
+    fn add(self, direction: Direction) -> Self {
+        step(self, direction.offset())
+    }
+}
+
+impl<T> Add<Direction8> for Point2<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + From<u8>,
+{
+    type Output = Self;
+
+    fn add(self, direction: Direction8) -> Self {
+        step(self, direction.offset())
+    }
+}
+
+impl<T> AddAssign<Direction> for Point2<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + From<u8> + Copy,
+{
+    fn add_assign(&mut self, direction: Direction) {
+        *self = *self + direction;
+    }
+}
+
+impl<T> AddAssign<Direction8> for Point2<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + From<u8> + Copy,
+{
+    fn add_assign(&mut self, direction: Direction8) {
+        *self = *self + direction;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turns() {
+        assert_eq!(Direction::Up.clockwise(), Direction::Right);
+        assert_eq!(Direction::Left.clockwise(), Direction::Up);
+        assert_eq!(Direction::Up.counter_clockwise(), Direction::Left);
+        assert_eq!(Direction::Down.opposite(), Direction::Up);
+
+        assert_eq!(Direction8::UpLeft.clockwise(), Direction8::Up);
+        assert_eq!(Direction8::Up.counter_clockwise(), Direction8::UpLeft);
+        assert_eq!(Direction8::DownRight.opposite(), Direction8::UpLeft);
+    }
+
+    #[test]
+    fn parses_arrows_letters_and_compass_points() {
+        let spellings = ["^UN", ">RE", "vDS", "<LW"];
+        for (direction, characters) in Direction::ALL.into_iter().zip(spellings) {
+            for character in characters.chars() {
+                assert_eq!(Direction::try_from(character), Ok(direction));
+            }
+        }
+
+        assert_eq!("R".parse(), Ok(Direction::Right));
+        assert!("x".parse::<Direction>().is_err());
+        assert!("RR".parse::<Direction>().is_err());
+        assert_eq!(Direction::Down.to_string(), "v");
+    }
+
+    #[test]
+    fn adds_onto_points() {
+        let point = Point2::new(3, 3);
+
+        assert_eq!(point + Direction::Up, Point2::new(3, 2));
+        assert_eq!(point + Direction::Left, Point2::new(2, 3));
+        assert_eq!(point + Direction8::DownRight, Point2::new(4, 4));
+        assert_eq!(Point2::new(1_usize, 0) + Direction::Down, Point2::new(1, 1));
+
+        let mut point = Point2::new(0_i64, 0);
+        point += Direction::Right;
+        point += Direction8::UpRight;
+        assert_eq!(point, Point2::new(2, -1));
+    }
+
+    #[test]
+    fn widens_to_eight_directions() {
+        let directions = Direction::ALL.map(Direction8::from);
+
+        assert_eq!(
+            directions,
+            [
+                Direction8::Up,
+                Direction8::Right,
+                Direction8::Down,
+                Direction8::Left
+            ]
+        );
+        assert!(directions.iter().all(|direction| !direction.is_diagonal()));
+        assert_eq!(
+            directions.map(Direction8::offset),
+            Direction::ALL.map(Direction::offset)
+        );
+    }
+}
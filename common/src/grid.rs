@@ -0,0 +1,362 @@
+use crate::{parse::ParseError, Point2};
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+/// Offsets to the four orthogonal neighbours, clockwise from up
+const ORTHOGONAL: [(i32, i32); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets to all eight surrounding neighbours, clockwise from up
+const SURROUNDING: [(i32, i32); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// A dense rectangular grid, such as a map parsed from puzzle input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    /// A `width` by `height` grid with every cell set to `cell`
+    pub fn new(width: usize, height: usize, cell: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![cell; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parses every character in each line of `text` into a cell, or fails on
+    /// the first character that `cell` rejects as not being `expected` or on a
+    /// line that is shorter or longer than the first
+    pub fn try_parse(
+        text: &str,
+        expected: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut width = 0;
+        let mut height = 0;
+        let mut cells = vec![];
+
+        for line in text.lines() {
+            let line_width = line.chars().count();
+            if height == 0 {
+                width = line_width;
+            }
+            if line_width != width {
+                return Err(ParseError::new(
+                    line,
+                    format!("a line of {width} characters"),
+                ));
+            }
+
+            for (index, c) in line.char_indices() {
+                let parsed = cell(c)
+                    .ok_or_else(|| ParseError::new(&line[index..index + c.len_utf8()], expected))?;
+                cells.push(parsed);
+            }
+            height += 1;
+        }
+
+        Ok(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// A grid of rows `width` cells wide, from all of their `cells` row by row
+    pub(crate) fn from_cells(width: usize, cells: Vec<T>) -> Self {
+        Self {
+            width,
+            height: cells.len() / width.max(1),
+            cells,
+        }
+    }
+
+    /// Parses every character in each line of `text` into a cell, panicking
+    /// on a line that is shorter or longer than the first
+    pub fn parse(text: &str, mut cell: impl FnMut(char) -> T) -> Self {
+        Self::try_parse(text, "a cell", |c| Some(cell(c))).unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// A grid of the same size with `f` applied to every cell
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index(&self, position: Point2<i32>) -> Option<usize> {
+        let x = usize::try_from(position.x).ok()?;
+        let y = usize::try_from(position.y).ok()?;
+        (x < self.width && y < self.height).then_some(y * self.width + x)
+    }
+
+    pub fn contains(&self, position: Point2<i32>) -> bool {
+        self.index(position).is_some()
+    }
+
+    /// The cell at `position`, or `None` if it is outside of the grid
+    pub fn get(&self, position: Point2<i32>) -> Option<&T> {
+        self.index(position).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, position: Point2<i32>) -> Option<&mut T> {
+        self.index(position).map(|index| &mut self.cells[index])
+    }
+
+    /// Every position in the grid, row by row
+    pub fn positions(&self) -> impl Iterator<Item = Point2<i32>> {
+        let (width, height) = (self.width as i32, self.height as i32);
+        (0..height).flat_map(move |y| (0..width).map(move |x| Point2::new(x, y)))
+    }
+
+    /// Every cell along with its position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Point2<i32>, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// Position of the first cell, row by row, that matches `predicate`
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Point2<i32>> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on a chunk size of zero
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Cells from `start` onwards, taking `step` each time, until leaving the grid
+    fn walk(&self, start: Point2<i32>, step: Point2<i32>) -> impl Iterator<Item = &T> {
+        std::iter::successors(Some(start), move |&position| Some(position + step))
+            .map_while(|position| self.get(position))
+    }
+
+    /// Every diagonal running down and to the right, starting with the one in
+    /// the bottom left corner
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let (width, height) = (self.width as i32, self.height as i32);
+        let starts = (0..height)
+            .rev()
+            .map(|y| Point2::new(0, y))
+            .chain((1..width).map(|x| Point2::new(x, 0)));
+
+        starts.map(|start| self.walk(start, Point2::new(1, 1)))
+    }
+
+    /// Every diagonal running down and to the left, starting with the one in
+    /// the top left corner
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let (width, height) = (self.width as i32, self.height as i32);
+        let starts = (0..width)
+            .map(|x| Point2::new(x, 0))
+            .chain((1..height).map(move |y| Point2::new(width - 1, y)));
+
+        starts.map(|start| self.walk(start, Point2::new(-1, 1)))
+    }
+
+    fn neighbours<'a>(
+        &self,
+        position: Point2<i32>,
+        offsets: &'a [(i32, i32)],
+    ) -> impl Iterator<Item = Point2<i32>> + use<'_, 'a, T> {
+        offsets
+            .iter()
+            .map(move |&offset| position + Point2::from(offset))
+            .filter(|&neighbour| self.contains(neighbour))
+    }
+
+    /// Positions of the up to four orthogonal neighbours of `position` that are
+    /// inside of the grid, clockwise from up
+    pub fn neighbours4(&self, position: Point2<i32>) -> impl Iterator<Item = Point2<i32>> + '_ {
+        self.neighbours(position, &ORTHOGONAL)
+    }
+
+    /// Positions of the up to eight cells surrounding `position` that are
+    /// inside of the grid, clockwise from up
+    pub fn neighbours8(&self, position: Point2<i32>) -> impl Iterator<Item = Point2<i32>> + '_ {
+        self.neighbours(position, &SURROUNDING)
+    }
+}
+
+impl<T> Index<Point2<i32>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Point2<i32>) -> &T {
+        self.get(position)
+            .unwrap_or_else(|| panic!("{position:?} is outside of the grid"))
+    }
+}
+
+impl<T> IndexMut<Point2<i32>> for Grid<T> {
+    fn index_mut(&mut self, position: Point2<i32>) -> &mut T {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{position:?} is outside of the grid"))
+    }
+}
+
+/// One line per row, without a trailing newline
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    const GRID: &str = indoc! {"
+        0123
+        4567
+        89ab
+    "};
+
+    fn strings<'a>(lines: impl Iterator<Item = impl Iterator<Item = &'a char>>) -> Vec<String> {
+        lines.map(|line| line.collect()).collect()
+    }
+
+    #[test]
+    fn gets_cells() {
+        let grid = Grid::parse(GRID, |c| c);
+
+        assert_eq!((grid.width(), grid.height()), (4, 3));
+        assert_eq!(grid.get(Point2::new(0, 0)), Some(&'0'));
+        assert_eq!(grid.get(Point2::new(3, 1)), Some(&'7'));
+        assert_eq!(grid[Point2::new(2, 2)], 'a');
+    }
+
+    #[test]
+    fn reports_rejected_cells_and_ragged_lines() {
+        let digit = |c: char| c.to_digit(10);
+
+        let error = Grid::try_parse("12\n3x\n", "a digit", digit).unwrap_err();
+        assert_eq!(
+            (error.token.as_str(), error.expected.as_str()),
+            ("x", "a digit")
+        );
+
+        let error = Grid::try_parse("12\n345\n", "a digit", digit).unwrap_err();
+        assert_eq!(error.token, "345");
+        assert_eq!(error.expected, "a line of 2 characters");
+    }
+
+    #[test]
+    fn is_bounds_checked() {
+        let grid = Grid::parse(GRID, |c| c);
+
+        assert_eq!(grid.get(Point2::new(-1, 0)), None);
+        assert_eq!(grid.get(Point2::new(0, -1)), None);
+        assert_eq!(grid.get(Point2::new(4, 0)), None);
+        assert_eq!(grid.get(Point2::new(0, 3)), None);
+    }
+
+    #[test]
+    fn sets_cells() {
+        let mut grid = Grid::new(2, 2, '.');
+        *grid.get_mut(Point2::new(1, 0)).unwrap() = '#';
+        grid[Point2::new(0, 1)] = '@';
+
+        assert_eq!(grid.get_mut(Point2::new(2, 0)), None);
+        assert_eq!(grid.to_string(), ".#\n@.");
+        assert_eq!(grid.find(|&c| c == '@'), Some(Point2::new(0, 1)));
+        assert_eq!(grid.map(|&c| c == '#').to_string(), "falsetrue\nfalsefalse");
+    }
+
+    #[test]
+    fn iterates_positions_row_by_row() {
+        let grid = Grid::parse("ab\ncd\n", |c| c);
+        let cells: String = grid.positions().map(|p| grid[p]).collect();
+
+        assert_eq!(cells, "abcd");
+    }
+
+    #[test]
+    fn iterates_rows_and_columns() {
+        let grid = Grid::parse(GRID, |c| c);
+
+        let rows: Vec<String> = grid.rows().map(|row| row.iter().collect()).collect();
+        assert_eq!(rows, ["0123", "4567", "89ab"]);
+        assert_eq!(strings(grid.columns()), ["048", "159", "26a", "37b"]);
+    }
+
+    #[test]
+    fn iterates_diagonals() {
+        let grid = Grid::parse(GRID, |c| c);
+
+        assert_eq!(
+            strings(grid.diagonals()),
+            ["8", "49", "05a", "16b", "27", "3"]
+        );
+        assert_eq!(
+            strings(grid.anti_diagonals()),
+            ["0", "14", "258", "369", "7a", "b"]
+        );
+    }
+
+    #[test]
+    fn finds_neighbours_inside_grid() {
+        let grid = Grid::parse(GRID, |c| c);
+        let cells = |positions: Vec<Point2<i32>>| -> String {
+            positions.into_iter().map(|p| grid[p]).collect()
+        };
+
+        assert_eq!(cells(grid.neighbours4(Point2::new(1, 1)).collect()), "1694");
+        assert_eq!(cells(grid.neighbours4(Point2::new(0, 0)).collect()), "14");
+        assert_eq!(
+            cells(grid.neighbours8(Point2::new(1, 1)).collect()),
+            "126a9840"
+        );
+        assert_eq!(cells(grid.neighbours8(Point2::new(3, 2)).collect()), "7a6");
+    }
+
+    #[test]
+    fn displays_rows_as_lines() {
+        let grid = Grid::parse(GRID, |c| c);
+
+        assert_eq!(grid.to_string(), GRID.trim_end());
+    }
+}
@@ -0,0 +1,205 @@
+use anyhow::{bail, Context};
+use std::{
+    env, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+/// Environment variable pointing to a directory of inputs laid out as `{year}/day{day}`
+pub const INPUT_DIR_VARIABLE: &str = "AOC_INPUT_DIR";
+
+/// Root of the repository, where each year has its own directory
+fn repository_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("`common` is inside the repository")
+}
+
+/// A year's own directory, e.g. `2024/`
+pub fn year_directory(year: u16) -> PathBuf {
+    repository_root().join(year.to_string())
+}
+
+/// Directory inside a year's crate that puzzle inputs are kept in
+fn input_directory(year: u16) -> &'static str {
+    match year {
+        2025 => "input",
+        _ => "data",
+    }
+}
+
+/// Where the puzzle input for `day` is expected, following each year's layout
+pub fn input_path(year: u16, day: u8) -> PathBuf {
+    YearCache.path(year, day)
+}
+
+/// Somewhere puzzle inputs are kept on disk
+pub trait InputStore {
+    fn path(&self, year: u16, day: u8) -> PathBuf;
+
+    /// The directory that every year's inputs are kept under, which files
+    /// shared by all years are kept in too
+    fn root(&self) -> PathBuf;
+}
+
+/// Each year's own input directory, e.g. `2024/data/day12` or `2025/input/day3`
+pub struct YearCache;
+
+impl InputStore for YearCache {
+    fn path(&self, year: u16, day: u8) -> PathBuf {
+        year_directory(year)
+            .join(input_directory(year))
+            .join(format!("day{day}"))
+    }
+
+    fn root(&self) -> PathBuf {
+        repository_root().to_path_buf()
+    }
+}
+
+/// A directory shared by all years, e.g. `$AOC_INPUT_DIR/2024/day12`
+pub struct InputDirectory(pub PathBuf);
+
+impl InputStore for InputDirectory {
+    fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.0.join(year.to_string()).join(format!("day{day}"))
+    }
+
+    fn root(&self) -> PathBuf {
+        self.0.clone()
+    }
+}
+
+/// `AOC_INPUT_DIR` if it is set, otherwise each year's own input directory
+pub fn default_store() -> Box<dyn InputStore> {
+    match env::var_os(INPUT_DIR_VARIABLE) {
+        Some(directory) => Box::new(InputDirectory(directory.into())),
+        None => Box::new(YearCache),
+    }
+}
+
+/// Where a day's puzzle input is read from
+#[derive(Debug, PartialEq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// An explicit input argument, where `-` means stdin, or the input in the
+    /// default store
+    pub fn resolve(argument: Option<&Path>, year: u16, day: u8) -> Self {
+        match argument {
+            Some(path) if path == Path::new("-") => InputSource::Stdin,
+            Some(path) => InputSource::File(path.to_path_buf()),
+            None => InputSource::File(default_store().path(year, day)),
+        }
+    }
+
+    pub fn read(&self) -> anyhow::Result<String> {
+        match self {
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .context("failed to read input from stdin")?;
+                Ok(input)
+            }
+            InputSource::File(path) if !path.exists() => {
+                bail!(
+                    "there is no input at '{}'. Pass a path to it, pipe it to stdin, set {INPUT_DIR_VARIABLE} or download it with `aoc fetch`",
+                    path.display()
+                )
+            }
+            InputSource::File(path) => fs::read_to_string(path)
+                .with_context(|| format!("failed to read input '{}'", path.display())),
+        }
+    }
+}
+
+/// Reads the input for a day's binary, from the path given as its first
+/// argument, stdin if that is `-`, or the default store
+pub fn read_input(year: u16, day: u8) -> anyhow::Result<String> {
+    let argument = env::args_os().nth(1).map(PathBuf::from);
+    InputSource::resolve(argument.as_deref(), year, day).read()
+}
+
+/// Where the examples of `day` are kept, e.g. `2025/examples/day7`. Each
+/// example input is in a file named after its number and the example's answer
+/// to each part in `part1` and `part2`.
+pub fn example_directory(year: u16, day: u8) -> PathBuf {
+    year_directory(year)
+        .join("examples")
+        .join(format!("day{day}"))
+}
+
+fn read_example_file(year: u16, day: u8, name: &str) -> String {
+    let path = example_directory(year, day).join(name);
+    fs::read_to_string(&path).unwrap_or_else(|error| {
+        panic!(
+            "failed to read '{}': {error}. Extract it from the puzzle with `aoc examples`",
+            path.display()
+        )
+    })
+}
+
+/// The `n`th example input of `day`, for tests. Panics if it is missing.
+pub fn example(year: u16, day: u8, n: usize) -> String {
+    read_example_file(year, day, &n.to_string())
+}
+
+/// The answer to `part` of `day`'s example, for tests. Panics if it is missing.
+pub fn example_answer(year: u16, day: u8, part: u8) -> String {
+    read_example_file(year, day, &format!("part{part}"))
+        .trim_end()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn follows_year_layout() {
+        assert!(input_path(2024, 12).ends_with("2024/data/day12"));
+        assert!(input_path(2025, 3).ends_with("2025/input/day3"));
+    }
+
+    #[test]
+    fn input_directory_has_year_subdirectories() {
+        let store = InputDirectory(PathBuf::from("/inputs"));
+        assert_eq!(store.path(2023, 7), PathBuf::from("/inputs/2023/day7"));
+    }
+
+    #[test]
+    fn resolves_explicit_arguments() {
+        assert_eq!(
+            InputSource::resolve(Some(Path::new("-")), 2024, 1),
+            InputSource::Stdin
+        );
+        assert_eq!(
+            InputSource::resolve(Some(Path::new("example")), 2024, 1),
+            InputSource::File(PathBuf::from("example"))
+        );
+    }
+
+    #[test]
+    fn reads_examples_and_answers() {
+        assert!(example(2025, 7, 1).starts_with(".......S......."));
+        assert_eq!(example_answer(2025, 7, 1), "21");
+    }
+
+    #[test]
+    #[should_panic(expected = "Extract it from the puzzle with `aoc examples`")]
+    fn missing_example_panics() {
+        example(2025, 7, 99);
+    }
+
+    #[test]
+    fn missing_input_is_an_error() {
+        let source = InputSource::File(PathBuf::from("/nonexistent/day1"));
+        let error = source.read().unwrap_err().to_string();
+
+        assert!(error.contains("there is no input at '/nonexistent/day1'"));
+    }
+}
@@ -0,0 +1,27 @@
+mod answer;
+pub mod combinators;
+mod direction;
+pub mod grid;
+mod input;
+mod macros;
+pub mod math;
+pub mod ocr;
+pub mod parse;
+pub mod point;
+pub mod precedence;
+pub mod search;
+mod simulate;
+mod solution;
+pub mod union_find;
+
+pub use answer::Answer;
+pub use direction::{Direction, Direction8, ParseDirectionError};
+pub use grid::Grid;
+pub use input::{
+    default_store, example, example_answer, example_directory, input_path, read_input,
+    year_directory, InputDirectory, InputSource, InputStore, YearCache, INPUT_DIR_VARIABLE,
+};
+pub use parse::ParseError;
+pub use point::{ParsePointError, Point2, Point3};
+pub use simulate::{Frames, Simulate};
+pub use solution::{Day, DynSolution, Parsed, Solution, Year};
@@ -0,0 +1,48 @@
+/// Asserts that an expression matches a pattern, like the unstable
+/// `std::assert_matches::assert_matches`, so that tests build on stable Rust
+#[macro_export]
+macro_rules! assert_matches {
+    ($expression:expr, $pattern:pat $(if $guard:expr)? $(,)?) => {
+        match $expression {
+            $pattern $(if $guard)? => {}
+            ref value => panic!(
+                "assertion failed: `{:?}` does not match `{}`",
+                value,
+                stringify!($pattern $(if $guard)?)
+            ),
+        }
+    };
+    ($expression:expr, $pattern:pat $(if $guard:expr)?, $($message:tt)+) => {
+        match $expression {
+            $pattern $(if $guard)? => {}
+            ref value => panic!(
+                "assertion failed: `{:?}` does not match `{}`: {}",
+                value,
+                stringify!($pattern $(if $guard)?),
+                format_args!($($message)+)
+            ),
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn matches_patterns() {
+        assert_matches!(Some(3), Some(_));
+        assert_matches!(Some(3), Some(n) if n > 2);
+        assert_matches!(("R", 29), ("R", 29), "rotation should be parsed");
+    }
+
+    #[test]
+    #[should_panic(expected = "`Some(1)` does not match `Some(n) if n > 2`")]
+    fn panics_on_mismatch() {
+        assert_matches!(Some(1), Some(n) if n > 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "does not match `None`: for 7")]
+    fn panics_with_message() {
+        assert_matches!(Some(7), None, "for {}", 7);
+    }
+}
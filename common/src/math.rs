@@ -0,0 +1,57 @@
+use std::ops::{Div, Mul, Rem};
+
+/// Primitive integers, which `gcd`, `lcm` and straight line distances work on
+pub trait Integer:
+    Copy + PartialEq + Div<Output = Self> + Mul<Output = Self> + Rem<Output = Self>
+{
+    const ZERO: Self;
+
+    /// The nearest `f64`, which is exact up to 2^53
+    fn as_f64(self) -> f64;
+}
+
+macro_rules! impl_integer {
+    ($($integer:ty),*) => {
+        $(impl Integer for $integer {
+            const ZERO: Self = 0;
+
+            fn as_f64(self) -> f64 {
+                self as f64
+            }
+        })*
+    };
+}
+
+impl_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Greatest common divisor
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    if b == T::ZERO {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// Least common multiple
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    a / gcd(a, b) * b
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_greatest_common_divisor() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(7_u64, 13), 1);
+        assert_eq!(gcd(5, 0), 5);
+    }
+
+    #[test]
+    fn finds_least_common_multiple() {
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!([2_usize, 3, 4].into_iter().reduce(lcm), Some(12));
+    }
+}
@@ -0,0 +1,272 @@
+//! Reading the block letters that some puzzles draw on a screen, in either of
+//! Advent of Code's two alphabets
+
+use crate::Grid;
+use anyhow::bail;
+
+/// Letters 6 pixels tall and mostly 4 wide, such as on 2022 day 10's CRT
+const SMALL: [(char, &str); 18] = [
+    ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+    ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+    ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+    ('E', "####\n#...\n###.\n#...\n#...\n####"),
+    ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+    ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+    ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+    ('I', ".###\n..#.\n..#.\n..#.\n..#.\n.###"),
+    ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+    ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+    ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+    ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+    ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+    ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+    ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('Y', "#...#\n#...#\n.#.#.\n..#..\n..#..\n..#.."),
+    ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+];
+
+/// Letters 10 pixels tall and 6 wide, such as in 2018 day 10's night sky
+const LARGE: [(char, &str); 15] = [
+    (
+        'A',
+        "..##..\n.#..#.\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'B',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#....#\n#....#\n#....#\n#....#\n#####.",
+    ),
+    (
+        'C',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#....#\n.####.",
+    ),
+    (
+        'E',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'F',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'G',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#..###\n#....#\n#....#\n#...##\n.###.#",
+    ),
+    (
+        'H',
+        "#....#\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'J',
+        "...###\n....#.\n....#.\n....#.\n....#.\n....#.\n....#.\n#...#.\n#...#.\n.###..",
+    ),
+    (
+        'K',
+        "#....#\n#...#.\n#..#..\n#.#...\n##....\n##....\n#.#...\n#..#..\n#...#.\n#....#",
+    ),
+    (
+        'L',
+        "#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'N',
+        "#....#\n##...#\n##...#\n#.#..#\n#.#..#\n#..#.#\n#..#.#\n#...##\n#...##\n#....#",
+    ),
+    (
+        'P',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'R',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#..#..\n#...#.\n#...#.\n#....#\n#....#",
+    ),
+    (
+        'X',
+        "#....#\n#....#\n.#..#.\n.#..#.\n..##..\n..##..\n.#..#.\n.#..#.\n#....#\n#....#",
+    ),
+    (
+        'Z',
+        "######\n.....#\n.....#\n....#.\n...#..\n..#...\n.#....\n#.....\n#.....\n######",
+    ),
+];
+
+/// A letter's lit pixels, row by row, without any blank columns on its sides
+struct Glyph {
+    letter: char,
+    rows: Vec<Vec<bool>>,
+}
+
+impl Glyph {
+    fn new(letter: char, drawing: &str) -> Self {
+        let rows: Vec<Vec<bool>> = drawing
+            .lines()
+            .map(|row| row.chars().map(|c| c == '#').collect())
+            .collect();
+        let lit = |x: usize| rows.iter().any(|row| row[x]);
+        let left = (0..rows[0].len()).find(|&x| lit(x)).unwrap_or(0);
+        let right = (0..rows[0].len()).rfind(|&x| lit(x)).map_or(0, |x| x + 1);
+        let rows = rows.iter().map(|row| row[left..right].to_vec()).collect();
+
+        Self { letter, rows }
+    }
+
+    fn width(&self) -> usize {
+        self.rows[0].len()
+    }
+
+    /// Whether the glyph is drawn in `screen`'s rows starting at column `x`
+    fn is_at(&self, screen: &[&[bool]], x: usize) -> bool {
+        screen
+            .iter()
+            .zip(&self.rows)
+            .all(|(row, glyph_row)| row.get(x..x + glyph_row.len()) == Some(glyph_row.as_slice()))
+    }
+}
+
+/// Reads the letters drawn by the lit pixels in `screen`, using the alphabet
+/// whose height matches the rows that have anything lit. Fails on anything
+/// that isn't one of the alphabet's letters.
+pub fn read(screen: &Grid<bool>) -> anyhow::Result<String> {
+    let rows: Vec<&[bool]> = screen.rows().collect();
+    let lit_row = |row: &&[bool]| row.contains(&true);
+    let (Some(top), Some(bottom)) = (
+        rows.iter().position(lit_row),
+        rows.iter().rposition(lit_row),
+    ) else {
+        return Ok(String::new());
+    };
+    let rows = &rows[top..=bottom];
+
+    let alphabet: &[(char, &str)] = match rows.len() {
+        6 => &SMALL,
+        10 => &LARGE,
+        height => bail!("letters are 6 or 10 pixels tall, but these are {height}"),
+    };
+    let glyphs: Vec<Glyph> = alphabet
+        .iter()
+        .map(|&(letter, drawing)| Glyph::new(letter, drawing))
+        .collect();
+
+    let blank = |x: usize| rows.iter().all(|row| !row[x]);
+    let mut text = String::new();
+    let mut x = 0;
+
+    while x < screen.width() {
+        if blank(x) {
+            x += 1;
+            continue;
+        }
+
+        // A letter as wide as its cell, like Y, touches the one after it
+        let matches = glyphs.iter().filter(|glyph| glyph.is_at(rows, x));
+        let Some(glyph) = matches.max_by_key(|glyph| {
+            let end = x + glyph.width();
+            (end == screen.width() || blank(end), glyph.width())
+        }) else {
+            let end = (x..screen.width())
+                .find(|&x| blank(x))
+                .unwrap_or(screen.width());
+            bail!(
+                "no letter looks like the one at column {x}:\n{}",
+                draw(rows, x..end)
+            );
+        };
+
+        text.push(glyph.letter);
+        x += glyph.width();
+    }
+
+    Ok(text)
+}
+
+fn draw(rows: &[&[bool]], columns: std::ops::Range<usize>) -> String {
+    rows.iter()
+        .map(|row| {
+            row[columns.clone()]
+                .iter()
+                .map(|&lit| if lit { '#' } else { '.' })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    fn screen(drawing: &str) -> Grid<bool> {
+        Grid::parse(drawing, |c| c == '#')
+    }
+
+    #[test]
+    fn reads_small_letters() {
+        let drawing = indoc! {"
+            ###..####.#..#.####..##..###..####.###..
+            #..#.#....#..#....#.#..#.#..#.#....#..#.
+            #..#.###..####...#..#....#..#.###..#..#.
+            ###..#....#..#..#...#.##.###..#....###..
+            #....#....#..#.#....#..#.#.#..#....#....
+            #....####.#..#.####..###.#..#.####.#....
+        "};
+
+        assert_eq!(read(&screen(drawing)).unwrap(), "PEHZGREP");
+    }
+
+    #[test]
+    fn reads_letters_touching_the_next() {
+        let drawing = indoc! {"
+            ......
+            #...#####
+            #...##...
+            .#.#.###.
+            ..#..#...
+            ..#..#...
+            ..#..####
+        "};
+        let drawing: String = drawing
+            .lines()
+            .map(|line| format!("{line:.<10}\n"))
+            .collect();
+
+        assert_eq!(read(&screen(&drawing)).unwrap(), "YE");
+    }
+
+    #[test]
+    fn reads_large_letters() {
+        let drawing = indoc! {"
+            #....#..######
+            #....#..#.....
+            .#..#...#.....
+            .#..#...#.....
+            ..##....#####.
+            ..##....#.....
+            .#..#...#.....
+            .#..#...#.....
+            #....#..#.....
+            #....#..#.....
+        "};
+
+        assert_eq!(read(&screen(drawing)).unwrap(), "XF");
+    }
+
+    #[test]
+    fn reports_unknown_letters() {
+        let drawing = indoc! {"
+            .##..###.
+            #..#.#..#
+            #..#.#..#
+            ####.#..#
+            #..#.#..#
+            #..#.###.
+        "};
+
+        let error = read(&screen(drawing)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "no letter looks like the one at column 5:\n###.\n#..#\n#..#\n#..#\n#..#\n###."
+        );
+        assert!(read(&screen("#\n#\n#")).is_err());
+    }
+}
@@ -0,0 +1,190 @@
+use std::{fmt, str::FromStr};
+
+/// A token in a day's input that isn't what its parser expected
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// What the token should have been, e.g. "a number"
+    pub expected: String,
+    /// The offending text, which is empty at the end of the input
+    pub token: String,
+    pub year: Option<u16>,
+    pub day: Option<u8>,
+    /// 1-based line of the token, once the error is located in its input
+    pub line: Option<usize>,
+    /// 1-based column of the token in its line
+    pub column: Option<usize>,
+    /// Where `token` was in memory, to find it in the input it was sliced from
+    address: usize,
+}
+
+impl ParseError {
+    /// `token`, which should be a slice of the input, isn't what was
+    /// `expected`
+    pub fn new(token: &str, expected: impl Into<String>) -> Self {
+        Self {
+            expected: expected.into(),
+            token: token.to_string(),
+            year: None,
+            day: None,
+            line: None,
+            column: None,
+            address: token.as_ptr() as usize,
+        }
+    }
+
+    /// `text` ended before what was `expected`
+    pub fn end_of(text: &str, expected: impl Into<String>) -> Self {
+        Self::new(&text[text.len()..], expected)
+    }
+
+    /// Records that the error is in `year`'s `day` and finds the line and
+    /// column of its token in `input`, if it was sliced from it or appears in
+    /// it
+    pub fn locate(mut self, input: &str, year: u16, day: u8) -> Self {
+        self.year = Some(year);
+        self.day = Some(day);
+
+        let start = input.as_ptr() as usize;
+        let offset = if (start..=start + input.len()).contains(&self.address) {
+            Some(self.address - start)
+        } else if !self.token.is_empty() {
+            input.find(&self.token)
+        } else {
+            None
+        };
+
+        if let Some(offset) = offset.filter(|&offset| input.is_char_boundary(offset)) {
+            let before = &input[..offset];
+            let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+            self.line = Some(before.matches('\n').count() + 1);
+            self.column = Some(before[line_start..].chars().count() + 1);
+        }
+
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut location = vec![];
+        if let (Some(year), Some(day)) = (self.year, self.day) {
+            location.push(format!("{year} day {day}"));
+        }
+        if let (Some(line), Some(column)) = (self.line, self.column) {
+            location.push(format!("line {line}, column {column}"));
+        }
+        if !location.is_empty() {
+            write!(f, "{}: ", location.join(", "))?;
+        }
+
+        match self.token.as_str() {
+            "" => write!(f, "expected {} but the input ended", self.expected),
+            "\n" | "\r\n" => write!(f, "expected {} but the line ended", self.expected),
+            token => write!(f, "expected {} but found '{token}'", self.expected),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// `token` without surrounding whitespace parsed as a `T`, or an error saying
+/// it should have been `expected`
+pub fn token<T: FromStr>(token: &str, expected: &str) -> Result<T, ParseError> {
+    let token = token.trim();
+    token.parse().map_err(|_| ParseError::new(token, expected))
+}
+
+/// `text` before and after the first `separator`
+pub fn split_once<'a>(text: &'a str, separator: &str) -> Result<(&'a str, &'a str), ParseError> {
+    text.split_once(separator)
+        .ok_or_else(|| ParseError::new(text, format!("'{separator}'")))
+}
+
+/// Every whitespace separated number in `text`, or an error pointing at the
+/// first token that isn't one
+pub fn numbers<T: FromStr>(text: &str) -> Result<Vec<T>, ParseError> {
+    text.split_whitespace()
+        .map(|number| token(number, "a number"))
+        .collect()
+}
+
+/// Sections of `text` separated by blank lines
+pub fn blocks(text: &str) -> impl Iterator<Item = &str> {
+    text.split("\n\n").filter(|block| !block.trim().is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_numbers() {
+        assert_eq!(numbers::<u32>("7 6 4 2 1"), Ok(vec![7, 6, 4, 2, 1]));
+        assert_eq!(numbers::<i64>(" -3  3\t0 "), Ok(vec![-3, 3, 0]));
+        assert_eq!(numbers::<i32>(""), Ok(vec![]));
+        assert_eq!(numbers::<u8>("1 300").unwrap_err().token, "300");
+    }
+
+    #[test]
+    fn rejects_tokens_that_arent_numbers() {
+        let input = "7 6\n1 x 2\n";
+        let error = numbers::<u32>(input.lines().nth(1).unwrap())
+            .unwrap_err()
+            .locate(input, 2024, 2);
+
+        assert_eq!(
+            error.to_string(),
+            "2024 day 2, line 2, column 3: expected a number but found 'x'"
+        );
+        assert!(numbers::<u32>("2-4,6-8").is_err());
+    }
+
+    #[test]
+    fn locates_token_in_input() {
+        let input = "1 2\n3 x 5\n";
+        let line = input.lines().nth(1).unwrap();
+        let error = token::<u32>(line.split(' ').nth(1).unwrap(), "a number")
+            .unwrap_err()
+            .locate(input, 2024, 7);
+
+        assert_eq!((error.line, error.column), (Some(2), Some(3)));
+        assert_eq!(
+            error.to_string(),
+            "2024 day 7, line 2, column 3: expected a number but found 'x'"
+        );
+    }
+
+    #[test]
+    fn locates_copied_token_by_searching() {
+        let copied = "x".to_string();
+        let error = ParseError::new(&copied, "a digit").locate("12\n3x", 2023, 1);
+
+        assert_eq!((error.line, error.column), (Some(2), Some(2)));
+    }
+
+    #[test]
+    fn reports_end_of_input() {
+        let input = "1 -> ";
+        let (_, rest) = split_once(input, " -> ").unwrap();
+        let error = ParseError::end_of(rest, "a number").locate(input, 2022, 5);
+
+        assert_eq!(
+            error.to_string(),
+            "2022 day 5, line 1, column 6: expected a number but the input ended"
+        );
+        assert_eq!(
+            split_once("1 2", " -> ").unwrap_err().to_string(),
+            "expected ' -> ' but found '1 2'"
+        );
+    }
+
+    #[test]
+    fn splits_blocks() {
+        let text = "1000\n2000\n\n4000\n\n5000\n6000\n";
+
+        assert_eq!(
+            blocks(text).collect::<Vec<_>>(),
+            vec!["1000\n2000", "4000", "5000\n6000\n"]
+        );
+    }
+}
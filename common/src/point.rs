@@ -0,0 +1,303 @@
+use crate::math::Integer;
+use std::{
+    fmt,
+    num::ParseIntError,
+    ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+/// A position on a 2D grid, where `y` grows downwards like the lines of a
+/// puzzle input
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// A position in 3D space
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl<T> From<Point2<T>> for (T, T) {
+    fn from(point: Point2<T>) -> Self {
+        (point.x, point.y)
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Self::new(x, y, z)
+    }
+}
+
+impl<T> From<Point3<T>> for (T, T, T) {
+    fn from(point: Point3<T>) -> Self {
+        (point.x, point.y, point.z)
+    }
+}
+
+/// `a - b` if `a` is larger, otherwise `b - a`, so unsigned values don't underflow
+fn abs_diff<T: PartialOrd + Sub<Output = T>>(a: T, b: T) -> T {
+    if a >= b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+/// Text that isn't a comma separated list of the expected number of integers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParsePointError {
+    Count { expected: usize, found: usize },
+    Number(ParseIntError),
+}
+
+impl fmt::Display for ParsePointError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParsePointError::Count { expected, found } => {
+                write!(f, "expected {expected} coordinates but found {found}")
+            }
+            ParsePointError::Number(error) => write!(f, "invalid coordinate: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for ParsePointError {}
+
+/// The comma separated coordinates in `text`, of which there must be `N`
+fn parse_coordinates<T, const N: usize>(text: &str) -> Result<[T; N], ParsePointError>
+where
+    T: FromStr<Err = ParseIntError>,
+{
+    let coordinates = text
+        .split(',')
+        .map(|coordinate| coordinate.trim().parse())
+        .collect::<Result<Vec<T>, _>>()
+        .map_err(ParsePointError::Number)?;
+    let found = coordinates.len();
+
+    coordinates
+        .try_into()
+        .map_err(|_| ParsePointError::Count { expected: N, found })
+}
+
+macro_rules! impl_point {
+    ($point:ident { $($axis:ident),+ }) => {
+        impl<T> $point<T>
+        where
+            T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+        {
+            /// Sum of the distances along each axis
+            pub fn manhattan_distance(self, other: Self) -> T {
+                [$(abs_diff(self.$axis, other.$axis)),+]
+                    .into_iter()
+                    .reduce(|sum, distance| sum + distance)
+                    .unwrap()
+            }
+
+            /// Largest of the distances along each axis, i.e. the number of
+            /// king's moves between the points
+            pub fn chebyshev_distance(self, other: Self) -> T {
+                [$(abs_diff(self.$axis, other.$axis)),+]
+                    .into_iter()
+                    .reduce(|max, distance| if distance > max { distance } else { max })
+                    .unwrap()
+            }
+
+            /// Square of the straight line distance, which orders points the
+            /// same way but stays exact
+            pub fn squared_distance(self, other: Self) -> T {
+                [$(abs_diff(self.$axis, other.$axis)),+]
+                    .into_iter()
+                    .map(|distance| distance * distance)
+                    .reduce(|sum, distance| sum + distance)
+                    .unwrap()
+            }
+        }
+
+        impl<T: Integer + PartialOrd + Sub<Output = T>> $point<T> {
+            /// Straight line distance. Each axis is squared as an `f64`, so
+            /// this doesn't overflow where `squared_distance` would.
+            pub fn euclidean_distance(self, other: Self) -> f64 {
+                [$(abs_diff(self.$axis, other.$axis).as_f64()),+]
+                    .into_iter()
+                    .map(|distance| distance * distance)
+                    .sum::<f64>()
+                    .sqrt()
+            }
+        }
+
+        impl<T: Add<Output = T>> Add for $point<T> {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Self { $($axis: self.$axis + other.$axis),+ }
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $point<T> {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                Self { $($axis: self.$axis - other.$axis),+ }
+            }
+        }
+
+        impl<T: Neg<Output = T>> Neg for $point<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $($axis: -self.$axis),+ }
+            }
+        }
+
+        impl<T: Mul<Output = T> + Copy> Mul<T> for $point<T> {
+            type Output = Self;
+
+            fn mul(self, factor: T) -> Self {
+                Self { $($axis: self.$axis * factor),+ }
+            }
+        }
+
+        impl<T: Div<Output = T> + Copy> Div<T> for $point<T> {
+            type Output = Self;
+
+            fn div(self, divisor: T) -> Self {
+                Self { $($axis: self.$axis / divisor),+ }
+            }
+        }
+
+        impl<T: AddAssign> AddAssign for $point<T> {
+            fn add_assign(&mut self, other: Self) {
+                $(self.$axis += other.$axis;)+
+            }
+        }
+
+        impl<T: SubAssign> SubAssign for $point<T> {
+            fn sub_assign(&mut self, other: Self) {
+                $(self.$axis -= other.$axis;)+
+            }
+        }
+
+        impl<T: MulAssign + Copy> MulAssign<T> for $point<T> {
+            fn mul_assign(&mut self, factor: T) {
+                $(self.$axis *= factor;)+
+            }
+        }
+
+        /// Comma separated coordinates, e.g. `3,-4`
+        impl<T: FromStr<Err = ParseIntError>> FromStr for $point<T> {
+            type Err = ParsePointError;
+
+            fn from_str(text: &str) -> Result<Self, Self::Err> {
+                let [$($axis),+] = parse_coordinates(text)?;
+                Ok(Self { $($axis),+ })
+            }
+        }
+    };
+}
+
+impl_point!(Point2 { x, y });
+impl_point!(Point3 { x, y, z });
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adds_and_subtracts() {
+        let point = Point2::new(3, -2);
+
+        assert_eq!(point + Point2::new(1, 1), Point2::new(4, -1));
+        assert_eq!(point - Point2::new(1, 1), Point2::new(2, -3));
+        assert_eq!(Point2::from((3, -2)), point);
+        assert_eq!(<(i32, i32)>::from(point), (3, -2));
+    }
+
+    #[test]
+    fn scales_and_negates() {
+        let mut point = Point3::new(1, -2, 3);
+
+        assert_eq!(point * 2, Point3::new(2, -4, 6));
+        assert_eq!(Point3::new(4, -6, 8) / 2, Point3::new(2, -3, 4));
+        assert_eq!(-point, Point3::new(-1, 2, -3));
+
+        point += Point3::new(1, 1, 1);
+        point -= Point3::new(0, 0, 4);
+        point *= 3;
+        assert_eq!(point, Point3::new(6, -3, 0));
+    }
+
+    #[test]
+    fn measures_distances() {
+        let (a, b) = (Point2::new(1, 5), Point2::new(4, 1));
+
+        assert_eq!(a.manhattan_distance(b), 7);
+        assert_eq!(a.chebyshev_distance(b), 4);
+        assert_eq!(a.squared_distance(b), 25);
+        assert_eq!(b.squared_distance(a), 25);
+        assert_eq!(a.euclidean_distance(b), 5.0);
+        assert_eq!(
+            Point2::new(-2, 0).euclidean_distance(Point2::new(1, 0)),
+            3.0
+        );
+    }
+
+    #[test]
+    fn measures_unsigned_distances_without_underflow() {
+        let a = Point3::<u64>::new(162, 817, 812);
+        let b = Point3::<u64>::new(425, 690, 689);
+
+        assert_eq!(a.manhattan_distance(b), 513);
+        assert_eq!(a.chebyshev_distance(b), 263);
+        assert_eq!(a.squared_distance(b), 100_427);
+        assert!((a.euclidean_distance(b) - 316.9022).abs() < 1e-4);
+        assert_eq!(
+            Point3::new(u64::MAX, 0, 0).euclidean_distance(Point3::new(0, 0, 0)),
+            u64::MAX as f64
+        );
+    }
+
+    #[test]
+    fn parses_comma_separated_coordinates() {
+        assert_eq!("3,-4".parse(), Ok(Point2::new(3, -4)));
+        assert_eq!(
+            "162, 817, 812".parse(),
+            Ok(Point3::<u32>::new(162, 817, 812))
+        );
+        assert_eq!(
+            "1,2".parse::<Point3<u32>>(),
+            Err(ParsePointError::Count {
+                expected: 3,
+                found: 2
+            })
+        );
+        assert!(matches!(
+            "1,x".parse::<Point2<u32>>(),
+            Err(ParsePointError::Number(_))
+        ));
+    }
+}
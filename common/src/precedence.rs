@@ -0,0 +1,204 @@
+//! Rules saying which things must come before which others, such as the page
+//! ordering rules of 2024 day 5
+
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fmt,
+    hash::Hash,
+};
+
+/// Rules that each say one node must come before another, which sequences of
+/// nodes can be checked against and sorted by
+#[derive(Debug, Clone)]
+pub struct Precedence<T> {
+    /// The nodes that must come after each node
+    after: HashMap<T, HashSet<T>>,
+}
+
+impl<T: Clone + Eq + Hash> Precedence<T> {
+    /// Whether a rule says `before` must come before `after`
+    pub fn must_precede(&self, before: &T, after: &T) -> bool {
+        self.after
+            .get(before)
+            .is_some_and(|nodes| nodes.contains(after))
+    }
+
+    /// Every rule as `(before, after)` that `sequence` breaks by having
+    /// `after` earlier than `before`, in the order they appear
+    pub fn violations(&self, sequence: &[T]) -> Vec<(T, T)> {
+        sequence
+            .iter()
+            .enumerate()
+            .flat_map(|(index, after)| {
+                sequence[index + 1..]
+                    .iter()
+                    .filter(|before| self.must_precede(before, after))
+                    .map(|before| (before.clone(), after.clone()))
+            })
+            .collect()
+    }
+
+    /// Whether `sequence` breaks none of the rules
+    pub fn is_ordered(&self, sequence: &[T]) -> bool {
+        sequence.iter().enumerate().all(|(index, after)| {
+            !sequence[index + 1..]
+                .iter()
+                .any(|before| self.must_precede(before, after))
+        })
+    }
+
+    /// `nodes` ordered so that every rule between them holds, using only the
+    /// rules between the nodes themselves. Nodes that no rule orders keep the
+    /// order they were given in, and repeated nodes are kept once. Fails with
+    /// a cycle of `nodes` if the rules between them contradict each other.
+    pub fn sort(&self, nodes: &[T]) -> Result<Vec<T>, Cycle<T>> {
+        let mut unique = HashSet::new();
+        let nodes: Vec<&T> = nodes.iter().filter(|node| unique.insert(*node)).collect();
+
+        let mut blockers: HashMap<&T, usize> = nodes
+            .iter()
+            .map(|node| {
+                let before = nodes
+                    .iter()
+                    .filter(|before| self.must_precede(before, node));
+                (*node, before.count())
+            })
+            .collect();
+        let mut ready: VecDeque<&T> = nodes
+            .iter()
+            .copied()
+            .filter(|node| blockers[node] == 0)
+            .collect();
+        let mut sorted = Vec::with_capacity(nodes.len());
+
+        while let Some(node) = ready.pop_front() {
+            sorted.push(node.clone());
+            for &after in nodes.iter().filter(|after| self.must_precede(node, after)) {
+                let count = blockers.get_mut(after).expect("every node has a count");
+                *count -= 1;
+                if *count == 0 {
+                    ready.push_back(after);
+                }
+            }
+        }
+
+        if sorted.len() == nodes.len() {
+            return Ok(sorted);
+        }
+
+        // Every node left is still waiting on another node that is left, so
+        // following those back from any of them has to come round in a loop
+        let left = |node: &&T| blockers[node] > 0;
+        let mut path = vec![nodes.iter().copied().find(left).expect("a node is left")];
+        loop {
+            let node = path[path.len() - 1];
+            let before = nodes
+                .iter()
+                .copied()
+                .filter(left)
+                .find(|before| self.must_precede(before, node))
+                .expect("a node that is left waits on another");
+            if let Some(start) = path.iter().position(|&node| node == before) {
+                let cycle = path[start..].iter().rev().map(|&node| node.clone());
+                return Err(Cycle(cycle.collect()));
+            }
+            path.push(before);
+        }
+    }
+}
+
+impl<T: Clone + Eq + Hash> FromIterator<(T, T)> for Precedence<T> {
+    /// Rules from pairs of `(before, after)`
+    fn from_iter<I: IntoIterator<Item = (T, T)>>(rules: I) -> Self {
+        let mut after: HashMap<T, HashSet<T>> = HashMap::new();
+        for (before, node) in rules {
+            after.entry(before).or_default().insert(node);
+        }
+        Self { after }
+    }
+}
+
+/// Nodes that the rules say must each come before the next, and the last
+/// before the first, so that they can't be sorted
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle<T>(pub Vec<T>);
+
+impl<T: fmt::Display> fmt::Display for Cycle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the ordering rules contradict each other:")?;
+        for (index, node) in self.0.iter().chain(self.0.first()).enumerate() {
+            let arrow = if index == 0 { "" } else { " ->" };
+            write!(f, "{arrow} {node}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: fmt::Debug + fmt::Display> std::error::Error for Cycle<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules() -> Precedence<u32> {
+        [
+            (97, 75),
+            (97, 47),
+            (75, 47),
+            (75, 29),
+            (47, 29),
+            (29, 13),
+            (47, 13),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    #[test]
+    fn reports_broken_rules() {
+        let rules = rules();
+
+        assert!(rules.is_ordered(&[97, 75, 47, 29, 13]));
+        assert!(rules.is_ordered(&[75, 61, 13]));
+        assert!(!rules.is_ordered(&[75, 97, 47]));
+        assert_eq!(rules.violations(&[75, 97, 47]), [(97, 75)]);
+        assert_eq!(
+            rules.violations(&[13, 29, 47]),
+            [(29, 13), (47, 13), (47, 29)]
+        );
+    }
+
+    #[test]
+    fn sorts_subsets() {
+        let rules = rules();
+
+        assert_eq!(rules.sort(&[13, 29, 47]), Ok(vec![47, 29, 13]));
+        assert_eq!(rules.sort(&[61, 13, 97, 47]), Ok(vec![61, 97, 47, 13]));
+        assert_eq!(rules.sort(&[29, 29, 75]), Ok(vec![75, 29]));
+        assert_eq!(rules.sort(&[]), Ok(vec![]));
+    }
+
+    #[test]
+    fn finds_cycles() {
+        let rules: Precedence<u32> = [(1, 2), (2, 3), (3, 1), (0, 1)].into_iter().collect();
+
+        let Err(Cycle(cycle)) = rules.sort(&[0, 3, 2, 1]) else {
+            panic!("the rules should contradict each other");
+        };
+
+        assert_eq!(cycle.len(), 3);
+        for (index, before) in cycle.iter().enumerate() {
+            assert!(rules.must_precede(before, &cycle[(index + 1) % cycle.len()]));
+        }
+        // Nodes outside of the sorted ones don't matter
+        assert_eq!(rules.sort(&[0, 1, 2]), Ok(vec![0, 1, 2]));
+    }
+
+    #[test]
+    fn describes_cycles() {
+        assert_eq!(
+            Cycle(vec![1, 2, 3]).to_string(),
+            "the ordering rules contradict each other: 1 -> 2 -> 3 -> 1"
+        );
+    }
+}
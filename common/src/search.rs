@@ -0,0 +1,344 @@
+//! Searches of graphs that are given as a function from each node to its
+//! neighbours, such as the open cells next to a position in a
+//! [`Grid`](crate::Grid) or the edges in an adjacency map
+
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// The cost of an edge, such as any unsigned integer. The default is no cost.
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<C: Copy + Ord + Add<Output = C> + Default> Cost for C {}
+
+/// The cheapest way to reach each node that a search found, from its start
+#[derive(Debug, Clone)]
+pub struct Distances<N, C> {
+    distances: HashMap<N, C>,
+    /// The node before each one on its cheapest path, for every node but the
+    /// start
+    parents: HashMap<N, N>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Distances<N, C> {
+    fn new(start: N, cost: C) -> Self {
+        Self {
+            distances: HashMap::from([(start, cost)]),
+            parents: HashMap::new(),
+        }
+    }
+
+    /// The cost of the cheapest path to `node`, or `None` if it can't be
+    /// reached
+    pub fn get(&self, node: &N) -> Option<C> {
+        self.distances.get(node).copied()
+    }
+
+    /// Every reached node, including the start, with the cost of reaching it
+    pub fn iter(&self) -> impl Iterator<Item = (&N, C)> {
+        self.distances.iter().map(|(node, &cost)| (node, cost))
+    }
+
+    /// How many nodes were reached, including the start
+    pub fn len(&self) -> usize {
+        self.distances.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.distances.is_empty()
+    }
+
+    /// The nodes along a cheapest path from the start to `node`, both included
+    pub fn path(&self, node: &N) -> Option<Vec<N>> {
+        self.distances.get(node)?;
+
+        let mut path = vec![node.clone()];
+        while let Some(parent) = self.parents.get(&path[path.len() - 1]) {
+            path.push(parent.clone());
+        }
+        path.reverse();
+
+        Some(path)
+    }
+}
+
+/// The number of steps to every node reachable from `start`
+pub fn bfs<N, I>(start: N, mut neighbours: impl FnMut(&N) -> I) -> Distances<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances = Distances::new(start.clone(), 0);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        let distance = distances.distances[&node];
+        for neighbour in neighbours(&node) {
+            if distances.distances.contains_key(&neighbour) {
+                continue;
+            }
+            distances.distances.insert(neighbour.clone(), distance + 1);
+            distances.parents.insert(neighbour.clone(), node.clone());
+            queue.push_back(neighbour);
+        }
+    }
+
+    distances
+}
+
+/// How many different paths of the fewest steps lead to every node reachable
+/// from `start`
+pub fn count_shortest_paths<N, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+) -> HashMap<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut counts = HashMap::from([(start.clone(), 1)]);
+    let mut queue = VecDeque::from([start]);
+
+    // Every path to a node is counted before it is taken off the queue, since
+    // the nodes one step closer all come before it
+    while let Some(node) = queue.pop_front() {
+        let (distance, count) = (distances[&node], counts[&node]);
+        for neighbour in neighbours(&node) {
+            match distances.get(&neighbour) {
+                None => {
+                    distances.insert(neighbour.clone(), distance + 1);
+                    counts.insert(neighbour.clone(), count);
+                    queue.push_back(neighbour);
+                }
+                Some(&next) if next == distance + 1 => {
+                    *counts
+                        .get_mut(&neighbour)
+                        .expect("reached nodes are counted") += count;
+                }
+                Some(_) => {}
+            }
+        }
+    }
+
+    counts
+}
+
+/// A node waiting to be searched, which comes out of the queue first when it
+/// has the lowest priority
+struct Queued<N, C> {
+    priority: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> PartialEq for Queued<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N, C: Ord> Eq for Queued<N, C> {}
+
+impl<N, C: Ord> PartialOrd for Queued<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> Ord for Queued<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // `BinaryHeap` takes out the greatest first
+        other.priority.cmp(&self.priority)
+    }
+}
+
+/// Searches from `start` in order of cost plus `heuristic`, until reaching a
+/// node that is a `goal` or running out of nodes
+fn best_first<N, C, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut goal: impl FnMut(&N) -> bool,
+) -> (Distances<N, C>, Option<N>)
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut distances = Distances::new(start.clone(), C::default());
+    let mut queue = BinaryHeap::from([Queued {
+        priority: heuristic(&start),
+        cost: C::default(),
+        node: start,
+    }]);
+
+    while let Some(Queued { cost, node, .. }) = queue.pop() {
+        // A cheaper way to the node was queued after this one
+        if cost > distances.distances[&node] {
+            continue;
+        }
+        if goal(&node) {
+            return (distances, Some(node));
+        }
+
+        for (neighbour, step) in neighbours(&node) {
+            let cost = cost + step;
+            if distances
+                .distances
+                .get(&neighbour)
+                .is_some_and(|&known| known <= cost)
+            {
+                continue;
+            }
+            distances.distances.insert(neighbour.clone(), cost);
+            distances.parents.insert(neighbour.clone(), node.clone());
+            queue.push(Queued {
+                priority: cost + heuristic(&neighbour),
+                cost,
+                node: neighbour,
+            });
+        }
+    }
+
+    (distances, None)
+}
+
+/// The cost of the cheapest path to every node reachable from `start`, where
+/// `neighbours` gives each neighbour along with the cost of stepping to it
+pub fn dijkstra<N, C, I>(start: N, neighbours: impl FnMut(&N) -> I) -> Distances<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let (distances, _) = best_first(start, neighbours, |_| C::default(), |_| false);
+    distances
+}
+
+/// The cheapest path from `start` to the closest node that is a `goal`, and
+/// its cost. `heuristic` guesses the cost from a node to a goal, which finds
+/// the cheapest path as long as it never guesses too high.
+pub fn astar<N, C, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    heuristic: impl FnMut(&N) -> C,
+    goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let (distances, goal) = best_first(start, neighbours, heuristic, goal);
+    let goal = goal?;
+
+    Some((distances.path(&goal)?, distances.get(&goal)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Grid, Point2};
+    use indoc::indoc;
+
+    const MAZE: &str = indoc! {"
+        S.#.
+        ..#.
+        ....
+        #..E
+    "};
+
+    fn open_neighbours(maze: &Grid<char>) -> impl FnMut(&Point2<i32>) -> Vec<Point2<i32>> + '_ {
+        |&position| {
+            maze.neighbours4(position)
+                .filter(|&neighbour| maze[neighbour] != '#')
+                .collect()
+        }
+    }
+
+    #[test]
+    fn finds_fewest_steps_in_grids() {
+        let maze = Grid::parse(MAZE, |c| c);
+        let end = maze.find(|&c| c == 'E').unwrap();
+
+        let distances = bfs(Point2::new(0, 0), open_neighbours(&maze));
+
+        assert_eq!(distances.get(&end), Some(6));
+        assert_eq!(distances.get(&Point2::new(3, 0)), Some(7));
+        assert_eq!(distances.get(&Point2::new(0, 3)), None);
+        assert_eq!(distances.len(), 13);
+
+        let path = distances.path(&end).unwrap();
+        assert_eq!(path.len(), 7);
+        assert_eq!((path[0], path[6]), (Point2::new(0, 0), end));
+        assert!(path
+            .windows(2)
+            .all(|step| step[0].manhattan_distance(step[1]) == 1));
+    }
+
+    #[test]
+    fn counts_shortest_paths() {
+        let open = Grid::new(3, 3, '.');
+
+        let counts = count_shortest_paths(Point2::new(0, 0), open_neighbours(&open));
+
+        assert_eq!(counts[&Point2::new(0, 0)], 1);
+        assert_eq!(counts[&Point2::new(1, 1)], 2);
+        assert_eq!(counts[&Point2::new(2, 2)], 6);
+    }
+
+    #[test]
+    fn finds_cheapest_paths_in_adjacency_maps() {
+        let edges = HashMap::from([
+            ('a', vec![('b', 7), ('c', 2)]),
+            ('c', vec![('b', 3), ('d', 10)]),
+            ('b', vec![('d', 1)]),
+        ]);
+        let neighbours = |node: &char| edges.get(node).cloned().unwrap_or_default();
+
+        let distances = dijkstra('a', neighbours);
+
+        assert_eq!(distances.get(&'b'), Some(5));
+        assert_eq!(distances.get(&'d'), Some(6));
+        assert_eq!(distances.path(&'d'), Some(vec!['a', 'c', 'b', 'd']));
+        assert_eq!(distances.path(&'e'), None);
+    }
+
+    #[test]
+    fn finds_cheapest_path_to_goal() {
+        let maze = Grid::parse(MAZE, |c| c);
+        let end = maze.find(|&c| c == 'E').unwrap();
+        // Stepping onto the right column costs more
+        let mut open = open_neighbours(&maze);
+        let neighbours = |position: &Point2<i32>| {
+            open(position)
+                .into_iter()
+                .map(|neighbour| (neighbour, if neighbour.x == 3 { 5 } else { 1 }))
+                .collect::<Vec<_>>()
+        };
+
+        let (path, cost) = astar(
+            Point2::new(0, 0),
+            neighbours,
+            |position| position.manhattan_distance(end),
+            |&position| position == end,
+        )
+        .unwrap();
+
+        assert_eq!(cost, 10);
+        assert_eq!(path.last(), Some(&end));
+        assert_eq!(
+            astar(
+                Point2::new(0, 0),
+                |_| vec![],
+                |_| 0,
+                |&position| position == end
+            ),
+            None
+        );
+    }
+}
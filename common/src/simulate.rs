@@ -0,0 +1,82 @@
+use std::fmt;
+
+/// A puzzle's state that can be stepped through one tick at a time, such as
+/// to animate it. Each tick is drawn with `Display`.
+pub trait Simulate: fmt::Display {
+    /// Advances the simulation by one tick, or returns `false` without
+    /// changing anything once it has finished
+    fn tick(&mut self) -> bool;
+}
+
+/// Every frame that a simulation has drawn so far, so that earlier ticks can
+/// be shown again without running it from the start
+pub struct Frames {
+    simulation: Box<dyn Simulate>,
+    frames: Vec<String>,
+    finished: bool,
+}
+
+impl Frames {
+    pub fn new(simulation: Box<dyn Simulate>) -> Self {
+        Self {
+            frames: vec![simulation.to_string()],
+            simulation,
+            finished: false,
+        }
+    }
+
+    /// The tick closest to `tick` that the simulation reaches and its frame,
+    /// simulating as far as needed
+    pub fn get(&mut self, tick: usize) -> (usize, &str) {
+        while self.frames.len() <= tick && !self.finished {
+            if self.simulation.tick() {
+                self.frames.push(self.simulation.to_string());
+            } else {
+                self.finished = true;
+            }
+        }
+
+        let tick = tick.min(self.frames.len() - 1);
+        (tick, &self.frames[tick])
+    }
+
+    /// The tick that the simulation finished on, once it has been reached
+    pub fn last(&self) -> Option<usize> {
+        self.finished.then(|| self.frames.len() - 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Countdown(u32);
+
+    impl fmt::Display for Countdown {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{}", self.0)
+        }
+    }
+
+    impl Simulate for Countdown {
+        fn tick(&mut self) -> bool {
+            let Some(count) = self.0.checked_sub(1) else {
+                return false;
+            };
+            self.0 = count;
+            true
+        }
+    }
+
+    #[test]
+    fn draws_frames_up_to_the_last_tick() {
+        let mut frames = Frames::new(Box::new(Countdown(3)));
+
+        assert_eq!(frames.get(0), (0, "3"));
+        assert_eq!(frames.get(2), (2, "1"));
+        assert_eq!(frames.last(), None);
+        assert_eq!(frames.get(10), (3, "0"));
+        assert_eq!(frames.last(), Some(3));
+        assert_eq!(frames.get(1), (1, "2"));
+    }
+}
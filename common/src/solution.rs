@@ -0,0 +1,211 @@
+use crate::{Answer, Simulate};
+use std::any::Any;
+
+/// A day's puzzle solution. The input is parsed once and shared by both parts,
+/// which each return `None` until they have been solved. Parts that can fail on
+/// an input that parses implement `try_part1` or `try_part2` instead.
+pub trait Solution {
+    /// The puzzle input after parsing
+    type Input;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input>;
+
+    fn part1(&self, _input: &Self::Input) -> Option<Answer> {
+        None
+    }
+
+    fn part2(&self, _input: &Self::Input) -> Option<Answer> {
+        None
+    }
+
+    fn try_part1(&self, input: &Self::Input) -> anyhow::Result<Option<Answer>> {
+        Ok(self.part1(input))
+    }
+
+    fn try_part2(&self, input: &Self::Input) -> anyhow::Result<Option<Answer>> {
+        Ok(self.part2(input))
+    }
+
+    /// The puzzle played out from `input`, for days that can be animated
+    fn simulation(&self, _input: &Self::Input) -> Option<Box<dyn Simulate>> {
+        None
+    }
+
+    /// A picture of `input` as an SVG document, for days that can be drawn
+    fn svg(&self, _input: &Self::Input) -> Option<String> {
+        None
+    }
+}
+
+/// An input parsed by a [`DynSolution`]
+pub type Parsed = Box<dyn Any>;
+
+/// A [`Solution`] with its input type erased, so that days with different
+/// inputs can be registered together
+pub trait DynSolution {
+    fn parse(&self, input: &str) -> anyhow::Result<Parsed>;
+
+    /// Panics if `input` wasn't parsed by this solution
+    fn part1(&self, input: &Parsed) -> anyhow::Result<Option<Answer>>;
+
+    /// Panics if `input` wasn't parsed by this solution
+    fn part2(&self, input: &Parsed) -> anyhow::Result<Option<Answer>>;
+
+    /// Panics if `input` wasn't parsed by this solution
+    fn simulation(&self, input: &Parsed) -> Option<Box<dyn Simulate>>;
+
+    /// Panics if `input` wasn't parsed by this solution
+    fn svg(&self, input: &Parsed) -> Option<String>;
+}
+
+fn downcast<T: 'static>(input: &Parsed) -> &T {
+    input
+        .downcast_ref()
+        .expect("input is parsed by the same solution")
+}
+
+impl<S> DynSolution for S
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    fn parse(&self, input: &str) -> anyhow::Result<Parsed> {
+        Ok(Box::new(Solution::parse(self, input)?))
+    }
+
+    fn part1(&self, input: &Parsed) -> anyhow::Result<Option<Answer>> {
+        Solution::try_part1(self, downcast(input))
+    }
+
+    fn part2(&self, input: &Parsed) -> anyhow::Result<Option<Answer>> {
+        Solution::try_part2(self, downcast(input))
+    }
+
+    fn simulation(&self, input: &Parsed) -> Option<Box<dyn Simulate>> {
+        Solution::simulation(self, downcast(input))
+    }
+
+    fn svg(&self, input: &Parsed) -> Option<String> {
+        Solution::svg(self, downcast(input))
+    }
+}
+
+pub struct Day {
+    pub day: u8,
+    pub solution: &'static dyn DynSolution,
+}
+
+impl Day {
+    pub const fn new(day: u8, solution: &'static dyn DynSolution) -> Self {
+        Self { day, solution }
+    }
+
+    /// Parses `input` and solves `part` of it
+    pub fn solve(&self, part: u8, input: &str) -> anyhow::Result<Option<Answer>> {
+        let input = self.solution.parse(input)?;
+        match part {
+            1 => self.solution.part1(&input),
+            _ => self.solution.part2(&input),
+        }
+    }
+}
+
+/// All registered solutions for one year
+pub struct Year {
+    pub year: u16,
+    pub days: &'static [Day],
+}
+
+impl Year {
+    pub fn get(&self, day: u8) -> Option<&Day> {
+        self.days.iter().find(|registered| registered.day == day)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Context;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<u32>;
+
+        fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+            input
+                .lines()
+                .map(|n| n.parse().with_context(|| format!("'{n}' is not a number")))
+                .collect()
+        }
+
+        fn part1(&self, numbers: &Self::Input) -> Option<Answer> {
+            Some(numbers.iter().sum::<u32>().into())
+        }
+    }
+
+    struct Half;
+
+    impl Solution for Half {
+        type Input = u32;
+
+        fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+            Ok(input.trim().parse()?)
+        }
+
+        fn try_part1(&self, number: &Self::Input) -> anyhow::Result<Option<Answer>> {
+            if number % 2 == 1 {
+                anyhow::bail!("{number} can't be halved");
+            }
+            Ok(Some((number / 2).into()))
+        }
+    }
+
+    const YEAR: Year = Year {
+        year: 2024,
+        days: &[Day::new(3, &Sum), Day::new(4, &Half)],
+    };
+
+    #[test]
+    fn gets_registered_day() {
+        let day = YEAR.get(3).unwrap();
+
+        assert_eq!(day.solve(1, "1\n2").unwrap(), Some(Answer::from(3)));
+        assert_eq!(day.solve(2, "1\n2").unwrap(), None);
+    }
+
+    #[test]
+    fn shares_parsed_input_between_parts() {
+        let solution = YEAR.get(3).unwrap().solution;
+        let input = solution.parse("4\n5").unwrap();
+
+        assert_eq!(solution.part1(&input).unwrap(), Some(Answer::from(9)));
+        assert_eq!(solution.part2(&input).unwrap(), None);
+        assert!(solution.simulation(&input).is_none());
+        assert!(solution.svg(&input).is_none());
+    }
+
+    #[test]
+    fn fails_to_parse_invalid_input() {
+        let error = YEAR.get(3).unwrap().solve(1, "1\nx").unwrap_err();
+
+        assert_eq!(error.to_string(), "'x' is not a number");
+    }
+
+    #[test]
+    fn reports_failing_parts() {
+        let day = YEAR.get(4).unwrap();
+
+        assert_eq!(day.solve(1, "4").unwrap(), Some(Answer::from(2)));
+        assert_eq!(
+            day.solve(1, "5").unwrap_err().to_string(),
+            "5 can't be halved"
+        );
+        assert_eq!(day.solve(2, "5").unwrap(), None);
+    }
+
+    #[test]
+    fn misses_unregistered_day() {
+        assert!(YEAR.get(5).is_none());
+    }
+}
@@ -0,0 +1,151 @@
+//! Groups of things that are merged as they're found to be connected, such as
+//! the circuits of junction boxes in 2025 day 8
+
+/// Disjoint sets of the indices `0..len`, which start out on their own and can
+/// be merged with each other
+#[derive(Debug, Clone)]
+pub struct UnionFind {
+    /// An index that each index is in the same set as, which leads to the
+    /// set's root when followed
+    parents: Vec<usize>,
+    /// The number of indices in each root's set
+    sizes: Vec<usize>,
+    components: usize,
+}
+
+impl UnionFind {
+    /// `len` indices that are each in a set of their own
+    pub fn new(len: usize) -> Self {
+        Self {
+            parents: (0..len).collect(),
+            sizes: vec![1; len],
+            components: len,
+        }
+    }
+
+    /// How many indices there are in total
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// How many separate sets there are
+    pub fn components(&self) -> usize {
+        self.components
+    }
+
+    /// The index that stands for the set that `index` is in
+    pub fn find(&mut self, index: usize) -> usize {
+        let mut root = index;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+
+        // Point everything on the way straight at the root, so that finding
+        // them again is quicker
+        let mut index = index;
+        while self.parents[index] != root {
+            index = std::mem::replace(&mut self.parents[index], root);
+        }
+
+        root
+    }
+
+    /// Merges the sets that `a` and `b` are in, or returns `false` if they were
+    /// already the same set
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+
+        // Hanging the smaller set under the larger keeps the paths short
+        let (larger, smaller) = if self.sizes[a] < self.sizes[b] {
+            (b, a)
+        } else {
+            (a, b)
+        };
+        self.parents[smaller] = larger;
+        self.sizes[larger] += self.sizes[smaller];
+        self.components -= 1;
+
+        true
+    }
+
+    /// Whether `a` and `b` are in the same set
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// How many indices are in the same set as `index`, including itself
+    pub fn size(&mut self, index: usize) -> usize {
+        let root = self.find(index);
+        self.sizes[root]
+    }
+
+    /// Every set's indices in ascending order, ordered by their lowest index
+    pub fn groups(&mut self) -> Vec<Vec<usize>> {
+        let mut groups: Vec<Vec<usize>> = vec![];
+        // Where each root's group is in `groups`
+        let mut positions = vec![None; self.len()];
+
+        for index in 0..self.len() {
+            let root = self.find(index);
+            let position = *positions[root].get_or_insert_with(|| {
+                groups.push(Vec::with_capacity(self.sizes[root]));
+                groups.len() - 1
+            });
+            groups[position].push(index);
+        }
+
+        groups
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merges_sets() {
+        let mut sets = UnionFind::new(6);
+
+        assert!(sets.union(0, 1));
+        assert!(sets.union(4, 1));
+        assert!(sets.union(2, 3));
+        assert!(!sets.union(0, 4));
+
+        assert!(sets.connected(0, 4));
+        assert!(!sets.connected(0, 2));
+        assert_eq!(sets.size(1), 3);
+        assert_eq!(sets.size(5), 1);
+        assert_eq!(sets.components(), 3);
+        assert_eq!(sets.groups(), [vec![0, 1, 4], vec![2, 3], vec![5]]);
+    }
+
+    #[test]
+    fn shortens_paths_to_roots() {
+        let mut sets = UnionFind::new(4);
+        // Unions by size would never build a chain, so build one by hand
+        sets.parents = vec![0, 0, 1, 2];
+
+        assert_eq!(sets.find(3), 0);
+        assert_eq!(sets.parents, [0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn merges_everything_into_one() {
+        let mut sets = UnionFind::new(100);
+
+        for index in 1..100 {
+            sets.union(index - 1, index);
+        }
+
+        assert_eq!(sets.components(), 1);
+        assert_eq!(sets.size(42), 100);
+        assert_eq!(UnionFind::new(0).groups(), Vec::<Vec<usize>>::new());
+    }
+}